@@ -35,10 +35,30 @@ pub fn auth_for_backend(backend: Backend) -> Box<dyn Auth> {
         Backend::Databricks | Backend::DatabricksODBC => Box::new(databricks::DatabricksAuth {}),
         Backend::Redshift | Backend::RedshiftODBC => Box::new(redshift::RedshiftAuth {}),
         Backend::Salesforce => Box::new(salesforce::SalesforceAuth {}),
+        Backend::MSSQL | Backend::ClickHouse => Box::new(UnsupportedAuth { backend }),
         Backend::Generic { .. } => unimplemented!("generic backend authentication"),
     }
 }
 
+/// Authenticator for the backends that can't be configured yet, failing
+/// with an [AuthError] instead of panicking.
+struct UnsupportedAuth {
+    backend: Backend,
+}
+
+impl Auth for UnsupportedAuth {
+    fn backend(&self) -> Backend {
+        self.backend
+    }
+
+    fn configure(&self, _config: &AdapterConfig) -> Result<database::Builder, AuthError> {
+        Err(AuthError::config(format!(
+            "Authentication is not supported for {} yet",
+            self.backend
+        )))
+    }
+}
+
 /// Error type for [dbt_auth].
 ///
 /// For display purposes, it must be converted into an [AdapterError] first, outside of this crate.
@@ -100,3 +120,26 @@ impl From<dbt_serde_yaml::Error> for AuthError {
         AuthError::YAML(err)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unsupported_backend_auth() {
+        for (backend, name) in [
+            (Backend::MSSQL, "SQL Server"),
+            (Backend::ClickHouse, "ClickHouse"),
+        ] {
+            let auth = auth_for_backend(backend);
+            assert_eq!(auth.backend(), backend);
+            let Err(err) = auth.configure(&AdapterConfig::default()) else {
+                panic!("configured an unsupported backend: {backend}");
+            };
+            assert_eq!(
+                err.msg(),
+                format!("Authentication is not supported for {name} yet")
+            );
+        }
+    }
+}
@@ -12,6 +12,14 @@ pub const DBT_MIN_SUPPORTED_VERSION: &str = "1.8.0";
 pub const DBT_CATALOGS_YML: &str = "catalogs.yml";
 pub const DBT_PROJECT_YML: &str = "dbt_project.yml";
 pub const DBT_PROFILES_YML: &str = "profiles.yml";
+pub const DBT_SEED_EXTENSIONS: &[&str] = &["csv", "parquet", "json"];
+// Extensions of files that look like data but can't be loaded as seeds, most likely
+// typos or formats that need converting first. Seeds with these extensions are
+// reported and skipped, other files in the seed paths are ignored silently.
+pub const DBT_SEED_DATA_LIKE_EXTENSIONS: &[&str] = &[
+    "cvs", "scv", "tsv", "psv", "txt", "dat", "jsonl", "ndjson", "parq", "pq", "avro", "orc",
+    "xls", "xlsx", "gz", "zip",
+];
 
 // ----------------------------------------------------------------------------------------------
 // dbt outputs
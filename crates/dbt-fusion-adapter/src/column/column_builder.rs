@@ -197,7 +197,7 @@ impl ColumnBuilder {
 
         let mode = match sql_type {
             Some((sql_type, _nullable)) => match sql_type {
-                SqlType::Array(_) => BigqueryColumnMode::Repeated,
+                SqlType::Array(_) | SqlType::NonNullArray(_) => BigqueryColumnMode::Repeated,
                 _ => non_repeated_mode,
             },
             None => {
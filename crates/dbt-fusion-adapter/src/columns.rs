@@ -422,30 +422,32 @@ impl StdColumn {
             }
             (AdapterType::Bigquery, sql_type) => {
                 match sql_type {
-                    SqlType::Array(Some(inner)) => match inner.as_ref() {
-                        SqlType::Struct(Some(fields)) => (
-                            "RECORD".to_string(),
-                            format!(
-                                "ARRAY<{}>",
-                                Self::make_degenerate_data_type_from_parsed_struct_fields(
-                                    adapter_type,
-                                    fields.as_slice()
-                                )
+                    SqlType::Array(Some(inner)) | SqlType::NonNullArray(inner) => {
+                        match inner.as_ref() {
+                            SqlType::Struct(Some(fields)) => (
+                                "RECORD".to_string(),
+                                format!(
+                                    "ARRAY<{}>",
+                                    Self::make_degenerate_data_type_from_parsed_struct_fields(
+                                        adapter_type,
+                                        fields.as_slice()
+                                    )
+                                ),
                             ),
-                        ),
-                        SqlType::Array(_) => unreachable!(
-                            "ARRAY of ARRAY is not allowed in BigQuery. This is a bug."
-                        ),
-                        _ => {
-                            let (dtype, data_type) =
-                                Self::make_degenerate_types_from_parsed_sqltype(
-                                    adapter_type,
-                                    inner.as_ref(),
-                                );
-
-                            (dtype, format!("ARRAY<{}>", data_type))
+                            SqlType::Array(_) | SqlType::NonNullArray(_) => unreachable!(
+                                "ARRAY of ARRAY is not allowed in BigQuery. This is a bug."
+                            ),
+                            _ => {
+                                let (dtype, data_type) =
+                                    Self::make_degenerate_types_from_parsed_sqltype(
+                                        adapter_type,
+                                        inner.as_ref(),
+                                    );
+
+                                (dtype, format!("ARRAY<{}>", data_type))
+                            }
                         }
-                    },
+                    }
                     SqlType::Struct(Some(fields)) => (
                         "RECORD".to_string(),
                         Self::make_degenerate_data_type_from_parsed_struct_fields(
@@ -1194,4 +1196,19 @@ mod tests {
                 .all(|(a, b)| a.cmp_column(b))
         );
     }
+
+    #[test]
+    fn test_bq_degenerate_types_of_non_null_array() {
+        for sql_type in [
+            SqlType::Array(Some(Box::new(SqlType::BigInt))),
+            SqlType::NonNullArray(Box::new(SqlType::BigInt)),
+        ] {
+            let (dtype, data_type) = StdColumn::make_degenerate_types_from_parsed_sqltype(
+                AdapterType::Bigquery,
+                &sql_type,
+            );
+            assert_eq!(dtype, "INT64", "{sql_type:?}");
+            assert_eq!(data_type, "ARRAY<INT64>", "{sql_type:?}");
+        }
+    }
 }
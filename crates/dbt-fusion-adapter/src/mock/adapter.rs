@@ -13,6 +13,7 @@ use arrow::record_batch::RecordBatch;
 use dbt_agate::AgateTable;
 use dbt_common::behavior_flags::BehaviorFlag;
 use dbt_common::cancellation::CancellationToken;
+use dbt_frontend_common::dialect::Dialect;
 use dbt_schemas::schemas::common::{ConstraintSupport, ConstraintType, ResolvedQuoting};
 use dbt_schemas::schemas::relations::base::{BaseRelation, TableFormat};
 use dbt_xdbc::{Connection, QueryCtx};
@@ -21,6 +22,7 @@ use minijinja::{State, Value};
 use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 /// An adapter intended to be used in tests only. This adapter
 /// hardcodes values used in tests. In the future this adapter could
@@ -36,6 +38,8 @@ pub struct MockAdapter {
     quoting: ResolvedQuoting,
     /// Global CLI cancellation token
     cancellation_token: CancellationToken,
+    /// Whether a transaction is open, shared by the clones of this adapter
+    in_transaction: Arc<AtomicBool>,
 }
 
 impl fmt::Debug for MockAdapter {
@@ -44,6 +48,7 @@ impl fmt::Debug for MockAdapter {
             .field("adapter_type", &self.adapter_type)
             .field("flags", &self.flags)
             .field("quoting", &self.quoting)
+            .field("in_transaction", &self.is_in_transaction())
             .finish()
     }
 }
@@ -62,8 +67,30 @@ impl MockAdapter {
             flags,
             quoting,
             cancellation_token: token,
+            in_transaction: Arc::new(AtomicBool::new(false)),
         }
     }
+
+    /// Open a transaction
+    pub fn begin(&self) {
+        self.in_transaction.store(true, Ordering::SeqCst);
+    }
+
+    /// Commit the open transaction, if any
+    pub fn commit(&self) {
+        self.in_transaction.store(false, Ordering::SeqCst);
+    }
+
+    /// Roll back the open transaction, if any
+    pub fn rollback(&self) {
+        self.in_transaction.store(false, Ordering::SeqCst);
+    }
+
+    /// Returns true if a transaction was opened and not yet committed or
+    /// rolled back
+    pub fn is_in_transaction(&self) -> bool {
+        self.in_transaction.load(Ordering::SeqCst)
+    }
 }
 
 impl AdapterTyping for MockAdapter {
@@ -103,11 +130,14 @@ impl TypedBaseAdapter for MockAdapter {
         _conn: &'_ mut dyn Connection,
         _ctx: &QueryCtx,
         sql: &str,
-        _auto_begin: bool,
+        auto_begin: bool,
         _fetch: bool,
         _limit: Option<i64>,
         _options: Option<HashMap<String, String>>,
     ) -> AdapterResult<(AdapterResponse, AgateTable)> {
+        if auto_begin {
+            self.begin();
+        }
         let response = AdapterResponse {
             message: "execute".to_string(),
             code: sql.to_string(),
@@ -134,15 +164,39 @@ impl TypedBaseAdapter for MockAdapter {
         _query_ctx: &QueryCtx,
         _conn: &'_ mut dyn Connection,
         _sql: &str,
-        _auto_begin: bool,
+        auto_begin: bool,
         _bindings: Option<&Value>,
         _abridge_sql_log: bool,
     ) -> AdapterResult<()> {
-        unimplemented!("query addition to connection in MockAdapter")
+        if auto_begin {
+            self.begin();
+        }
+        Ok(())
     }
 
+    /// Quote like the adapter of [MockAdapter::adapter_type] does, with
+    /// backticks on BigQuery and Databricks and double quotes elsewhere.
+    /// Embedded quote characters are escaped. If the quoting policy doesn't
+    /// quote identifiers, the identifier is first folded to the case the
+    /// warehouse stores unquoted names in, so that the quoted name refers to
+    /// the same object as the unquoted one.
     fn quote(&self, _state: &State, identifier: &str) -> AdapterResult<String> {
-        Ok(format!("\"{identifier}\""))
+        let identifier = if self.quoting.identifier {
+            identifier.to_string()
+        } else {
+            match self.adapter_type() {
+                AdapterType::Snowflake => identifier.to_uppercase(),
+                AdapterType::Postgres | AdapterType::Redshift => identifier.to_lowercase(),
+                _ => identifier.to_string(),
+            }
+        };
+        let dialect = Dialect::from(self.adapter_type());
+        let quote = dialect.quote_char();
+        let escaped = match dialect {
+            Dialect::Bigquery => dialect.escape_identifier(&identifier),
+            _ => identifier.replace(quote, &format!("{quote}{quote}")),
+        };
+        Ok(format!("{quote}{escaped}{quote}"))
     }
 
     fn get_relation(
@@ -213,17 +267,27 @@ impl TypedBaseAdapter for MockAdapter {
     }
 
     fn behavior(&self) -> Vec<BehaviorFlag> {
-        let is_true = self.flags.get("is_true").is_none_or(|v| v.is_true());
-        let is_false = self.flags.get("is_false").is_some_and(|v| v.is_true());
-        let is_unknown = self.flags.get("is_unknown").is_none_or(|v| v.is_true());
-
-        // Ane example flag defined by an adapter
-        let flags = vec![
-            BehaviorFlag::new("is_true", is_true, None, None, None),
-            BehaviorFlag::new("is_false", is_false, None, None, None),
-            BehaviorFlag::new("is_unknown", is_unknown, None, None, None),
-        ];
-        flags
+        // Example flags defined by every adapter
+        let mut defaults = vec![("is_true", true), ("is_false", false), ("is_unknown", true)];
+        // The flags (and their defaults) the real adapters define
+        defaults.extend_from_slice(match self.adapter_type() {
+            AdapterType::Snowflake => &[("enable_iceberg_materializations", false)][..],
+            AdapterType::Databricks => &[
+                ("use_info_schema_for_columns", false),
+                ("use_user_folder_for_python", false),
+                ("use_materialization_v2", false),
+            ],
+            _ => &[],
+        });
+
+        // Flags set in dbt_project.yml override the defaults
+        defaults
+            .into_iter()
+            .map(|(name, default)| {
+                let value = self.flags.get(name).map_or(default, |v| v.is_true());
+                BehaviorFlag::new(name, value, None, None, None)
+            })
+            .collect()
     }
 
     fn get_constraint_support(&self, _ct: ConstraintType) -> ConstraintSupport {
@@ -239,10 +303,12 @@ impl fmt::Display for MockAdapter {
 
 #[cfg(test)]
 mod tests {
+    use dbt_common::behavior_flags::Behavior;
     use dbt_common::cancellation::never_cancels;
-    use dbt_schemas::schemas::relations::SNOWFLAKE_RESOLVED_QUOTING;
+    use dbt_schemas::schemas::relations::{DEFAULT_RESOLVED_QUOTING, SNOWFLAKE_RESOLVED_QUOTING};
 
     use super::*;
+    use crate::sql_engine::NoopConnection;
 
     #[test]
     fn test_adapter_type() {
@@ -255,16 +321,191 @@ mod tests {
         assert_eq!(adapter.adapter_type(), AdapterType::Snowflake);
     }
 
+    #[test]
+    fn test_transaction_state() {
+        let adapter = MockAdapter::new(
+            AdapterType::Postgres,
+            BTreeMap::new(),
+            SNOWFLAKE_RESOLVED_QUOTING,
+            never_cancels(),
+        );
+        let ctx = QueryCtx::default();
+        let mut conn = NoopConnection;
+        assert!(!adapter.is_in_transaction());
+
+        adapter.begin();
+        assert!(adapter.is_in_transaction());
+        adapter
+            .execute(None, &mut conn, &ctx, "select 1", false, false, None, None)
+            .unwrap();
+        assert!(adapter.is_in_transaction());
+        adapter.commit();
+        assert!(!adapter.is_in_transaction());
+
+        // without auto_begin, queries run outside of a transaction
+        adapter
+            .execute(None, &mut conn, &ctx, "select 1", false, false, None, None)
+            .unwrap();
+        assert!(!adapter.is_in_transaction());
+
+        adapter
+            .execute(None, &mut conn, &ctx, "select 1", true, false, None, None)
+            .unwrap();
+        assert!(adapter.is_in_transaction());
+        adapter.rollback();
+        assert!(!adapter.is_in_transaction());
+
+        // the state is shared with the clones of the adapter
+        let clone = adapter.clone();
+        clone
+            .add_query(&ctx, &mut conn, "select 1", true, None, false)
+            .unwrap();
+        assert!(adapter.is_in_transaction());
+        adapter.commit();
+        assert!(!clone.is_in_transaction());
+    }
+
     #[test]
     fn test_quote() {
         let adapter = MockAdapter::new(
             AdapterType::Snowflake,
             BTreeMap::new(),
-            SNOWFLAKE_RESOLVED_QUOTING,
+            DEFAULT_RESOLVED_QUOTING,
             never_cancels(),
         );
         let env = minijinja::Environment::new();
         let state = State::new_for_env(&env);
         assert_eq!(adapter.quote(&state, "abc").unwrap(), "\"abc\"");
     }
+
+    #[test]
+    fn test_behavior_per_adapter_type() {
+        let behavior = |adapter_type, flags: &[(&str, bool)]| {
+            let flags = flags
+                .iter()
+                .map(|(name, value)| (name.to_string(), Value::from(*value)))
+                .collect();
+            let adapter = MockAdapter::new(
+                adapter_type,
+                flags,
+                SNOWFLAKE_RESOLVED_QUOTING,
+                never_cancels(),
+            );
+            Value::from_object(Behavior::new(&adapter.behavior()))
+        };
+        let is_set = |behavior: &Value, name: &str| {
+            let flag = behavior.get_attr(name).unwrap();
+            (!flag.is_undefined()).then(|| flag.is_true())
+        };
+
+        let snowflake = behavior(AdapterType::Snowflake, &[]);
+        assert_eq!(is_set(&snowflake, "is_true"), Some(true));
+        assert_eq!(is_set(&snowflake, "is_false"), Some(false));
+        assert_eq!(
+            is_set(&snowflake, "enable_iceberg_materializations"),
+            Some(false)
+        );
+        assert_eq!(is_set(&snowflake, "use_materialization_v2"), None);
+
+        let databricks = behavior(AdapterType::Databricks, &[]);
+        assert_eq!(is_set(&databricks, "is_true"), Some(true));
+        assert_eq!(is_set(&databricks, "use_materialization_v2"), Some(false));
+        assert_eq!(is_set(&databricks, "enable_iceberg_materializations"), None);
+
+        let bigquery = behavior(AdapterType::Bigquery, &[]);
+        assert_eq!(is_set(&bigquery, "is_unknown"), Some(true));
+        assert_eq!(is_set(&bigquery, "enable_iceberg_materializations"), None);
+
+        // flags from dbt_project.yml override the defaults
+        let snowflake = behavior(
+            AdapterType::Snowflake,
+            &[
+                ("enable_iceberg_materializations", true),
+                ("is_true", false),
+            ],
+        );
+        assert_eq!(
+            is_set(&snowflake, "enable_iceberg_materializations"),
+            Some(true)
+        );
+        assert_eq!(is_set(&snowflake, "is_true"), Some(false));
+    }
+
+    #[test]
+    fn test_quote_per_adapter_type() {
+        let env = minijinja::Environment::new();
+        let state = State::new_for_env(&env);
+        let table = [
+            (AdapterType::Snowflake, "\"MyTable\""),
+            (AdapterType::Postgres, "\"MyTable\""),
+            (AdapterType::Redshift, "\"MyTable\""),
+            (AdapterType::Bigquery, "`MyTable`"),
+            (AdapterType::Databricks, "`MyTable`"),
+        ];
+        for (adapter_type, expected) in table {
+            let adapter = MockAdapter::new(
+                adapter_type,
+                BTreeMap::new(),
+                DEFAULT_RESOLVED_QUOTING,
+                never_cancels(),
+            );
+            // quoted identifiers keep their case
+            assert_eq!(
+                adapter.quote(&state, "MyTable").unwrap(),
+                expected,
+                "{adapter_type}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_quote_folds_case_of_unquoted_identifiers() {
+        let env = minijinja::Environment::new();
+        let state = State::new_for_env(&env);
+        let table = [
+            (AdapterType::Snowflake, "\"MYTABLE\""),
+            (AdapterType::Postgres, "\"mytable\""),
+            (AdapterType::Redshift, "\"mytable\""),
+            (AdapterType::Bigquery, "`MyTable`"),
+            (AdapterType::Databricks, "`MyTable`"),
+        ];
+        for (adapter_type, expected) in table {
+            let adapter = MockAdapter::new(
+                adapter_type,
+                BTreeMap::new(),
+                SNOWFLAKE_RESOLVED_QUOTING,
+                never_cancels(),
+            );
+            assert_eq!(
+                adapter.quote(&state, "MyTable").unwrap(),
+                expected,
+                "{adapter_type}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_quote_escapes_embedded_quotes() {
+        let env = minijinja::Environment::new();
+        let state = State::new_for_env(&env);
+        let table = [
+            (AdapterType::Snowflake, "a\"b", "\"a\"\"b\""),
+            (AdapterType::Postgres, "a\"b", "\"a\"\"b\""),
+            (AdapterType::Databricks, "a`b", "`a``b`"),
+            (AdapterType::Bigquery, "a`b", "`a\\`b`"),
+        ];
+        for (adapter_type, identifier, expected) in table {
+            let adapter = MockAdapter::new(
+                adapter_type,
+                BTreeMap::new(),
+                DEFAULT_RESOLVED_QUOTING,
+                never_cancels(),
+            );
+            assert_eq!(
+                adapter.quote(&state, identifier).unwrap(),
+                expected,
+                "{adapter_type}"
+            );
+        }
+    }
 }
@@ -8,9 +8,9 @@ use crate::{
     types::{
         funcsign_parser::parse_type,
         function::{
-            Argument, BatchFunctionType, FirstFunctionType, FunctionType, ListFunctionType,
-            MapFunctionType, PrintFunctionType, RejectAttrFunctionType, SelectAttrFunctionType,
-            TryOrCompilerErrorFunctionType, UserDefinedFunctionType,
+            Argument, BatchFunctionType, DefaultFunctionType, FirstFunctionType, FunctionType,
+            ListFunctionType, MapFunctionType, PrintFunctionType, RejectAttrFunctionType,
+            SelectAttrFunctionType, TryOrCompilerErrorFunctionType, UserDefinedFunctionType,
         },
         DynObject, Object, Type,
     },
@@ -75,6 +75,12 @@ fn init_builtins(namespace_registry: Vec<String>) -> Arc<DashMap<String, Type>>
         "batch".to_string(),
         Type::Object(DynObject::new(Arc::new(BatchFunctionType::default()))),
     );
+    for name in ["default", "d"] {
+        registry.insert(
+            name.to_string(),
+            Type::Object(DynObject::new(Arc::new(DefaultFunctionType))),
+        );
+    }
 
     for name in namespace_registry {
         registry.insert(name.clone(), Type::Namespace(name));
@@ -411,3 +417,327 @@ impl Object for Reference {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, collections::BTreeMap};
+
+    use super::*;
+    use crate::types::union::UnionType;
+
+    #[derive(Default)]
+    struct RecordingListener {
+        warnings: RefCell<Vec<String>>,
+    }
+
+    impl TypecheckingEventListener for RecordingListener {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn warn(&self, message: &str) {
+            self.warnings.borrow_mut().push(message.to_string());
+        }
+
+        fn set_span(&self, _span: &Span) {}
+
+        fn new_block(&self, _block_id: usize) {}
+
+        fn flush(&self) {}
+
+        fn on_lookup(
+            &self,
+            _span: &Span,
+            _simple_name: &str,
+            _full_name: &str,
+            _def_spans: Vec<Span>,
+        ) {
+        }
+    }
+
+    fn lookup(
+        registry: &DashMap<String, Type>,
+        path: &[&str],
+        listener: Rc<RecordingListener>,
+    ) -> Type {
+        let mut type_ = registry.get(path[0]).unwrap().value().clone();
+        for name in &path[1..] {
+            type_ = type_.get_attribute(name, listener.clone()).unwrap();
+        }
+        type_
+    }
+
+    #[test]
+    fn test_modules_re_match() {
+        let registry = init_builtins(vec![]);
+        let listener = Rc::new(RecordingListener::default());
+
+        let re_match = lookup(&registry, &["modules", "re", "match"], listener.clone());
+        let ret = re_match
+            .call(
+                &[Type::String(None), Type::String(None)],
+                &BTreeMap::new(),
+                listener.clone(),
+            )
+            .unwrap();
+        assert!(ret.is_optional());
+        assert!(listener.warnings.borrow().is_empty());
+
+        // the match object exposes `group`
+        let group = ret
+            .get_non_optional_type()
+            .get_attribute("group", listener.clone())
+            .unwrap()
+            .call(&[Type::Integer(None)], &BTreeMap::new(), listener.clone())
+            .unwrap();
+        assert!(group.is_optional());
+        assert!(listener.warnings.borrow().is_empty());
+
+        let now = lookup(
+            &registry,
+            &["modules", "datetime", "datetime", "now"],
+            listener.clone(),
+        )
+        .call(&[], &BTreeMap::new(), listener.clone())
+        .unwrap();
+        assert!(matches!(now, Type::TimeStamp));
+        assert!(listener.warnings.borrow().is_empty());
+    }
+
+    #[test]
+    fn test_default_filter_echoes_value_type() {
+        let registry = init_builtins(vec![]);
+        let listener = Rc::new(RecordingListener::default());
+        let default = lookup(&registry, &["default"], listener.clone());
+        let call = |args: &[Type]| {
+            default
+                .call(args, &BTreeMap::new(), listener.clone())
+                .unwrap()
+        };
+
+        assert_eq!(
+            call(&[Type::Integer(None), Type::Integer(None)]),
+            Type::Integer(None)
+        );
+        // the implicit default is the empty string
+        assert_eq!(call(&[Type::String(None)]), Type::String(None));
+        // None is replaced by the default
+        let optional_int = Type::Union(UnionType::new([Type::Integer(None), Type::None]));
+        assert_eq!(
+            call(&[optional_int, Type::Integer(None)]),
+            Type::Integer(None)
+        );
+        assert!(listener.warnings.borrow().is_empty());
+
+        // `d` is an alias
+        let d = lookup(&registry, &["d"], listener.clone());
+        let ret = d
+            .call(
+                &[Type::Bool, Type::Bool],
+                &BTreeMap::new(),
+                listener.clone(),
+            )
+            .unwrap();
+        assert_eq!(ret, Type::Bool);
+    }
+
+    #[test]
+    fn test_default_filter_optional_arguments() {
+        let registry = init_builtins(vec![]);
+        let listener = Rc::new(RecordingListener::default());
+        let default = lookup(&registry, &["default"], listener.clone());
+        let call = |args: &[Type], kwargs: &[(&str, Type)]| {
+            let kwargs = kwargs
+                .iter()
+                .map(|(name, ty)| (name.to_string(), ty.clone()))
+                .collect::<BTreeMap<_, _>>();
+            default.call(args, &kwargs, listener.clone()).unwrap()
+        };
+
+        // both optional arguments left out
+        assert_eq!(
+            call(&[Type::Integer(None)], &[]),
+            Type::Integer(None).union(&Type::String(None))
+        );
+        // given by position or by name
+        assert_eq!(
+            call(&[Type::Integer(None), Type::Integer(None), Type::Bool], &[]),
+            Type::Integer(None)
+        );
+        assert_eq!(
+            call(&[Type::Integer(None)], &[("boolean", Type::Bool)]),
+            Type::Integer(None).union(&Type::String(None))
+        );
+        assert!(listener.warnings.borrow().is_empty());
+
+        // an explicit `none` default is not the implicit empty string
+        assert_eq!(
+            call(&[Type::Integer(None), Type::None], &[]),
+            Type::Integer(None).union(&Type::None)
+        );
+        assert!(listener.warnings.borrow().is_empty());
+
+        call(
+            &[Type::Integer(None), Type::Integer(None), Type::String(None)],
+            &[],
+        );
+        assert_eq!(listener.warnings.borrow().len(), 1);
+        assert!(listener.warnings.borrow()[0].contains("boolean"));
+    }
+
+    #[test]
+    fn test_union_argument() {
+        let registry = Arc::new(DashMap::new());
+        let udf = UserDefinedFunctionType::new(
+            "quote",
+            vec![Argument {
+                name: "value".to_string(),
+                type_: parse_type("optional[string]", registry.clone()).unwrap(),
+                is_optional: false,
+            }],
+            Type::String(None),
+            &PathBuf::from(""),
+            &Span::default(),
+            "",
+        );
+        let resolve = |arg: Type| {
+            let listener = Rc::new(RecordingListener::default());
+            let ret = udf
+                .resolve_arguments(&[arg], &BTreeMap::new(), listener.clone())
+                .unwrap();
+            assert_eq!(ret, Type::String(None));
+            listener.warnings.take()
+        };
+
+        // each member of the union, and the union itself, is accepted
+        assert!(resolve(Type::String(None)).is_empty());
+        assert!(resolve(Type::None).is_empty());
+        assert!(resolve(parse_type("string | none", registry.clone()).unwrap()).is_empty());
+
+        // types outside of the union are rejected
+        let warnings = resolve(Type::Integer(None));
+        assert_eq!(warnings.len(), 1);
+        assert!(
+            warnings[0].starts_with("Argument type mismatch"),
+            "{warnings:?}"
+        );
+        let warnings = resolve(parse_type("string | integer", registry).unwrap());
+        assert_eq!(warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_numeric_arguments() {
+        let registry = Arc::new(DashMap::new());
+        let udf = UserDefinedFunctionType::new(
+            "round_to",
+            vec![
+                Argument {
+                    name: "value".to_string(),
+                    type_: parse_type("number", registry.clone()).unwrap(),
+                    is_optional: false,
+                },
+                Argument {
+                    name: "digits".to_string(),
+                    type_: parse_type("integer", registry).unwrap(),
+                    is_optional: false,
+                },
+            ],
+            Type::Float,
+            &PathBuf::from(""),
+            &Span::default(),
+            "",
+        );
+        let resolve = |args: &[Type]| {
+            let listener = Rc::new(RecordingListener::default());
+            udf.resolve_arguments(args, &BTreeMap::new(), listener.clone())
+                .unwrap();
+            listener.warnings.take()
+        };
+
+        // an integer is accepted where a number is expected
+        assert!(resolve(&[Type::Float, Type::Integer(None)]).is_empty());
+        assert!(resolve(&[Type::Integer(Some(3)), Type::Integer(Some(2))]).is_empty());
+
+        // but neither a string nor a float is an integer
+        let warnings = resolve(&[Type::Float, Type::String(None)]);
+        assert_eq!(warnings.len(), 1);
+        assert!(
+            warnings[0].starts_with("Argument type mismatch"),
+            "{warnings:?}"
+        );
+        assert_eq!(resolve(&[Type::Float, Type::Float]).len(), 1);
+        assert_eq!(resolve(&[Type::String(None), Type::Integer(None)]).len(), 1);
+    }
+
+    #[test]
+    fn test_modules_re_match_arity_error() {
+        let registry = init_builtins(vec![]);
+        let listener = Rc::new(RecordingListener::default());
+
+        let re_match = lookup(&registry, &["modules", "re", "match"], listener.clone());
+        let ret = re_match
+            .call(&[Type::String(None)], &BTreeMap::new(), listener.clone())
+            .unwrap();
+        assert!(matches!(ret, Type::Any { hard: false }));
+        assert_eq!(
+            listener.warnings.borrow().as_slice(),
+            ["Missing required argument: string"]
+        );
+    }
+
+    #[test]
+    fn test_modules_re_optional_arguments() {
+        let registry = init_builtins(vec![]);
+        let listener = Rc::new(RecordingListener::default());
+
+        // modules.re.sub(p, r, s, count=1, flags=modules.re.IGNORECASE)
+        let flags = lookup(
+            &registry,
+            &["modules", "re", "IGNORECASE"],
+            listener.clone(),
+        );
+        assert!(matches!(flags, Type::Integer(_)), "{flags:?}");
+        let kwargs = BTreeMap::from([
+            ("count".to_string(), Type::Integer(Some(1))),
+            ("flags".to_string(), flags.clone()),
+        ]);
+        let ret = lookup(&registry, &["modules", "re", "sub"], listener.clone())
+            .call(
+                &[Type::String(None), Type::String(None), Type::String(None)],
+                &kwargs,
+                listener.clone(),
+            )
+            .unwrap();
+        assert!(matches!(ret, Type::String(_)), "{ret:?}");
+
+        let kwargs = BTreeMap::from([
+            ("maxsplit".to_string(), Type::Integer(Some(1))),
+            ("flags".to_string(), flags),
+        ]);
+        let ret = lookup(&registry, &["modules", "re", "split"], listener.clone())
+            .call(
+                &[Type::String(None), Type::String(None)],
+                &kwargs,
+                listener.clone(),
+            )
+            .unwrap();
+        assert!(matches!(ret, Type::List(_)), "{ret:?}");
+
+        // a compiled pattern takes the same arguments minus the pattern
+        let pattern = lookup(&registry, &["modules", "re", "compile"], listener.clone())
+            .call(&[Type::String(None)], &BTreeMap::new(), listener.clone())
+            .unwrap();
+        let ret = pattern
+            .get_attribute("search", listener.clone())
+            .unwrap()
+            .call(&[Type::String(None)], &BTreeMap::new(), listener.clone())
+            .unwrap();
+        assert!(ret.is_optional());
+
+        let ret = lookup(&registry, &["modules", "re", "escape"], listener.clone())
+            .call(&[Type::String(None)], &BTreeMap::new(), listener.clone())
+            .unwrap();
+        assert!(matches!(ret, Type::String(_)), "{ret:?}");
+        assert!(listener.warnings.borrow().is_empty());
+    }
+}
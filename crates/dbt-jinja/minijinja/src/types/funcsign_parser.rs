@@ -260,7 +260,8 @@ fn _parse_type(
         Some(Token::Identifier(id, _location)) => match id.as_str() {
             "string" => Ok((Type::String(None), 1)),
             "integer" => Ok((Type::Integer(None), 1)),
-            "float" => Ok((Type::Float, 1)),
+            // integers are subtypes of float, so `number` accepts both
+            "float" | "number" => Ok((Type::Float, 1)),
             "bool" => Ok((Type::Bool, 1)),
             "bytes" => Ok((Type::Bytes, 1)),
             "seq" | "list" => {
@@ -60,7 +60,7 @@ pub trait FunctionType: Object + Send + Sync + std::fmt::Debug {
                 args.push(value.clone());
                 kwargs.remove(&spec.name);
             } else if spec.is_optional {
-                args.push(Type::None);
+                args.push(self.arg_default(&spec.name).unwrap_or(Type::None));
             } else {
                 listener.warn(&format!("Missing required argument: {}", spec.name));
                 return Ok(Type::Any { hard: false });
@@ -77,6 +77,15 @@ pub trait FunctionType: Object + Send + Sync + std::fmt::Debug {
 
     fn arg_specs(&self) -> Vec<ArgSpec>;
 
+    /// The type of the optional argument `name` when the caller leaves it out.
+    ///
+    /// Optional arguments without a default are passed to
+    /// [FunctionType::_resolve_arguments] as `None`, which can't be told apart
+    /// from an explicit `none`.
+    fn arg_default(&self, _name: &str) -> Option<Type> {
+        None
+    }
+
     fn _resolve_arguments(
         &self,
         actual_arguments: &[Type],
@@ -690,3 +699,49 @@ impl FunctionType for BatchFunctionType {
         ]
     }
 }
+
+/// The `default` filter: `value | default(default_value, boolean)`.
+///
+/// The result has the type of the value, without `None` since that's what the
+/// default replaces, widened by the type of the default value.
+#[derive(Default, Clone, Eq, PartialEq)]
+pub struct DefaultFunctionType;
+
+impl fmt::Debug for DefaultFunctionType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("default")
+    }
+}
+
+impl FunctionType for DefaultFunctionType {
+    fn _resolve_arguments(
+        &self,
+        args: &[Type],
+        listener: Rc<dyn TypecheckingEventListener>,
+    ) -> Result<Type, crate::Error> {
+        if !args[2].is_subtype_of(&Type::Bool) {
+            listener.warn(&format!(
+                "Expected a boolean argument for default function, got {:?}",
+                args[2]
+            ));
+        }
+        let value = args[0].get_non_optional_type();
+        Ok(value.union(&args[1]))
+    }
+
+    fn arg_specs(&self) -> Vec<ArgSpec> {
+        vec![
+            ArgSpec::new("value", false),
+            ArgSpec::new("default_value", true),
+            ArgSpec::new("boolean", true),
+        ]
+    }
+
+    fn arg_default(&self, name: &str) -> Option<Type> {
+        match name {
+            "default_value" => Some(Type::String(None)),
+            "boolean" => Some(Type::Bool),
+            _ => None,
+        }
+    }
+}
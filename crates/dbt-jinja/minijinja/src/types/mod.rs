@@ -300,6 +300,9 @@ impl Type {
             // Integer types are compatible with each other
             (Type::Integer(_), Type::Integer(_)) => true,
 
+            // Integers are accepted where a float (any number) is expected
+            (Type::Integer(_), Type::Float) => true,
+
             // Handle union types - a type is a subtype of a union if it's a subtype of any member
             (type_, Type::Union(UnionType { types })) if !type_.is_union() => {
                 types.iter().any(|ty| type_.is_subtype_of(ty))
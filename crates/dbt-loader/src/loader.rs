@@ -3,6 +3,7 @@ use chrono_tz::Tz;
 use dbt_common::cancellation::CancellationToken;
 use dbt_common::constants::{
     DBT_CATALOGS_YML, DBT_DEPENDENCIES_YML, DBT_PACKAGES_LOCK_FILE, DBT_PACKAGES_YML,
+    DBT_SEED_DATA_LIKE_EXTENSIONS, DBT_SEED_EXTENSIONS,
 };
 use dbt_common::once_cell_vars::DISPATCH_CONFIG;
 use dbt_common::tracing::emit::emit_warn_log_from_fs_error;
@@ -550,11 +551,13 @@ pub async fn load_inner(
         &["csv", "sql"],
         &all_files,
     );
+    // data-like files are kept so that resolving seeds can warn about them
+    let seed_extensions = [DBT_SEED_EXTENSIONS, DBT_SEED_DATA_LIKE_EXTENSIONS].concat();
     let seed_files = find_files_by_kind_and_extension(
         package_path,
         &dbt_project.name,
         &ResourcePathKind::SeedPaths,
-        &["csv", "parquet", "json"],
+        &seed_extensions,
         &all_files,
     );
     let docs_files = find_files_by_kind_and_extension(
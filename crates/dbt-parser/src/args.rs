@@ -38,8 +38,14 @@ pub struct ResolveArgs {
     pub inline_sql: Option<String>,
     /// For remapping unique_is to (database, schema, table) when sampling is enabled
     pub sample_renaming: BTreeMap<String, (String, String, String)>,
+    /// Warn about seeds with more columns than this and no column_types
+    /// (defaults to [DEFAULT_WIDE_SEED_COLUMN_THRESHOLD])
+    pub wide_seed_column_threshold: Option<usize>,
 }
 
+/// Default for [ResolveArgs::wide_seed_column_threshold]
+pub const DEFAULT_WIDE_SEED_COLUMN_THRESHOLD: usize = 256;
+
 impl ResolveArgs {
     /// Produce [ResolveArgs] from a set of [EvalArgs]
     pub fn try_from_eval_args(arg: &EvalArgs) -> FsResult<Self> {
@@ -57,6 +63,7 @@ impl ResolveArgs {
             sample_config: RunFilter::try_from(arg.empty, arg.sample.clone())?,
             inline_sql: None, // Will be set separately when needed
             sample_renaming: arg.sample_renaming.clone(),
+            wide_seed_column_threshold: None,
         })
     }
 
@@ -82,6 +82,7 @@ mod tests {
             sample_config: RunFilter::default(),
             inline_sql: None,
             sample_renaming: BTreeMap::new(),
+            wide_seed_column_threshold: None,
         };
 
         // Create base context with minimal required values
@@ -1,5 +1,7 @@
 /// Utilities for inferring model primary keys from constraints and tests
 pub(crate) mod primary_key_inference;
+/// Functions for fetching seeds configured with a url
+pub(crate) mod remote_seeds;
 /// Functions for resolving analyses
 pub mod resolve_analyses;
 /// Functions for resolving exposures
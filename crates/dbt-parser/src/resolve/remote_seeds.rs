@@ -0,0 +1,249 @@
+use dbt_common::constants::DBT_SEED_EXTENSIONS;
+use dbt_common::{ErrorCode, FsResult, err, fs_err};
+use dbt_serde_yaml::Value as YmlValue;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{LazyLock, Mutex};
+
+/// The url each downloaded seed file was fetched from in this run, so that a
+/// seed is only downloaded once however many times it is resolved.
+static DOWNLOADED_SEEDS: LazyLock<Mutex<HashMap<PathBuf, String>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// The raw (unrendered) `url` in the config of a seed properties entry, if
+/// it has one.
+pub(crate) fn seed_url(schema_value: &YmlValue) -> Option<&str> {
+    schema_value
+        .as_mapping()?
+        .get("config")?
+        .as_mapping()?
+        .get("url")?
+        .as_str()
+}
+
+/// Validate the url of a remote seed and return the seed format (the
+/// extension of the last path segment, e.g. `csv`).
+pub(crate) fn remote_seed_extension(url: &str) -> FsResult<String> {
+    let Some(rest) = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+    else {
+        return err!(
+            ErrorCode::InvalidConfig,
+            "Invalid seed url '{url}': only http:// and https:// urls are supported"
+        );
+    };
+    let path = rest.split(['?', '#']).next().unwrap_or_default();
+    let extension = path
+        .rsplit('/')
+        .next()
+        .and_then(|segment| Path::new(segment).extension())
+        .map(|ext| ext.to_string_lossy().to_ascii_lowercase())
+        .unwrap_or_default();
+    if !DBT_SEED_EXTENSIONS.contains(&extension.as_str()) {
+        return err!(
+            ErrorCode::UnsupportedFileExtension,
+            "Invalid seed url '{url}': the path must end in .csv, .parquet or .json"
+        );
+    }
+    Ok(extension)
+}
+
+/// Download the seed `seed_name` from `url` into `cache_dir`, reusing the file
+/// if it was already downloaded in this run.
+///
+/// Returns the path of the file relative to `cache_dir`, laid out the way a
+/// local seed would be (parquet seeds are named after their directory).
+pub(crate) fn fetch_remote_seed(url: &str, seed_name: &str, cache_dir: &Path) -> FsResult<PathBuf> {
+    fetch_remote_seed_with(url, seed_name, cache_dir, download)
+}
+
+/// Like [fetch_remote_seed], but fetching the bytes with `download`.
+fn fetch_remote_seed_with(
+    url: &str,
+    seed_name: &str,
+    cache_dir: &Path,
+    download: impl FnOnce(&str) -> FsResult<Vec<u8>>,
+) -> FsResult<PathBuf> {
+    let extension = remote_seed_extension(url)?;
+    let path = if extension == "parquet" {
+        Path::new(seed_name).join(format!("{seed_name}.parquet"))
+    } else {
+        PathBuf::from(format!("{seed_name}.{extension}"))
+    };
+    let full_path = cache_dir.join(&path);
+
+    let already_downloaded = DOWNLOADED_SEEDS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(&full_path)
+        .is_some_and(|u| u == url);
+    if already_downloaded && full_path.exists() {
+        return Ok(path);
+    }
+    // the lock is not held while downloading, so other seeds download in parallel
+    let contents = download(url)?;
+    std::fs::create_dir_all(full_path.parent().unwrap_or(cache_dir))
+        .and_then(|_| std::fs::write(&full_path, contents))
+        .map_err(|e| {
+            fs_err!(
+                ErrorCode::IoError,
+                "Failed to cache seed downloaded from '{url}': {e}"
+            )
+        })?;
+    DOWNLOADED_SEEDS
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(full_path, url.to_string());
+    Ok(path)
+}
+
+#[cfg(feature = "remote-seeds")]
+fn download(url: &str) -> FsResult<Vec<u8>> {
+    let mut response = ureq::get(url).call().map_err(|e| {
+        fs_err!(
+            ErrorCode::RemoteError,
+            "Failed to download seed from '{url}': {e}"
+        )
+    })?;
+    response.body_mut().read_to_vec().map_err(|e| {
+        fs_err!(
+            ErrorCode::RemoteError,
+            "Failed to download seed from '{url}': {e}"
+        )
+    })
+}
+
+#[cfg(not(feature = "remote-seeds"))]
+fn download(url: &str) -> FsResult<Vec<u8>> {
+    err!(
+        ErrorCode::NotSupported,
+        "Cannot download seed from '{url}': this build does not support remote seeds \
+         (enable the `remote-seeds` feature)"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_remote_seed_extension() {
+        assert_eq!(
+            remote_seed_extension("https://example.com/data/customers.csv").unwrap(),
+            "csv"
+        );
+        assert_eq!(
+            remote_seed_extension("http://example.com/orders.PARQUET?token=abc#x").unwrap(),
+            "parquet"
+        );
+
+        let error = remote_seed_extension("s3://bucket/customers.csv").unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidConfig);
+        assert!(error.to_string().contains("s3://bucket/customers.csv"));
+
+        let error = remote_seed_extension("https://example.com/customers.xlsx").unwrap_err();
+        assert_eq!(error.code, ErrorCode::UnsupportedFileExtension);
+        let error = remote_seed_extension("https://example.com/").unwrap_err();
+        assert_eq!(error.code, ErrorCode::UnsupportedFileExtension);
+    }
+
+    #[test]
+    fn test_seed_url() {
+        let value: YmlValue = dbt_serde_yaml::from_str(
+            "name: customers\nconfig:\n  url: https://example.com/customers.csv\n",
+        )
+        .unwrap();
+        assert_eq!(seed_url(&value), Some("https://example.com/customers.csv"));
+
+        let value: YmlValue = dbt_serde_yaml::from_str("name: customers\n").unwrap();
+        assert_eq!(seed_url(&value), None);
+    }
+
+    #[test]
+    fn test_fetch_remote_seed_with() {
+        let cache_dir = tempfile::TempDir::new().unwrap();
+        let url = "https://example.com/seeds/orders.csv";
+        let downloads = std::cell::Cell::new(0);
+        let download = |_: &str| {
+            downloads.set(downloads.get() + 1);
+            Ok(b"id\n1\n".to_vec())
+        };
+        let path = fetch_remote_seed_with(url, "orders", cache_dir.path(), download).unwrap();
+        assert_eq!(path, PathBuf::from("orders.csv"));
+        assert_eq!(
+            std::fs::read(cache_dir.path().join(&path)).unwrap(),
+            b"id\n1\n"
+        );
+        // the second fetch in the same run reuses the download
+        fetch_remote_seed_with(url, "orders", cache_dir.path(), download).unwrap();
+        assert_eq!(downloads.get(), 1);
+
+        let path = fetch_remote_seed_with(
+            "https://example.com/events.parquet",
+            "events",
+            cache_dir.path(),
+            |_| Ok(vec![]),
+        )
+        .unwrap();
+        assert_eq!(path, PathBuf::from("events/events.parquet"));
+
+        // the url is validated before downloading anything
+        let error = fetch_remote_seed_with(
+            "ftp://example.com/orders.csv",
+            "ftp_orders",
+            cache_dir.path(),
+            |_| panic!("downloaded an invalid url"),
+        )
+        .unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidConfig);
+
+        let error = fetch_remote_seed_with(
+            "https://example.com/missing.csv",
+            "missing",
+            cache_dir.path(),
+            |url| {
+                err!(
+                    ErrorCode::RemoteError,
+                    "Failed to download seed from '{url}'"
+                )
+            },
+        )
+        .unwrap_err();
+        assert_eq!(error.code, ErrorCode::RemoteError);
+        assert!(!cache_dir.path().join("missing.csv").exists());
+    }
+
+    #[cfg(feature = "remote-seeds")]
+    #[test]
+    fn test_fetch_remote_seed() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", "/seeds/customers.csv")
+            .with_body("id,name\n1,alice\n2,bob\n")
+            .expect(1)
+            .create();
+        let missing = server
+            .mock("GET", "/seeds/missing.csv")
+            .with_status(404)
+            .create();
+
+        let cache_dir = tempfile::TempDir::new().unwrap();
+        let url = format!("{}/seeds/customers.csv", server.url());
+        let path = fetch_remote_seed(&url, "customers", cache_dir.path()).unwrap();
+        assert_eq!(path, PathBuf::from("customers.csv"));
+        assert_eq!(
+            std::fs::read_to_string(cache_dir.path().join(&path)).unwrap(),
+            "id,name\n1,alice\n2,bob\n"
+        );
+        // the second fetch in the same run reuses the download
+        fetch_remote_seed(&url, "customers", cache_dir.path()).unwrap();
+        mock.assert();
+
+        let url = format!("{}/seeds/missing.csv", server.url());
+        let error = fetch_remote_seed(&url, "missing", cache_dir.path()).unwrap_err();
+        assert_eq!(error.code, ErrorCode::RemoteError);
+        assert!(error.to_string().contains(&url));
+        missing.assert();
+    }
+}
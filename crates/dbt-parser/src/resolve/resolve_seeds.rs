@@ -1,34 +1,355 @@
-use crate::args::ResolveArgs;
+use crate::args::{DEFAULT_WIDE_SEED_COLUMN_THRESHOLD, ResolveArgs};
 use crate::dbt_project_config::{RootProjectConfigs, init_project_config};
 use crate::utils::{
     RelationComponents, get_node_fqn, register_duplicate_resource, trigger_duplicate_errors,
     update_node_relation_components,
 };
 use dbt_common::adapter::AdapterType;
-use dbt_common::tracing::emit::emit_error_log_from_fs_error;
-use dbt_common::{ErrorCode, FsResult, fs_err, stdfs};
+use dbt_common::constants::{DBT_SEED_DATA_LIKE_EXTENSIONS, DBT_SEED_EXTENSIONS};
+use dbt_common::tracing::emit::{emit_error_log_from_fs_error, emit_warn_log_message};
+use dbt_common::{ErrorCode, FsResult, err, fs_err, stdfs};
 use dbt_frontend_common::Dialect;
+use dbt_fusion_adapter::base_adapter::backend_of;
 use dbt_jinja_utils::jinja_environment::JinjaEnv;
 use dbt_jinja_utils::node_resolver::NodeResolver;
 use dbt_jinja_utils::serde::into_typed_with_jinja;
 use dbt_jinja_utils::utils::dependency_package_name_from_ctx;
 use dbt_schemas::dbt_utils::validate_delimiter;
-use dbt_schemas::schemas::common::{DbtChecksum, DbtMaterialization, DbtQuoting, NodeDependsOn};
+use dbt_schemas::schemas::common::{
+    DbtChecksum, DbtMaterialization, DbtQuoting, DbtUniqueKey, NodeDependsOn,
+};
 use dbt_schemas::schemas::dbt_column::process_columns;
 use dbt_schemas::schemas::project::DefaultTo;
 use dbt_schemas::schemas::project::{DbtProject, SeedConfig};
 use dbt_schemas::schemas::properties::SeedProperties;
 use dbt_schemas::schemas::{CommonAttributes, DbtSeed, DbtSeedAttr, NodeBaseAttributes};
-use dbt_schemas::state::{DbtPackage, GenericTestAsset};
+use dbt_schemas::state::{DbtAsset, DbtPackage, GenericTestAsset};
 use dbt_schemas::state::{ModelStatus, NodeResolverTracker};
+use dbt_serde_yaml::{Spanned, Value as YmlValue};
+use dbt_xdbc::Backend;
+use dbt_xdbc::sql::types::SqlType;
 use minijinja::value::Value as MinijinjaValue;
+use parquet::arrow::parquet_to_arrow_schema;
+use parquet::file::metadata::{ParquetMetaData, ParquetMetaDataReader};
 use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use super::remote_seeds::{fetch_remote_seed, seed_url};
 use super::resolve_properties::MinimalPropertiesEntry;
 use super::resolve_tests::persist_generic_data_tests::TestableNodeTrait;
 
+/// The settings used to read a seed file, as recorded in the manifest.
+///
+/// The typed [DbtSeedAttr] fields remain authoritative; these are passed through
+/// so that downstream consumers don't have to re-derive the defaults. Only
+/// settings that are actually resolved from the seed config are recorded. Keys
+/// must not clash with the flattened [DbtSeedAttr] fields (hence
+/// `resolved_delimiter`).
+fn seed_format_settings(extension: &str, delimiter: Option<String>) -> BTreeMap<String, YmlValue> {
+    let string = |s: &str| YmlValue::String(s.to_string(), Default::default());
+    let mut settings = BTreeMap::new();
+    settings.insert("format".to_string(), string(extension));
+    if extension == "csv" {
+        settings.insert(
+            "resolved_delimiter".to_string(),
+            string(delimiter.as_deref().unwrap_or(",")),
+        );
+    }
+    settings
+}
+
+/// Where the data of a seed comes from.
+enum SeedSource<'a> {
+    /// A file in the seed paths of the package.
+    File(&'a DbtAsset),
+    /// The name of a seed whose properties have a `url` to download it from.
+    Remote(String),
+}
+
+/// The name of the seed stored at `path`: parquet seeds are named after
+/// their directory, everything else after the file.
+fn seed_name_from_path(path: &Path) -> &str {
+    let path = if path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("parquet"))
+    {
+        path.parent().unwrap()
+    } else {
+        path
+    };
+    path.file_stem().unwrap().to_str().unwrap()
+}
+
+/// The column names in the header (first line) of a CSV seed, unquoted.
+fn csv_header_column_names(contents: &[u8], delimiter: u8) -> Vec<String> {
+    let contents = contents.strip_prefix(b"\xef\xbb\xbf").unwrap_or(contents);
+    let mut names = vec![];
+    let mut name = vec![];
+    let mut in_quotes = false;
+    let mut previous = None;
+    for &b in contents {
+        match b {
+            // a doubled quote inside a quoted name is a literal quote
+            b'"' if !in_quotes && previous == Some(b'"') => {
+                name.push(b'"');
+                in_quotes = true;
+            }
+            b'"' => in_quotes = !in_quotes,
+            b'\n' if !in_quotes => break,
+            b'\r' if !in_quotes => {}
+            _ if b == delimiter && !in_quotes => {
+                names.push(String::from_utf8_lossy(&name).into_owned());
+                name.clear();
+            }
+            _ => name.push(b),
+        }
+        previous = Some(b);
+    }
+    names.push(String::from_utf8_lossy(&name).into_owned());
+    names
+}
+
+/// Count the `(rows, columns)` of a CSV seed, not counting the header row or
+/// blank lines.
+fn csv_seed_dimensions(contents: &[u8], delimiter: u8) -> (usize, usize) {
+    let mut lines = 0;
+    let mut line_has_content = false;
+    let mut in_quotes = false;
+    for &b in contents {
+        match b {
+            b'"' => {
+                in_quotes = !in_quotes;
+                line_has_content = true;
+            }
+            b'\n' if !in_quotes => {
+                if line_has_content {
+                    lines += 1;
+                }
+                line_has_content = false;
+            }
+            b'\r' => {}
+            _ => line_has_content = true,
+        }
+    }
+    if line_has_content {
+        lines += 1;
+    }
+    (
+        lines.saturating_sub(1),
+        csv_header_column_names(contents, delimiter).len(),
+    )
+}
+
+/// The checksum of a parquet seed: the SHA256 of the raw file bytes, whatever
+/// the size of the file. The file is streamed through the hasher rather than
+/// read in memory, so large parquet seeds are still checksummed by content
+/// (csv and json seeds over 1MB fall back to a path-based checksum instead).
+fn parquet_seed_checksum(path: &Path) -> FsResult<DbtChecksum> {
+    std::fs::File::open(path)
+        .and_then(|file| DbtChecksum::hash_reader(std::io::BufReader::new(file)))
+        .map_err(|e| fs_err!(ErrorCode::IoError, "Failed to read seed file: {}", e))
+}
+
+/// Read the footer of a parquet seed, without reading any of the row groups.
+/// Returns `None` if the footer can't be read; a broken file is reported when
+/// the seed is loaded.
+fn read_parquet_seed_footer(path: &Path) -> Option<ParquetMetaData> {
+    let file = std::fs::File::open(path).ok()?;
+    ParquetMetaDataReader::new().parse_and_finish(&file).ok()
+}
+
+/// Count the `(rows, columns)` of a parquet seed from its `footer`.
+fn parquet_seed_dimensions(footer: &ParquetMetaData) -> Option<(usize, usize)> {
+    let file_metadata = footer.file_metadata();
+    Some((
+        usize::try_from(file_metadata.num_rows()).ok()?,
+        file_metadata.schema().get_fields().len(),
+    ))
+}
+
+/// The column names of a parquet seed, from its `footer`.
+fn parquet_seed_column_names(footer: &ParquetMetaData) -> Vec<String> {
+    footer
+        .file_metadata()
+        .schema()
+        .get_fields()
+        .iter()
+        .map(|field| field.name().to_string())
+        .collect()
+}
+
+/// Key the `column_types` of a seed by the column names Snowflake stores.
+///
+/// Snowflake folds unquoted names to uppercase, but the columns of a seed with
+/// `quote_columns` are created with quoted names, so unquoted keys keep their
+/// case. Quoted keys are unquoted either way.
+fn snowflake_column_types(
+    column_types: &BTreeMap<Spanned<String>, String>,
+    quote_columns: bool,
+) -> FsResult<BTreeMap<Spanned<String>, String>> {
+    column_types
+        .iter()
+        .map(|(k, v)| {
+            let name = if quote_columns && !k.starts_with(Dialect::Snowflake.quote_char()) {
+                k.as_str().to_string()
+            } else {
+                Dialect::Snowflake
+                    .parse_identifier(k.as_str())
+                    .map_err(|e| {
+                        fs_err!(
+                            code => ErrorCode::InvalidColumnReference,
+                            loc => k.span().clone(),
+                            "Invalid identifier: {e}",
+                        )
+                    })?
+                    .to_value()
+            };
+            Ok((name.into(), v.to_owned()))
+        })
+        .collect()
+}
+
+/// Name the `inferred` column types of a seed after the columns Snowflake
+/// stores, like [snowflake_column_types] does for the configured ones. The
+/// inferred names are the literal column names of the file, so they are only
+/// folded to uppercase, never parsed as identifiers.
+fn snowflake_inferred_column_types(
+    inferred: Vec<(String, String)>,
+    quote_columns: bool,
+) -> Vec<(String, String)> {
+    if quote_columns {
+        return inferred;
+    }
+    inferred
+        .into_iter()
+        .map(|(name, sql_type)| (name.to_uppercase(), sql_type))
+        .collect()
+}
+
+/// The SQL type of every column of a parquet seed, derived from the logical
+/// types in its `footer` (decimal precision and scale, timestamp units, etc.).
+/// Returns `None` if a column has no SQL type.
+fn parquet_seed_column_types(
+    footer: &ParquetMetaData,
+    backend: Backend,
+) -> Option<Vec<(String, String)>> {
+    let file_metadata = footer.file_metadata();
+    let schema = parquet_to_arrow_schema(
+        file_metadata.schema_descr(),
+        file_metadata.key_value_metadata(),
+    )
+    .ok()?;
+    let columns = SqlType::from_arrow_schema(backend, &schema).ok()?;
+    Some(
+        columns
+            .into_iter()
+            .map(|(name, sql_type, _)| (name, sql_type.to_string(backend)))
+            .collect(),
+    )
+}
+
+/// Add the `inferred` column types to the configured `column_types`. Columns
+/// that already have a configured type (compared case-insensitively) keep it.
+fn merge_inferred_column_types(
+    column_types: &mut Option<BTreeMap<Spanned<String>, String>>,
+    inferred: Vec<(String, String)>,
+) {
+    let column_types = column_types.get_or_insert_default();
+    for (name, sql_type) in inferred {
+        if !column_types
+            .keys()
+            .any(|configured| configured.eq_ignore_ascii_case(&name))
+        {
+            column_types.insert(name.into(), sql_type);
+        }
+    }
+}
+
+/// Record the `(rows, columns)` summary of a seed in its passthrough settings.
+fn insert_seed_dimensions(
+    settings: &mut BTreeMap<String, YmlValue>,
+    (rows, columns): (usize, usize),
+) {
+    let number = |n: usize| YmlValue::Number((n as u64).into(), Default::default());
+    settings.insert("row_count".to_string(), number(rows));
+    settings.insert("column_count".to_string(), number(columns));
+}
+
+/// The warning for a seed with too many columns to infer their types cheaply.
+fn wide_untyped_seed_warning(
+    seed_name: &str,
+    column_count: usize,
+    threshold: usize,
+    has_column_types: bool,
+) -> Option<String> {
+    (!has_column_types && column_count > threshold).then(|| {
+        format!(
+            "Seed '{seed_name}' has {column_count} columns (more than {threshold}) and no column_types. \
+             Inferring their types is expensive: consider declaring column_types or \
+             whether this data belongs in a seed."
+        )
+    })
+}
+
+/// The warning for a file in the seed paths that looks like data but has an
+/// extension other than csv, parquet or json.
+fn unsupported_seed_extension_warning(path: &Path, extension: &str) -> Option<String> {
+    DBT_SEED_DATA_LIKE_EXTENSIONS.contains(&extension).then(|| {
+        format!(
+            "Skipping '{}': seeds must be csv, parquet or json files, not '.{extension}'",
+            path.display()
+        )
+    })
+}
+
+/// Check that the columns of the `unique_key` of a seed are among its
+/// `columns`: the ones declared in its properties or, when it declares none,
+/// the ones in the seed file. Nothing is checked if neither is known.
+fn validate_seed_unique_key(
+    seed_name: &str,
+    unique_key: Option<&DbtUniqueKey>,
+    columns: &[String],
+) -> FsResult<()> {
+    let Some(unique_key) = unique_key else {
+        return Ok(());
+    };
+    if columns.is_empty() {
+        return Ok(());
+    }
+    for key_column in unique_key.columns() {
+        if !columns
+            .iter()
+            .any(|column| column.eq_ignore_ascii_case(&key_column))
+        {
+            return err!(
+                ErrorCode::InvalidConfig,
+                "Seed '{seed_name}' has unique_key column '{key_column}', which is not one of its columns"
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Check that `seed_name` is safe to use in a unique id and FQN. Seed names
+/// come from file names, which may contain `\` (a path separator on
+/// Windows) or control characters on some platforms.
+fn validate_seed_name(seed_name: &str) -> FsResult<()> {
+    if matches!(seed_name, "" | "." | "..")
+        || seed_name.contains(['/', '\\'])
+        || seed_name.chars().any(char::is_control)
+    {
+        return err!(
+            ErrorCode::InvalidPath,
+            "Invalid seed name '{}': seed names can't contain path separators or control characters",
+            seed_name.escape_debug()
+        );
+    }
+    Ok(())
+}
+
 #[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn resolve_seeds(
     arg: &ResolveArgs,
@@ -62,31 +383,69 @@ pub fn resolve_seeds(
         dependency_package_name,
     )?;
 
+    // Seeds with a `url` and no file in the project are downloaded into the
+    // target directory once their config says they're enabled, and then
+    // resolved like any other seed
+    let local_seed_names = package
+        .seed_files
+        .iter()
+        .map(|seed_file| seed_name_from_path(&seed_file.path))
+        .collect::<HashSet<_>>();
+    let remote_seed_names = seed_properties
+        .iter()
+        .filter(|(seed_name, mpe)| {
+            !local_seed_names.contains(seed_name.as_str()) && seed_url(&mpe.schema_value).is_some()
+        })
+        .map(|(seed_name, _)| seed_name.clone())
+        .collect::<Vec<_>>();
+    let remote_seeds_dir = io_args.out_dir.join("remote_seeds").join(package_name);
+
     // TODO: update this to be relative of the root project
     let mut duplicate_errors = Vec::new();
-    for seed_file in package.seed_files.iter() {
-        // Validate that path extension is one of csv, parquet, or json
-        let path = seed_file.path.clone();
-        let path_extension = path.extension().unwrap_or_default().to_ascii_lowercase();
-        if path_extension != "csv" && path_extension != "parquet" && path_extension != "json" {
+    let seed_sources = package
+        .seed_files
+        .iter()
+        .map(SeedSource::File)
+        .chain(remote_seed_names.into_iter().map(SeedSource::Remote));
+    for seed_source in seed_sources {
+        let (seed_name, location) = match &seed_source {
+            SeedSource::File(seed_file) => {
+                // Validate that path extension is one of csv, parquet, or json
+                let path = &seed_file.path;
+                let path_extension = path.extension().unwrap_or_default().to_ascii_lowercase();
+                let extension = path_extension.to_string_lossy();
+                if !DBT_SEED_EXTENSIONS.contains(&extension.as_ref()) {
+                    if let Some(warning) = unsupported_seed_extension_warning(path, &extension) {
+                        emit_warn_log_message(
+                            ErrorCode::UnsupportedFileExtension,
+                            warning,
+                            io_args,
+                        );
+                    }
+                    continue;
+                }
+                (seed_name_from_path(path).to_owned(), path.clone())
+            }
+            SeedSource::Remote(seed_name) => (
+                seed_name.clone(),
+                seed_properties[seed_name].relative_path.clone(),
+            ),
+        };
+        let seed_name = seed_name.as_str();
+        if let Err(e) = validate_seed_name(seed_name) {
+            emit_error_log_from_fs_error(&e.with_location(location), io_args);
             continue;
         }
-
-        let seed_name = if path_extension == "parquet" {
-            path.parent()
-                .unwrap()
-                .file_stem()
-                .unwrap()
-                .to_str()
-                .unwrap()
-        } else {
-            path.file_stem().unwrap().to_str().unwrap()
-        };
         let unique_id = format!("seed.{package_name}.{seed_name}");
 
+        // remote seeds are not in a seed path, so their fqn is just their name
+        let fqn_path = match &seed_source {
+            SeedSource::File(seed_file) => seed_file.path.clone(),
+            SeedSource::Remote(_) => PathBuf::from(seed_name),
+        };
         let fqn = get_node_fqn(
             package_name,
-            path.to_owned(),
+            fqn_path,
             vec![seed_name.to_owned()],
             package.dbt_project.seed_paths.as_ref().unwrap_or(&vec![]),
         );
@@ -122,54 +481,147 @@ pub fn resolve_seeds(
             project_config.clone()
         };
 
-        // XXX: normalize column_types to uppercase if it is snowflake
-        if matches!(adapter_type, AdapterType::Snowflake)
-            && let Some(column_types) = &properties_config.column_types
-        {
-            let column_types = column_types
-                .iter()
-                .map(|(k, v)| {
-                    Ok((
-                        Dialect::Snowflake
-                            .parse_identifier(k.as_str())
-                            .map_err(|e| {
-                                fs_err!(
-                                    code => ErrorCode::InvalidColumnReference,
-                                    loc => k.span().clone(),
-                                    "Invalid identifier: {e}",
-                                )
-                            })?
-                            .to_value()
-                            .into(),
-                        v.to_owned(),
-                    ))
-                })
-                .collect::<FsResult<_>>()?;
-
-            properties_config.column_types = Some(column_types);
-        }
-
         if package_name != root_project.name {
             let mut root_config = root_project_configs.seeds.get_config_for_fqn(&fqn).clone();
             root_config.default_to(&properties_config);
             properties_config = root_config;
         }
 
+        // XXX: normalize column_types to uppercase if it is snowflake
+        if matches!(adapter_type, AdapterType::Snowflake)
+            && let Some(column_types) = &properties_config.column_types
+        {
+            properties_config.column_types = Some(snowflake_column_types(
+                column_types,
+                properties_config.quote_columns.unwrap_or(false),
+            )?);
+        }
+
         let is_enabled = properties_config.get_enabled().unwrap_or(true);
 
+        let seed_file = match seed_source {
+            SeedSource::File(seed_file) => seed_file.clone(),
+            // disabled remote seeds are never downloaded, so they're left out
+            SeedSource::Remote(_) if !is_enabled => continue,
+            SeedSource::Remote(_) => {
+                // the url is rendered with the rest of the properties (and is
+                // never inherited from the project config)
+                let url = seed
+                    .config
+                    .as_ref()
+                    .and_then(|config| config.url.as_deref())
+                    .unwrap_or_default();
+                match fetch_remote_seed(url, seed_name, &remote_seeds_dir) {
+                    Ok(path) => DbtAsset {
+                        base_path: remote_seeds_dir.clone(),
+                        path,
+                        package_name: package_name.to_owned(),
+                    },
+                    Err(e) => {
+                        emit_error_log_from_fs_error(&e.with_location(location), io_args);
+                        continue;
+                    }
+                }
+            }
+        };
+        let path = seed_file.path.clone();
+        let path_extension = path.extension().unwrap_or_default().to_ascii_lowercase();
+
         let columns = process_columns(
             seed.columns.as_ref(),
             properties_config.meta.clone(),
             properties_config.tags.clone().map(|tags| tags.into()),
         )?;
 
-        validate_delimiter(&properties_config.delimiter)?;
+        let delimiter = validate_delimiter(&properties_config.delimiter)?;
 
         // Calculate original file path first so we can use it for the checksum
         // if necessary for large seeds
         let original_file_path =
             stdfs::diff_paths(seed_file.base_path.join(&path), &io_args.in_dir)?;
 
+        let full_path = seed_file.base_path.join(&path);
+        // Parquet seeds are never read in full (see [parquet_seed_checksum])
+        let seed_contents = if path_extension == "parquet" {
+            None
+        } else {
+            Some(
+                std::fs::read(&full_path)
+                    .map_err(|e| fs_err!(ErrorCode::IoError, "Failed to read seed file: {}", e))?,
+            )
+        };
+        let checksum = match &seed_contents {
+            Some(contents) => DbtChecksum::seed_file_hash(
+                contents.as_slice(),
+                &original_file_path.to_string_lossy(),
+            ),
+            None => parquet_seed_checksum(&full_path)?,
+        };
+
+        let mut settings =
+            seed_format_settings(&path_extension.to_string_lossy(), delimiter.clone());
+        // only the declared column_types, before any are inferred
+        let has_column_types = properties_config
+            .column_types
+            .as_ref()
+            .is_some_and(|column_types| !column_types.is_empty());
+        // the columns in the seed file, where the format has a header
+        let mut file_column_names = vec![];
+        let mut dimensions = None;
+        if path_extension == "csv"
+            && let Some(seed_contents) = &seed_contents
+        {
+            let delimiter = delimiter.as_deref().map_or(b',', |d| d.as_bytes()[0]);
+            file_column_names = csv_header_column_names(seed_contents, delimiter);
+            dimensions = Some(csv_seed_dimensions(seed_contents, delimiter));
+        } else if path_extension == "parquet"
+            && let Some(footer) = read_parquet_seed_footer(&full_path)
+        {
+            dimensions = parquet_seed_dimensions(&footer);
+            file_column_names = parquet_seed_column_names(&footer);
+            // inferred types only fill in the columns without a declared type
+            if let Some(mut inferred) = parquet_seed_column_types(&footer, backend_of(adapter_type))
+            {
+                if matches!(adapter_type, AdapterType::Snowflake) {
+                    inferred = snowflake_inferred_column_types(
+                        inferred,
+                        properties_config.quote_columns.unwrap_or(false),
+                    );
+                }
+                merge_inferred_column_types(&mut properties_config.column_types, inferred);
+            }
+        }
+        if let Some((rows, columns)) = dimensions {
+            insert_seed_dimensions(&mut settings, (rows, columns));
+            if let Some(warning) = wide_untyped_seed_warning(
+                seed_name,
+                columns,
+                arg.wide_seed_column_threshold
+                    .unwrap_or(DEFAULT_WIDE_SEED_COLUMN_THRESHOLD),
+                has_column_types,
+            ) {
+                emit_warn_log_message(ErrorCode::InvalidConfig, warning, io_args);
+            }
+        }
+
+        // disabled seeds are never loaded, so their unique_key doesn't matter
+        if is_enabled {
+            let column_names = if columns.is_empty() {
+                file_column_names
+            } else {
+                columns.iter().map(|column| column.name.clone()).collect()
+            };
+            if let Err(e) = validate_seed_unique_key(
+                seed_name,
+                properties_config.unique_key.as_ref(),
+                &column_names,
+            ) {
+                let location = patch_path.clone().unwrap_or_else(|| path.clone());
+                emit_error_log_from_fs_error(&e.with_location(location), io_args);
+                continue;
+            }
+        }
+
         // Create initial seed with default values
         let mut dbt_seed = DbtSeed {
             __common_attr__: CommonAttributes {
@@ -178,14 +630,7 @@ pub fn resolve_seeds(
                 path: path.to_owned(),
                 name_span: dbt_common::Span::default(),
                 original_file_path: original_file_path.clone(),
-                checksum: DbtChecksum::seed_file_hash(
-                    std::fs::read(seed_file.base_path.join(&path))
-                        .map_err(|e| {
-                            fs_err!(ErrorCode::IoError, "Failed to read seed file: {}", e)
-                        })?
-                        .as_slice(),
-                    &original_file_path.to_string_lossy(),
-                ),
+                checksum,
                 patch_path: patch_path.clone(),
                 unique_id: unique_id.clone(),
                 fqn,
@@ -219,8 +664,11 @@ pub fn resolve_seeds(
                 column_types: properties_config.column_types.clone(),
                 delimiter: properties_config.delimiter.clone().map(|d| d.into_inner()),
                 root_path: Some(seed_file.base_path.clone()),
+                constraints: DbtSeedAttr::unique_key_constraints(
+                    properties_config.unique_key.as_ref(),
+                ),
             },
-            __other__: BTreeMap::new(),
+            __other__: settings,
             deprecated_config: properties_config.clone(),
         };
 
@@ -276,3 +724,732 @@ pub fn resolve_seeds(
     trigger_duplicate_errors(io_args, &mut duplicate_errors)?;
     Ok((seeds, disabled_seeds))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dbt_project_config::build_root_project_configs;
+    use dbt_common::cancellation::never_cancels;
+    use dbt_common::io_args::IoArgs;
+    use dbt_jinja_utils::invocation_args::InvocationArgs;
+    use dbt_jinja_utils::phases::parse::init::initialize_parse_jinja_environment;
+    use dbt_schemas::schemas::profiles::{
+        BigqueryDbConfig, DbConfig, PostgresDbConfig, SnowflakeDbConfig,
+    };
+    use dbt_schemas::schemas::relations::{DEFAULT_DBT_QUOTING, SNOWFLAKE_DBT_QUOTING};
+    use dbt_schemas::schemas::serde::StringOrInteger;
+    use dbt_serde_yaml::Verbatim;
+    use std::collections::BTreeSet;
+
+    fn parse_env(adapter_type: &str, db_config: DbConfig, quoting: DbtQuoting) -> JinjaEnv {
+        initialize_parse_jinja_environment(
+            "common",
+            "profile",
+            "target",
+            adapter_type,
+            db_config,
+            quoting,
+            BTreeMap::new(),
+            BTreeMap::new(),
+            BTreeMap::new(),
+            BTreeMap::new(),
+            chrono::Utc::now().with_timezone(&chrono_tz::Tz::UTC),
+            &InvocationArgs::default(),
+            BTreeSet::from(["common".to_string()]),
+            IoArgs::default(),
+            never_cancels(),
+            None,
+        )
+        .unwrap()
+    }
+
+    fn postgres_config() -> DbConfig {
+        DbConfig::Postgres(Box::new(PostgresDbConfig {
+            port: Some(StringOrInteger::Integer(5432)),
+            database: Some("db".to_string()),
+            host: Some("localhost".to_string()),
+            user: Some("postgres".to_string()),
+            schema: Some("schema".to_string()),
+            ..Default::default()
+        }))
+    }
+
+    /// Run [resolve_seeds] over `files` (paths relative to the `seeds`
+    /// directory and their contents) as the seeds of the `common` package.
+    fn resolve_seed_files(
+        env: &JinjaEnv,
+        adapter_type: AdapterType,
+        quoting: DbtQuoting,
+        files: &[(&str, &[u8])],
+    ) -> HashMap<String, Arc<DbtSeed>> {
+        resolve_seed_files_with_properties(env, adapter_type, quoting, files, &[]).0
+    }
+
+    /// Like [resolve_seed_files], but with the `properties` (a seed name and
+    /// its YAML properties entry) of the seeds, returning the enabled and the
+    /// disabled seeds.
+    fn resolve_seed_files_with_properties(
+        env: &JinjaEnv,
+        adapter_type: AdapterType,
+        quoting: DbtQuoting,
+        files: &[(&str, &[u8])],
+        properties: &[(&str, &str)],
+    ) -> (HashMap<String, Arc<DbtSeed>>, HashMap<String, Arc<DbtSeed>>) {
+        let dir = tempfile::TempDir::new().unwrap();
+        let seed_files = files
+            .iter()
+            .map(|(path, contents)| {
+                let path = Path::new("seeds").join(path);
+                let full_path = dir.path().join(&path);
+                std::fs::create_dir_all(full_path.parent().unwrap()).unwrap();
+                std::fs::write(&full_path, contents).unwrap();
+                DbtAsset {
+                    base_path: dir.path().to_path_buf(),
+                    path,
+                    package_name: "common".to_string(),
+                }
+            })
+            .collect();
+        let project = DbtProject {
+            name: "common".to_string(),
+            version: None,
+            profile: None,
+            analysis_paths: None,
+            asset_paths: None,
+            macro_paths: None,
+            model_paths: None,
+            function_paths: None,
+            seed_paths: Some(vec!["seeds".to_string()]),
+            snapshot_paths: None,
+            test_paths: None,
+            docs_paths: None,
+            target_path: None,
+            log_path: None,
+            packages_install_path: None,
+            metrics: None,
+            models: None,
+            functions: None,
+            snapshots: None,
+            seeds: None,
+            sources: None,
+            tests: None,
+            unit_tests: None,
+            data_tests: None,
+            saved_queries: None,
+            semantic_models: None,
+            exposures: None,
+            analyses: None,
+            clean_targets: None,
+            config_version: None,
+            dbt_cloud: None,
+            dispatch: None,
+            flags: None,
+            on_run_end: Verbatim::from(None),
+            on_run_start: Verbatim::from(None),
+            query_comment: Verbatim::from(None),
+            quoting: Spanned::new(None),
+            require_dbt_version: None,
+            restrict_access: None,
+            vars: Verbatim::from(None),
+        };
+        let package = DbtPackage {
+            dbt_project: project.clone(),
+            package_root_path: dir.path().to_path_buf(),
+            dbt_properties: vec![],
+            analysis_files: vec![],
+            model_sql_files: vec![],
+            function_sql_files: vec![],
+            macro_files: vec![],
+            test_files: vec![],
+            fixture_files: vec![],
+            seed_files,
+            docs_files: vec![],
+            snapshot_files: vec![],
+            inline_file: None,
+            dependencies: BTreeSet::new(),
+            all_paths: HashMap::new(),
+        };
+        let arg = ResolveArgs {
+            io: IoArgs {
+                in_dir: dir.path().to_path_buf(),
+                out_dir: dir.path().join("target"),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let seed_properties = properties
+            .iter()
+            .map(|(name, yaml)| {
+                let entry = MinimalPropertiesEntry {
+                    name: name.to_string(),
+                    name_span: Default::default(),
+                    relative_path: PathBuf::from("seeds/properties.yml"),
+                    schema_value: dbt_serde_yaml::from_str(yaml).unwrap(),
+                    table_value: None,
+                    version_info: None,
+                    duplicate_paths: vec![],
+                };
+                (name.to_string(), entry)
+            })
+            .collect();
+        let root_project_configs = build_root_project_configs(&arg.io, &project, quoting).unwrap();
+        resolve_seeds(
+            &arg,
+            seed_properties,
+            &package,
+            quoting,
+            &project,
+            &root_project_configs,
+            "db",
+            "schema",
+            adapter_type,
+            "common",
+            env,
+            &BTreeMap::new(),
+            &mut vec![],
+            &mut NodeResolver::default(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_resolve_remote_seeds() {
+        let env = parse_env("postgres", postgres_config(), DEFAULT_DBT_QUOTING);
+        let (seeds, disabled_seeds) = resolve_seed_files_with_properties(
+            &env,
+            AdapterType::Postgres,
+            DEFAULT_DBT_QUOTING,
+            &[("orders.csv", b"id\n1\n")],
+            &[
+                // disabled seeds are never downloaded, so their url is not even checked
+                (
+                    "archived",
+                    "name: archived\nconfig:\n  enabled: false\n  url: ftp://example.com/archived.csv\n",
+                ),
+                // a seed that can't be downloaded doesn't fail the other seeds
+                (
+                    "customers",
+                    "name: customers\nconfig:\n  url: ftp://example.com/customers.csv\n",
+                ),
+            ],
+        );
+        assert_eq!(seeds.keys().collect::<Vec<_>>(), ["seed.common.orders"]);
+        assert!(disabled_seeds.is_empty());
+    }
+
+    #[test]
+    fn test_resolve_parquet_seed_column_types() {
+        use parquet::file::properties::WriterProperties;
+        use parquet::file::writer::SerializedFileWriter;
+        use parquet::schema::parser::parse_message_type;
+
+        let schema = parse_message_type(
+            "message seed { REQUIRED INT64 amount (DECIMAL(10, 2)); REQUIRED BYTE_ARRAY note (UTF8); }",
+        )
+        .unwrap();
+        let contents = SerializedFileWriter::new(
+            Vec::new(),
+            Arc::new(schema),
+            Arc::new(WriterProperties::builder().build()),
+        )
+        .unwrap()
+        .into_inner()
+        .unwrap();
+
+        let env = parse_env("postgres", postgres_config(), DEFAULT_DBT_QUOTING);
+        let (seeds, _) = resolve_seed_files_with_properties(
+            &env,
+            AdapterType::Postgres,
+            DEFAULT_DBT_QUOTING,
+            &[("payments/part-0.parquet", contents.as_slice())],
+            &[(
+                "payments",
+                "name: payments\nconfig:\n  column_types:\n    AMOUNT: numeric(12, 4)\n",
+            )],
+        );
+        let seed = &seeds["seed.common.payments"];
+        let column_types = seed
+            .__seed_attr__
+            .column_types
+            .clone()
+            .unwrap()
+            .into_iter()
+            .map(|(k, v)| (k.as_str().to_string(), v))
+            .collect::<BTreeMap<_, _>>();
+        // the declared type wins over the inferred one, whatever its case
+        assert_eq!(
+            column_types,
+            BTreeMap::from([
+                ("AMOUNT".to_string(), "numeric(12, 4)".to_string()),
+                ("note".to_string(), "VARCHAR".to_string()),
+            ])
+        );
+        let number = |n: u64| YmlValue::Number(n.into(), Default::default());
+        assert_eq!(seed.__other__.get("column_count"), Some(&number(2)));
+    }
+
+    #[test]
+    fn test_seed_format_settings() {
+        let string = |s: &str| YmlValue::String(s.to_string(), Default::default());
+
+        let settings = seed_format_settings("csv", Some("|".to_string()));
+        assert_eq!(settings.get("format"), Some(&string("csv")));
+        assert_eq!(settings.get("resolved_delimiter"), Some(&string("|")));
+
+        let settings = seed_format_settings("csv", None);
+        assert_eq!(settings.get("resolved_delimiter"), Some(&string(",")));
+
+        let settings = seed_format_settings("parquet", None);
+        assert_eq!(settings.get("format"), Some(&string("parquet")));
+        assert!(!settings.contains_key("resolved_delimiter"));
+    }
+
+    #[test]
+    fn test_resolve_seeds_format_settings() {
+        let env = parse_env("postgres", postgres_config(), DEFAULT_DBT_QUOTING);
+        let seeds = resolve_seed_files(
+            &env,
+            AdapterType::Postgres,
+            DEFAULT_DBT_QUOTING,
+            &[("customers.csv", b"id,name\n1,a\n2,b\n")],
+        );
+        let settings = &seeds["seed.common.customers"].__other__;
+
+        let string = |s: &str| YmlValue::String(s.to_string(), Default::default());
+        assert_eq!(settings.get("format"), Some(&string("csv")));
+        assert_eq!(settings.get("resolved_delimiter"), Some(&string(",")));
+        // nothing that isn't resolved from the seed config is recorded
+        assert_eq!(
+            settings.keys().map(String::as_str).collect::<BTreeSet<_>>(),
+            BTreeSet::from(["column_count", "format", "resolved_delimiter", "row_count"])
+        );
+    }
+
+    #[test]
+    fn test_wide_untyped_seed_warning() {
+        let header = (0..300)
+            .map(|i| format!("col_{i}"))
+            .collect::<Vec<_>>()
+            .join(",");
+        let csv = format!("{header}\n{}\n", vec!["1"; 300].join(","));
+        let column_count = csv_header_column_names(csv.as_bytes(), b',').len();
+        assert_eq!(column_count, 300);
+
+        assert!(wide_untyped_seed_warning("wide", column_count, 256, false).is_some());
+        assert!(wide_untyped_seed_warning("wide", column_count, 300, false).is_none());
+        // declaring the types silences the warning
+        assert!(wide_untyped_seed_warning("wide", column_count, 256, true).is_none());
+
+        // quoted delimiters don't separate columns
+        let csv = "a,\"b,c\",d\n1,2,3\n";
+        assert_eq!(csv_header_column_names(csv.as_bytes(), b',').len(), 3);
+        assert_eq!(csv_header_column_names(b"a|b\n", b'|').len(), 2);
+    }
+
+    #[test]
+    fn test_wide_untyped_parquet_seed_warning() {
+        use parquet::file::properties::WriterProperties;
+        use parquet::file::writer::SerializedFileWriter;
+        use parquet::schema::parser::parse_message_type;
+
+        // the column count comes from the footer, so no rows are needed
+        let fields = (0..300)
+            .map(|i| format!("OPTIONAL INT32 col_{i};"))
+            .collect::<String>();
+        let schema = parse_message_type(&format!("message seed {{ {fields} }}")).unwrap();
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("wide.parquet");
+        let file = std::fs::File::create(&path).unwrap();
+        SerializedFileWriter::new(
+            file,
+            Arc::new(schema),
+            Arc::new(WriterProperties::builder().build()),
+        )
+        .unwrap()
+        .close()
+        .unwrap();
+
+        let footer = read_parquet_seed_footer(&path).unwrap();
+        let (_, column_count) = parquet_seed_dimensions(&footer).unwrap();
+        assert_eq!(column_count, 300);
+        assert!(wide_untyped_seed_warning("wide", column_count, 256, false).is_some());
+    }
+
+    #[test]
+    fn test_csv_header_column_names() {
+        let csv = "\u{feff}id,\"full, name\",\"say \"\"hi\"\"\"\r\n1,a,b\n";
+        assert_eq!(
+            csv_header_column_names(csv.as_bytes(), b','),
+            ["id", "full, name", "say \"hi\""]
+        );
+        assert_eq!(csv_header_column_names(b"a|b", b'|'), ["a", "b"]);
+    }
+
+    #[test]
+    fn test_csv_seed_dimensions() {
+        let csv = "id,name,note\n1,alice,\"multi\nline\"\r\n2,bob,\n\n";
+        assert_eq!(csv_seed_dimensions(csv.as_bytes(), b','), (2, 3));
+        // no trailing newline
+        assert_eq!(csv_seed_dimensions(b"a|b\n1|2", b'|'), (1, 2));
+        // header only
+        assert_eq!(csv_seed_dimensions(b"a,b\n", b','), (0, 2));
+
+        let mut settings = seed_format_settings("csv", None);
+        insert_seed_dimensions(&mut settings, (2, 3));
+        let number = |n: u64| YmlValue::Number(n.into(), Default::default());
+        assert_eq!(settings.get("row_count"), Some(&number(2)));
+        assert_eq!(settings.get("column_count"), Some(&number(3)));
+    }
+
+    #[test]
+    fn test_parquet_seed_dimensions() {
+        use parquet::data_type::{ByteArray, ByteArrayType, Int32Type};
+        use parquet::file::properties::WriterProperties;
+        use parquet::file::writer::SerializedFileWriter;
+        use parquet::schema::parser::parse_message_type;
+
+        let schema = parse_message_type(
+            "message seed { REQUIRED INT32 id; REQUIRED BYTE_ARRAY name (UTF8); }",
+        )
+        .unwrap();
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("customers.parquet");
+        let file = std::fs::File::create(&path).unwrap();
+        let mut writer = SerializedFileWriter::new(
+            file,
+            Arc::new(schema),
+            Arc::new(WriterProperties::builder().build()),
+        )
+        .unwrap();
+        let mut row_group = writer.next_row_group().unwrap();
+        let mut column = row_group.next_column().unwrap().unwrap();
+        column
+            .typed::<Int32Type>()
+            .write_batch(&[1, 2, 3], None, None)
+            .unwrap();
+        column.close().unwrap();
+        let mut column = row_group.next_column().unwrap().unwrap();
+        let names = ["a", "b", "c"].map(ByteArray::from);
+        column
+            .typed::<ByteArrayType>()
+            .write_batch(&names, None, None)
+            .unwrap();
+        column.close().unwrap();
+        row_group.close().unwrap();
+        writer.close().unwrap();
+
+        let footer = read_parquet_seed_footer(&path).unwrap();
+        assert_eq!(parquet_seed_dimensions(&footer), Some((3, 2)));
+        assert_eq!(parquet_seed_column_names(&footer), ["id", "name"]);
+        assert_eq!(
+            parquet_seed_column_types(&footer, Backend::Postgres),
+            Some(vec![
+                ("id".to_string(), "INT".to_string()),
+                ("name".to_string(), "VARCHAR".to_string()),
+            ])
+        );
+        assert!(read_parquet_seed_footer(&dir.path().join("missing.parquet")).is_none());
+    }
+
+    #[test]
+    fn test_parquet_seed_column_types() {
+        use parquet::data_type::{ByteArray, ByteArrayType, Int64Type};
+        use parquet::file::properties::WriterProperties;
+        use parquet::file::writer::SerializedFileWriter;
+        use parquet::schema::parser::parse_message_type;
+
+        let schema = parse_message_type(
+            "message seed {
+                REQUIRED INT64 amount (DECIMAL(10, 2));
+                REQUIRED INT64 paid_at (TIMESTAMP(MICROS, true));
+                REQUIRED BYTE_ARRAY note (UTF8);
+            }",
+        )
+        .unwrap();
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("payments.parquet");
+        let file = std::fs::File::create(&path).unwrap();
+        let mut writer = SerializedFileWriter::new(
+            file,
+            Arc::new(schema),
+            Arc::new(WriterProperties::builder().build()),
+        )
+        .unwrap();
+        let mut row_group = writer.next_row_group().unwrap();
+        for values in [[1999, 250], [1_700_000_000_000_000, 1_700_000_060_000_000]] {
+            let mut column = row_group.next_column().unwrap().unwrap();
+            column
+                .typed::<Int64Type>()
+                .write_batch(&values, None, None)
+                .unwrap();
+            column.close().unwrap();
+        }
+        let mut column = row_group.next_column().unwrap().unwrap();
+        let notes = ["a", "b"].map(ByteArray::from);
+        column
+            .typed::<ByteArrayType>()
+            .write_batch(&notes, None, None)
+            .unwrap();
+        column.close().unwrap();
+        row_group.close().unwrap();
+        writer.close().unwrap();
+
+        let footer = read_parquet_seed_footer(&path).unwrap();
+        let inferred = parquet_seed_column_types(&footer, Backend::Snowflake).unwrap();
+        assert_eq!(
+            inferred,
+            vec![
+                ("amount".to_string(), "NUMBER(10, 2)".to_string()),
+                ("paid_at".to_string(), "TIMESTAMP_TZ(6)".to_string()),
+                ("note".to_string(), "VARCHAR".to_string()),
+            ]
+        );
+
+        // configured types win over the inferred ones
+        let mut column_types = Some(BTreeMap::from([(
+            Spanned::new("NOTE".to_string()),
+            "VARCHAR(16)".to_string(),
+        )]));
+        merge_inferred_column_types(&mut column_types, inferred.clone());
+        let column_types = column_types
+            .unwrap()
+            .into_iter()
+            .map(|(k, v)| (k.as_str().to_string(), v))
+            .collect::<BTreeMap<_, _>>();
+        assert_eq!(
+            column_types,
+            BTreeMap::from([
+                ("NOTE".to_string(), "VARCHAR(16)".to_string()),
+                ("amount".to_string(), "NUMBER(10, 2)".to_string()),
+                ("paid_at".to_string(), "TIMESTAMP_TZ(6)".to_string()),
+            ])
+        );
+
+        let mut column_types = None;
+        merge_inferred_column_types(&mut column_types, inferred.clone());
+        assert_eq!(column_types.as_ref().unwrap().len(), 3);
+
+        // on snowflake the inferred names are folded like the configured ones
+        let snowflake_keys = |quote_columns| {
+            let configured =
+                BTreeMap::from([(Spanned::new("note".to_string()), "VARCHAR(16)".to_string())]);
+            let mut column_types =
+                Some(snowflake_column_types(&configured, quote_columns).unwrap());
+            merge_inferred_column_types(
+                &mut column_types,
+                snowflake_inferred_column_types(inferred.clone(), quote_columns),
+            );
+            column_types
+                .unwrap()
+                .into_keys()
+                .map(|k| k.as_str().to_string())
+                .collect::<BTreeSet<_>>()
+        };
+        assert_eq!(
+            snowflake_keys(false),
+            BTreeSet::from(["AMOUNT".into(), "NOTE".into(), "PAID_AT".into()])
+        );
+        assert_eq!(
+            snowflake_keys(true),
+            BTreeSet::from(["amount".into(), "note".into(), "paid_at".into()])
+        );
+    }
+
+    #[test]
+    fn test_snowflake_column_types_quote_columns() {
+        let column_types = BTreeMap::from([
+            (Spanned::new("id".to_string()), "INT".to_string()),
+            (
+                Spanned::new("\"Mixed Case\"".to_string()),
+                "TEXT".to_string(),
+            ),
+        ]);
+        let keys = |quote_columns| {
+            snowflake_column_types(&column_types, quote_columns)
+                .unwrap()
+                .into_keys()
+                .map(|k| k.as_str().to_string())
+                .collect::<BTreeSet<_>>()
+        };
+        // unquoted column names are folded to uppercase...
+        assert_eq!(
+            keys(false),
+            BTreeSet::from(["ID".into(), "Mixed Case".into()])
+        );
+        // ...unless the columns are quoted
+        assert_eq!(
+            keys(true),
+            BTreeSet::from(["id".into(), "Mixed Case".into()])
+        );
+
+        let invalid = BTreeMap::from([(Spanned::new("a b".to_string()), "INT".to_string())]);
+        let error = snowflake_column_types(&invalid, false).unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidColumnReference);
+    }
+
+    #[test]
+    fn test_unsupported_seed_extension_warning() {
+        let warning =
+            unsupported_seed_extension_warning(Path::new("seeds/customers.cvs"), "cvs").unwrap();
+        assert!(warning.contains("seeds/customers.cvs"), "{warning}");
+        assert!(warning.contains("'.cvs'"), "{warning}");
+
+        // unrelated files are skipped silently
+        assert!(unsupported_seed_extension_warning(Path::new("seeds/schema.yml"), "yml").is_none());
+        assert!(unsupported_seed_extension_warning(Path::new("seeds/README.md"), "md").is_none());
+    }
+
+    #[test]
+    fn test_seed_relation_name_is_set_for_all_adapters() {
+        let snowflake_config = DbConfig::Snowflake(Box::new(SnowflakeDbConfig {
+            account: Some("account".to_string()),
+            user: Some("user".to_string()),
+            database: Some("db".to_string()),
+            schema: Some("schema".to_string()),
+            ..Default::default()
+        }));
+        let bigquery_config = DbConfig::Bigquery(Box::new(
+            dbt_serde_yaml::from_str::<BigqueryDbConfig>("database: db\nschema: schema\n").unwrap(),
+        ));
+
+        let table = vec![
+            (
+                "postgres",
+                AdapterType::Postgres,
+                postgres_config(),
+                DEFAULT_DBT_QUOTING,
+                r#""db"."schema"."my_seed""#,
+            ),
+            (
+                "snowflake",
+                AdapterType::Snowflake,
+                snowflake_config,
+                SNOWFLAKE_DBT_QUOTING,
+                "db.schema.my_seed",
+            ),
+            (
+                "bigquery",
+                AdapterType::Bigquery,
+                bigquery_config,
+                DEFAULT_DBT_QUOTING,
+                "`db`.`schema`.`my_seed`",
+            ),
+        ];
+        for (name, adapter_type, db_config, quoting, expected) in table {
+            let env = parse_env(name, db_config, quoting);
+            let seeds =
+                resolve_seed_files(&env, adapter_type, quoting, &[("my_seed.csv", b"id\n1\n")]);
+            assert_eq!(
+                seeds["seed.common.my_seed"]
+                    .__base_attr__
+                    .relation_name
+                    .as_deref(),
+                Some(expected),
+                "relation name for {adapter_type}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parquet_seed_checksum() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("events.parquet");
+        // larger than the 1MB above which csv seeds are checksummed by path
+        let mut contents = vec![7u8; 2 * 1024 * 1024];
+        std::fs::write(&path, &contents).unwrap();
+
+        let checksum = parquet_seed_checksum(&path).unwrap();
+        assert_eq!(checksum, DbtChecksum::hash(&contents));
+        assert_eq!(parquet_seed_checksum(&path).unwrap(), checksum);
+
+        contents[1024 * 1024] = 8;
+        std::fs::write(&path, &contents).unwrap();
+        assert_ne!(parquet_seed_checksum(&path).unwrap(), checksum);
+
+        let error = parquet_seed_checksum(&dir.path().join("missing.parquet")).unwrap_err();
+        assert_eq!(error.code, ErrorCode::IoError);
+    }
+
+    #[test]
+    fn test_validate_seed_name() {
+        for path in ["seeds/customers.csv", "seeds/orders/orders.parquet"] {
+            assert!(validate_seed_name(seed_name_from_path(Path::new(path))).is_ok());
+        }
+
+        for path in [
+            "seeds/..\\..\\secrets.csv",
+            "seeds/a\\b/a\\b.parquet",
+            "seeds/new\nline.csv",
+        ] {
+            let seed_name = seed_name_from_path(Path::new(path));
+            let error = validate_seed_name(seed_name).unwrap_err();
+            assert_eq!(error.code, ErrorCode::InvalidPath);
+            assert!(error.to_string().contains("path separators"), "{error}");
+        }
+        assert!(validate_seed_name("..").is_err());
+        assert!(validate_seed_name("../secrets").is_err());
+    }
+
+    #[test]
+    fn test_seed_unique_key() {
+        use dbt_schemas::schemas::common::ConstraintType;
+
+        let columns = ["id".to_string(), "region".to_string()];
+        let unique_key = DbtUniqueKey::Multiple(vec!["ID".to_string(), "region".to_string()]);
+        validate_seed_unique_key("customers", Some(&unique_key), &columns).unwrap();
+        let constraints = DbtSeedAttr::unique_key_constraints(Some(&unique_key));
+        assert_eq!(constraints.len(), 1);
+        assert_eq!(constraints[0].type_, ConstraintType::PrimaryKey);
+        assert_eq!(
+            constraints[0].columns.as_deref(),
+            Some(["ID".to_string(), "region".to_string()].as_slice())
+        );
+
+        let unique_key = DbtUniqueKey::Single("customer_id".to_string());
+        let error = validate_seed_unique_key("customers", Some(&unique_key), &columns).unwrap_err();
+        assert_eq!(error.code, ErrorCode::InvalidConfig);
+        assert!(error.to_string().contains("'customer_id'"), "{error}");
+
+        // without known columns (or a unique key) there is nothing to check
+        validate_seed_unique_key("customers", Some(&unique_key), &[]).unwrap();
+        validate_seed_unique_key("customers", None, &columns).unwrap();
+        assert!(DbtSeedAttr::unique_key_constraints(None).is_empty());
+    }
+
+    #[test]
+    fn test_resolve_seeds_unique_key() {
+        let env = parse_env("postgres", postgres_config(), DEFAULT_DBT_QUOTING);
+        let (seeds, disabled_seeds) = resolve_seed_files_with_properties(
+            &env,
+            AdapterType::Postgres,
+            DEFAULT_DBT_QUOTING,
+            &[
+                ("customers.csv", b"id,name\n1,a\n"),
+                ("orders.csv", b"id,amount\n1,2\n"),
+                ("regions.csv", b"code\nEU\n"),
+                ("archived.csv", b"id\n1\n"),
+            ],
+            &[
+                // checked against the CSV header when no columns are declared
+                ("customers", "name: customers\nconfig:\n  unique_key: ID\n"),
+                // a missing column only fails its own seed
+                ("orders", "name: orders\nconfig:\n  unique_key: order_id\n"),
+                // declared columns take precedence over the header
+                (
+                    "regions",
+                    "name: regions\nconfig:\n  unique_key: code\ncolumns:\n  - name: region_code\n",
+                ),
+                // disabled seeds are not checked
+                (
+                    "archived",
+                    "name: archived\nconfig:\n  enabled: false\n  unique_key: archived_id\n",
+                ),
+            ],
+        );
+        assert_eq!(seeds.keys().collect::<Vec<_>>(), ["seed.common.customers"]);
+        assert_eq!(
+            disabled_seeds.keys().collect::<Vec<_>>(),
+            ["seed.common.archived"]
+        );
+    }
+}
@@ -9,21 +9,25 @@ use dbt_common::tracing::emit::emit_error_log_from_fs_error;
 use dbt_common::{ErrorCode, FsResult, err};
 use dbt_jinja_utils::jinja_environment::JinjaEnv;
 use dbt_jinja_utils::node_resolver::NodeResolver;
-use dbt_jinja_utils::serde::{Omissible, into_typed_with_jinja};
+use dbt_jinja_utils::serde::into_typed_with_jinja;
 use dbt_jinja_utils::utils::generate_relation_name;
 use dbt_schemas::schemas::common::{
-    DbtChecksum, DbtMaterialization, DbtQuoting, FreshnessDefinition, FreshnessRules,
-    NodeDependsOn, merge_meta, merge_tags, normalize_quoting,
+    DbtChecksum, DbtMaterialization, DbtQuoting, NodeDependsOn, deep_merge_meta, merge_tags,
+    normalize_quoting,
 };
 use dbt_schemas::schemas::dbt_column::process_columns;
 use dbt_schemas::schemas::project::{DefaultTo, SourceConfig};
-use dbt_schemas::schemas::properties::{SourceProperties, Tables};
+use dbt_schemas::schemas::properties::{
+    SourceProperties, Tables, merge_enabled, merge_event_time, merge_freshness, merge_loaded_at,
+    merge_quoting, validate_freshness_filter,
+};
 use dbt_schemas::schemas::{CommonAttributes, DbtSource, DbtSourceAttr, NodeBaseAttributes};
 use dbt_schemas::state::{DbtPackage, GenericTestAsset, ModelStatus, NodeResolverTracker};
 use minijinja::Value as MinijinjaValue;
 use regex::Regex;
 use std::collections::BTreeMap;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use super::resolve_properties::MinimalPropertiesEntry;
@@ -70,6 +74,9 @@ pub fn resolve_sources(
         },
         dependency_package_name,
     )?;
+    // every table of a source carries the same source properties, so they
+    // are only validated with its first table
+    let mut validated_sources = HashSet::new();
     for ((source_name, table_name), mpe) in source_properties.into_iter() {
         let source: SourceProperties = into_typed_with_jinja(
             io_args,
@@ -124,10 +131,8 @@ pub fn resolve_sources(
 
         let table_config = table.config.clone().unwrap_or_default();
 
-        let is_enabled = table_config
-            .enabled
-            .or_else(|| source_properties_config.get_enabled())
-            .unwrap_or(true);
+        let is_enabled =
+            merge_enabled(source_properties_config.get_enabled(), table_config.enabled);
 
         let normalized_table_name = special_chars.replace_all(&table_name, "__");
         let unique_id = format!(
@@ -135,20 +140,22 @@ pub fn resolve_sources(
             &package_name, source_name, &normalized_table_name
         );
 
-        let merged_loaded_at_field = Some(
-            table_config
-                .loaded_at_field
-                .clone()
-                .or_else(|| source_properties_config.loaded_at_field.clone())
-                .unwrap_or_default(),
-        );
-        let merged_loaded_at_query = Some(
-            table_config
-                .loaded_at_query
-                .clone()
-                .or_else(|| source_properties_config.loaded_at_query.clone())
-                .unwrap_or_default(),
+        if validated_sources.insert(source_name.clone()) {
+            source.validate_loaded_at()?;
+        }
+        table.validate_loaded_at()?;
+        let (merged_loaded_at_field, merged_loaded_at_query) = merge_loaded_at(
+            (
+                source_properties_config.loaded_at_field.clone(),
+                source_properties_config.loaded_at_query.clone(),
+            ),
+            (
+                table_config.loaded_at_field.clone(),
+                table_config.loaded_at_query.clone(),
+            ),
         );
+        let merged_loaded_at_field = Some(merged_loaded_at_field.unwrap_or_default());
+        let merged_loaded_at_query = Some(merged_loaded_at_query.unwrap_or_default());
         if !merged_loaded_at_field.as_ref().unwrap().is_empty()
             && !merged_loaded_at_query.as_ref().unwrap().is_empty()
         {
@@ -168,11 +175,7 @@ pub fn resolve_sources(
             .quoting
             .expect("quoting should be set");
 
-        let mut source_quoting = source.quoting.unwrap_or_default();
-        source_quoting.default_to(&properties_quoting);
-
-        let mut table_quoting = table.quoting.unwrap_or_default();
-        table_quoting.default_to(&source_quoting);
+        let table_quoting = merge_quoting(&properties_quoting, source.quoting, table.quoting);
         let quoting_ignore_case = table_quoting.snowflake_ignore_case.unwrap_or(false);
 
         let (database, schema, identifier, quoting) = normalize_quoting(
@@ -200,7 +203,8 @@ pub fn resolve_sources(
         let table_tags: Option<Vec<String>> = table_config.tags.clone().map(|tags| tags.into());
 
         let merged_tags = merge_tags(source_tags, table_tags);
-        let merged_meta = merge_meta(
+        // table meta extends the source meta, down to nested mappings
+        let merged_meta = deep_merge_meta(
             source_properties_config.meta.clone(),
             table_config.meta.clone(),
         );
@@ -224,9 +228,12 @@ pub fn resolve_sources(
         };
 
         if let Some(freshness) = merged_freshness.as_ref() {
-            FreshnessRules::validate(freshness.error_after.as_ref())?;
-            FreshnessRules::validate(freshness.warn_after.as_ref())?;
+            freshness.validate()?;
         }
+        validate_freshness_filter(
+            &format!("table '{}'", table.name),
+            merged_freshness.as_ref(),
+        )?;
 
         // Add any other non-standard dbt keys that might be used by dbt packages under
         // the "other" key. This needs to be untyped since it's up to the packages to define
@@ -355,262 +362,3 @@ pub fn resolve_sources(
     }
     Ok((sources, disabled_sources))
 }
-
-fn merge_event_time(
-    source_event_time: Option<String>,
-    table_event_time: Option<String>,
-) -> Option<String> {
-    // If table_config.event_time is set (Some), use it regardless of its value
-    // Only use source_event_time if table_event_time is None
-    table_event_time.or(source_event_time)
-}
-
-fn merge_freshness(
-    base: Option<&FreshnessDefinition>,
-    update: &Omissible<Option<FreshnessDefinition>>,
-) -> Option<FreshnessDefinition> {
-    match update {
-        // A present but 'null' freshness does not inherit from the base and inhibits freshness by returning None.
-        Omissible::Present(update) => update
-            .as_ref()
-            .and_then(|update| merge_freshness_unwrapped(base, Some(update))),
-        // If there is no freshness present in the update then it is inherited (merged) from the base.
-        Omissible::Omitted => merge_freshness_unwrapped(base, None),
-    }
-}
-
-fn merge_freshness_unwrapped(
-    base: Option<&FreshnessDefinition>,
-    update: Option<&FreshnessDefinition>,
-) -> Option<FreshnessDefinition> {
-    match (base, update) {
-        // As long as a single element is present in update, override all of the elements in the base
-        // with the elements in the update.
-        // See mantle logic: https://github.com/dbt-labs/dbt-mantle/blob/847ab93f830d745c1c3d6609ead642b2bd07139a/core/dbt/parser/sources.py#L532-L542
-        // The mantle logic looks complicated but it is basically doing the same thing as the first
-        // statement of this comment. Especially look at the merge_freshness_time_thresholds function,
-        // which states that if an element of update is None, just return None for the specific element.
-        (_, Some(update)) => Some(update.clone()),
-        (Some(base), None) => Some(base.clone()),
-        (None, None) => Some(FreshnessDefinition::default()), // Provide default value if user never defined freshness https://dbtlabs.atlassian.net/browse/META-5461
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use dbt_jinja_utils::serde::Omissible;
-    use dbt_schemas::schemas::common::{FreshnessDefinition, FreshnessPeriod, FreshnessRules};
-
-    #[test]
-    fn test_merge_event_time_table_overrides_source() {
-        // When table_event_time is Some, it should always be used
-        let source_event_time = Some("source_timestamp".to_string());
-        let table_event_time = Some("table_timestamp".to_string());
-        let result = merge_event_time(source_event_time, table_event_time);
-        assert_eq!(result, Some("table_timestamp".to_string()));
-    }
-
-    #[test]
-    fn test_merge_event_time_uses_source_when_table_none() {
-        // When table_event_time is None, source_event_time should be used
-        let source_event_time = Some("source_timestamp".to_string());
-        let table_event_time = None;
-        let result = merge_event_time(source_event_time, table_event_time);
-        assert_eq!(result, Some("source_timestamp".to_string()));
-    }
-
-    #[test]
-    fn test_merge_event_time_both_none() {
-        // When both are None, result should be None
-        let source_event_time = None;
-        let table_event_time = None;
-        let result = merge_event_time(source_event_time, table_event_time);
-        assert_eq!(result, None);
-    }
-
-    #[test]
-    fn test_merge_event_time_empty_table_overrides() {
-        // Even empty string in table_event_time should override source
-        let source_event_time = Some("source_timestamp".to_string());
-        let table_event_time = Some("".to_string());
-        let result = merge_event_time(source_event_time, table_event_time);
-        assert_eq!(result, Some("".to_string()));
-    }
-
-    #[test]
-    fn test_merge_freshness_unwrapped_update_overrides_base() {
-        // When both base and update have values, update should override completely
-        let base = FreshnessDefinition {
-            error_after: Some(FreshnessRules {
-                count: Some(5),
-                period: Some(FreshnessPeriod::hour),
-            }),
-            warn_after: Some(FreshnessRules {
-                count: Some(3),
-                period: Some(FreshnessPeriod::hour),
-            }),
-            filter: Some("base_filter".to_string()),
-        };
-        let update = FreshnessDefinition {
-            error_after: Some(FreshnessRules {
-                count: Some(10),
-                period: Some(FreshnessPeriod::day),
-            }),
-            warn_after: None,
-            filter: None,
-        };
-
-        let result = merge_freshness_unwrapped(Some(&base), Some(&update));
-        assert_eq!(result, Some(update));
-    }
-
-    #[test]
-    fn test_merge_freshness_unwrapped_inherit_from_base() {
-        // When update is None, base should be inherited
-        let base = FreshnessDefinition {
-            error_after: Some(FreshnessRules {
-                count: Some(5),
-                period: Some(FreshnessPeriod::hour),
-            }),
-            warn_after: Some(FreshnessRules {
-                count: Some(3),
-                period: Some(FreshnessPeriod::hour),
-            }),
-            filter: None,
-        };
-
-        let result = merge_freshness_unwrapped(Some(&base), None);
-        assert_eq!(result, Some(base));
-    }
-
-    #[test]
-    fn test_merge_freshness_unwrapped_no_base() {
-        // When base is None but update has value, use update
-        let update = FreshnessDefinition {
-            error_after: Some(FreshnessRules {
-                count: Some(10),
-                period: Some(FreshnessPeriod::day),
-            }),
-            warn_after: None,
-            filter: None,
-        };
-
-        let result = merge_freshness_unwrapped(None, Some(&update));
-        assert_eq!(result, Some(update));
-    }
-
-    #[test]
-    fn test_merge_freshness_unwrapped_both_none() {
-        // When both are None, result should be None
-        let result = merge_freshness_unwrapped(None, None);
-        assert_eq!(result, Some(FreshnessDefinition::default()));
-    }
-
-    #[test]
-    fn test_merge_freshness_present_null_inhibits() {
-        // Present but null freshness should return None (inhibits freshness)
-        let base = FreshnessDefinition {
-            error_after: Some(FreshnessRules {
-                count: Some(5),
-                period: Some(FreshnessPeriod::hour),
-            }),
-            warn_after: None,
-            filter: None,
-        };
-
-        let update = Omissible::Present(None);
-        let result = merge_freshness(Some(&base), &update);
-        assert_eq!(result, None);
-    }
-
-    #[test]
-    fn test_merge_freshness_present_with_value() {
-        // Present with value should use the value
-        let base = FreshnessDefinition {
-            error_after: Some(FreshnessRules {
-                count: Some(5),
-                period: Some(FreshnessPeriod::hour),
-            }),
-            warn_after: None,
-            filter: None,
-        };
-
-        let update_value = FreshnessDefinition {
-            error_after: Some(FreshnessRules {
-                count: Some(10),
-                period: Some(FreshnessPeriod::day),
-            }),
-            warn_after: Some(FreshnessRules {
-                count: Some(5),
-                period: Some(FreshnessPeriod::day),
-            }),
-            filter: None,
-        };
-
-        let update = Omissible::Present(Some(update_value.clone()));
-        let result = merge_freshness(Some(&base), &update);
-        assert_eq!(result, Some(update_value));
-    }
-
-    #[test]
-    fn test_merge_freshness_omitted_inherits_base() {
-        // Omitted freshness should inherit from base
-        let base = FreshnessDefinition {
-            error_after: Some(FreshnessRules {
-                count: Some(5),
-                period: Some(FreshnessPeriod::hour),
-            }),
-            warn_after: None,
-            filter: None,
-        };
-
-        let update = Omissible::Omitted;
-        let result = merge_freshness(Some(&base), &update);
-        assert_eq!(result, Some(base));
-    }
-
-    #[test]
-    fn test_merge_freshness_omitted_no_base() {
-        // Omitted freshness with no base should return None
-        let update = Omissible::Omitted;
-        let result = merge_freshness(None, &update);
-        assert_eq!(result, Some(FreshnessDefinition::default()));
-    }
-
-    #[test]
-    fn test_merge_freshness_partial_update_overrides_completely() {
-        // Test that partial updates in the update completely override base
-        // This validates the comment about mantle logic
-        let base = FreshnessDefinition {
-            error_after: Some(FreshnessRules {
-                count: Some(5),
-                period: Some(FreshnessPeriod::hour),
-            }),
-            warn_after: Some(FreshnessRules {
-                count: Some(3),
-                period: Some(FreshnessPeriod::hour),
-            }),
-            filter: Some("base_filter".to_string()),
-        };
-
-        // Update only has error_after, but it should still completely replace base
-        let update_value = FreshnessDefinition {
-            error_after: Some(FreshnessRules {
-                count: Some(10),
-                period: Some(FreshnessPeriod::day),
-            }),
-            warn_after: None,
-            filter: None,
-        };
-
-        let update = Omissible::Present(Some(update_value.clone()));
-        let result = merge_freshness(Some(&base), &update);
-
-        // The result should be exactly the update, not a merge
-        assert_eq!(result, Some(update_value));
-        // Specifically verify that warn_after and filter are None, not inherited from base
-        assert!(result.as_ref().unwrap().warn_after.is_none());
-        assert!(result.as_ref().unwrap().filter.is_none());
-    }
-}
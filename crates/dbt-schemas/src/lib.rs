@@ -189,7 +189,10 @@ pub mod schemas {
         pub use saved_queries_properties::SavedQueriesProperties;
         pub use seed_properties::SeedProperties;
         pub use snapshot_properties::SnapshotProperties;
-        pub use source_properties::{SourceProperties, Tables};
+        pub use source_properties::{
+            ResolvedTable, SourceProperties, Tables, merge_enabled, merge_event_time,
+            merge_freshness, merge_loaded_at, merge_quoting, validate_freshness_filter,
+        };
         pub use unit_test_properties::{UnitTestOverrides, UnitTestProperties};
     }
 
@@ -157,10 +157,21 @@ pub struct FreshnessDefinition {
     pub error_after: Option<FreshnessRules>,
     #[serde(default, serialize_with = "serialize_freshness_rule")]
     pub warn_after: Option<FreshnessRules>,
+    /// Predicate that the `collect_freshness` macro appends to the freshness
+    /// query as a `WHERE` clause.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub filter: Option<String>,
 }
 
+impl FreshnessDefinition {
+    /// Check that both rules are complete. The `filter` is checked separately,
+    /// with the rest of the source's SQL snippets.
+    pub fn validate(&self) -> FsResult<()> {
+        FreshnessRules::validate(self.error_after.as_ref())?;
+        FreshnessRules::validate(self.warn_after.as_ref())
+    }
+}
+
 /// Custom serializer to ensure FreshnessRules are always objects, never null
 fn serialize_freshness_rule<S>(
     rule: &Option<FreshnessRules>,
@@ -599,6 +610,16 @@ pub enum OnConfigurationChange {
     Unknown,
 }
 
+impl DbtUniqueKey {
+    /// The columns making up the key.
+    pub fn columns(&self) -> Vec<String> {
+        match self {
+            DbtUniqueKey::Single(column) => vec![column.clone()],
+            DbtUniqueKey::Multiple(columns) => columns.clone(),
+        }
+    }
+}
+
 impl From<StringOrArrayOfStrings> for DbtUniqueKey {
     fn from(value: StringOrArrayOfStrings) -> Self {
         match value {
@@ -709,6 +730,17 @@ impl DbtChecksum {
             checksum: hex::encode(checksum),
         })
     }
+    /// Like [DbtChecksum::hash], but streams the bytes through the hasher in
+    /// chunks so that they never have to be held in memory all at once.
+    pub fn hash_reader(mut reader: impl std::io::Read) -> std::io::Result<Self> {
+        let mut hasher = Sha256::new();
+        std::io::copy(&mut reader, &mut hasher)?;
+        let checksum = hasher.finalize();
+        Ok(Self::Object(DbtChecksumObject {
+            name: "SHA256".to_string(),
+            checksum: hex::encode(checksum),
+        }))
+    }
     pub fn seed_file_hash(s: &[u8], path: &str) -> Self {
         const MAXIMUM_SEED_SIZE: usize = 1024 * 1024; // 1MB
 
@@ -1112,6 +1144,45 @@ pub fn merge_meta(
     }
 }
 
+/// Merge two meta maps like [merge_meta], but recursively: where both maps
+/// hold a mapping under the same key, the two mappings are merged instead of
+/// the second replacing the first.
+pub fn deep_merge_meta(
+    base_meta: Option<BTreeMap<String, YmlValue>>,
+    update_meta: Option<BTreeMap<String, YmlValue>>,
+) -> Option<BTreeMap<String, YmlValue>> {
+    match (base_meta, update_meta) {
+        (Some(mut merged), Some(update_map)) => {
+            for (key, value) in update_map {
+                match merged.get_mut(&key) {
+                    Some(base_value) => deep_merge_yml(base_value, value),
+                    None => {
+                        merged.insert(key, value);
+                    }
+                }
+            }
+            Some(merged)
+        }
+        (base_meta, update_meta) => update_meta.or(base_meta),
+    }
+}
+
+fn deep_merge_yml(base: &mut YmlValue, update: YmlValue) {
+    match (base, update) {
+        (YmlValue::Mapping(base_map, _), YmlValue::Mapping(update_map, _)) => {
+            for (key, value) in update_map {
+                match base_map.get_mut(&key) {
+                    Some(base_value) => deep_merge_yml(base_value, value),
+                    None => {
+                        base_map.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, update) => *base = update,
+    }
+}
+
 /// Merge two tag lists, deduplicating and sorting the result.
 pub fn merge_tags(
     base_tags: Option<Vec<String>>,
@@ -1137,6 +1208,84 @@ pub fn merge_tags(
 mod tests {
     use super::*;
 
+    fn meta(yaml: &str) -> Option<BTreeMap<String, YmlValue>> {
+        Some(dbt_serde_yaml::from_str(yaml).unwrap())
+    }
+
+    /// Compare meta maps by value, ignoring the source spans.
+    fn json(meta: Option<BTreeMap<String, YmlValue>>) -> serde_json::Value {
+        serde_json::to_value(meta).unwrap()
+    }
+
+    #[test]
+    fn test_freshness_definition_with_filter() {
+        let freshness: FreshnessDefinition = dbt_serde_yaml::from_str(
+            "warn_after: {count: 12, period: hour}\nfilter: \"_etl_loaded_at >= date('2024-01-01')\"",
+        )
+        .unwrap();
+        assert_eq!(
+            freshness.filter.as_deref(),
+            Some("_etl_loaded_at >= date('2024-01-01')")
+        );
+        assert!(freshness.validate().is_ok());
+
+        let json = serde_json::to_value(&freshness).unwrap();
+        assert_eq!(json["filter"], "_etl_loaded_at >= date('2024-01-01')");
+        assert_eq!(json["warn_after"]["count"], 12);
+        let roundtrip: FreshnessDefinition = serde_json::from_value(json).unwrap();
+        assert_eq!(roundtrip, freshness);
+    }
+
+    #[test]
+    fn test_freshness_definition_without_filter() {
+        let freshness: FreshnessDefinition =
+            dbt_serde_yaml::from_str("error_after: {count: 1, period: day}").unwrap();
+        assert_eq!(freshness.filter, None);
+        assert!(freshness.validate().is_ok());
+
+        let json = serde_json::to_value(&freshness).unwrap();
+        assert!(json.get("filter").is_none(), "{json}");
+        // rules are always serialized as objects
+        assert!(json["warn_after"].is_object(), "{json}");
+    }
+
+    #[test]
+    fn test_deep_merge_meta_disjoint_keys() {
+        let merged = deep_merge_meta(meta("owner: data"), meta("pii: true"));
+        assert_eq!(json(merged), json(meta("owner: data\npii: true")));
+        assert_eq!(
+            json(deep_merge_meta(meta("owner: data"), None)),
+            json(meta("owner: data"))
+        );
+        assert_eq!(
+            json(deep_merge_meta(None, meta("pii: true"))),
+            json(meta("pii: true"))
+        );
+    }
+
+    #[test]
+    fn test_deep_merge_meta_overlapping_scalar() {
+        let merged = deep_merge_meta(meta("owner: data\nsla: 1"), meta("owner: finance"));
+        assert_eq!(json(merged), json(meta("owner: finance\nsla: 1")));
+        // a scalar replaces a mapping rather than merging into it
+        let merged = deep_merge_meta(meta("contact: {team: data}"), meta("contact: nobody"));
+        assert_eq!(json(merged), json(meta("contact: nobody")));
+    }
+
+    #[test]
+    fn test_deep_merge_meta_nested_objects() {
+        let merged = deep_merge_meta(
+            meta("contact: {team: data, slack: '#data', page: {hours: 24}}"),
+            meta("contact: {slack: '#finance', page: {sev: 1}}"),
+        );
+        assert_eq!(
+            json(merged),
+            json(meta(
+                "contact: {team: data, slack: '#finance', page: {hours: 24, sev: 1}}"
+            ))
+        );
+    }
+
     #[test]
     fn test_hooks_equal_array_of_strings_vs_hook_config_array() {
         let array_of_strings = Hooks::ArrayOfStrings(vec![
@@ -780,6 +780,9 @@ pub fn nodes_from_dbt_manifest(manifest: DbtManifest, dbt_quoting: DbtQuoting) -
                             column_types: seed.config.column_types.clone(),
                             delimiter: seed.config.delimiter.clone().map(|d| d.into_inner()),
                             root_path: seed.root_path,
+                            constraints: DbtSeedAttr::unique_key_constraints(
+                                seed.config.unique_key.as_ref(),
+                            ),
                         },
                         deprecated_config: seed.config,
                         __other__: seed.__other__,
@@ -17,8 +17,9 @@ use crate::schemas::project::WarehouseSpecificNodeConfig;
 use crate::schemas::serde::StringOrArrayOfStrings;
 use crate::schemas::{
     common::{
-        Access, DbtChecksum, DbtContract, DbtIncrementalStrategy, DbtMaterialization, Expect,
-        FreshnessDefinition, Given, IncludeExclude, NodeDependsOn, ResolvedQuoting, ScheduleConfig,
+        Access, ConstraintType, DbtChecksum, DbtContract, DbtIncrementalStrategy,
+        DbtMaterialization, DbtUniqueKey, Expect, FreshnessDefinition, Given, IncludeExclude,
+        NodeDependsOn, ResolvedQuoting, ScheduleConfig,
     },
     macros::DbtMacro,
     manifest::common::DbtOwner,
@@ -1884,6 +1885,24 @@ pub struct DbtSeedAttr {
     pub column_types: Option<BTreeMap<Spanned<String>, String>>,
     pub delimiter: Option<String>,
     pub root_path: Option<PathBuf>,
+    /// The primary key implied by the `unique_key` config, for DDL
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub constraints: Vec<ModelConstraint>,
+}
+
+impl DbtSeedAttr {
+    /// The constraints implied by the `unique_key` of a seed config: a
+    /// primary key on its columns.
+    pub fn unique_key_constraints(unique_key: Option<&DbtUniqueKey>) -> Vec<ModelConstraint> {
+        unique_key
+            .map(|unique_key| ModelConstraint {
+                type_: ConstraintType::PrimaryKey,
+                columns: Some(unique_key.columns()),
+                ..Default::default()
+            })
+            .into_iter()
+            .collect()
+    }
 }
 
 fn is_false(b: &bool) -> bool {
@@ -12,6 +12,7 @@ use std::collections::btree_map::Iter;
 use crate::default_to;
 use crate::schemas::common::DbtMaterialization;
 use crate::schemas::common::DbtQuoting;
+use crate::schemas::common::DbtUniqueKey;
 use crate::schemas::common::DocsConfig;
 use crate::schemas::common::Hooks;
 use crate::schemas::common::PersistDocsConfig;
@@ -76,6 +77,8 @@ pub struct ProjectSeedConfig {
     pub tags: Option<StringOrArrayOfStrings>,
     #[serde(rename = "+transient")]
     pub transient: Option<bool>,
+    #[serde(rename = "+unique_key")]
+    pub unique_key: Option<DbtUniqueKey>,
     #[serde(rename = "+quoting")]
     pub quoting: Option<DbtQuoting>,
     #[serde(rename = "+delimiter")]
@@ -297,6 +300,10 @@ pub struct SeedConfig {
     pub quoting: Option<DbtQuoting>,
     pub description: Option<String>,
     pub materialized: Option<DbtMaterialization>,
+    /// Fetch the seed from this http(s) url instead of a file in the project
+    pub url: Option<String>,
+    /// The column(s) uniquely identifying a row, declared as the primary key
+    pub unique_key: Option<DbtUniqueKey>,
     // Adapter specific configs
     pub __warehouse_specific_config__: WarehouseSpecificNodeConfig,
 }
@@ -324,6 +331,8 @@ impl From<ProjectSeedConfig> for SeedConfig {
             quoting: config.quoting,
             description: config.description,
             materialized: Some(DbtMaterialization::Seed),
+            url: None,
+            unique_key: config.unique_key,
             __warehouse_specific_config__: WarehouseSpecificNodeConfig {
                 adapter_properties: config.adapter_properties,
                 external_volume: config.external_volume,
@@ -423,6 +432,7 @@ impl From<SeedConfig> for ProjectSeedConfig {
             tags: config.tags,
             quoting: config.quoting,
             description: config.description,
+            unique_key: config.unique_key,
             // Snowflake fields
             adapter_properties: config.__warehouse_specific_config__.adapter_properties,
             snowflake_warehouse: config.__warehouse_specific_config__.snowflake_warehouse,
@@ -544,6 +554,9 @@ impl DefaultTo<SeedConfig> for SeedConfig {
             persist_docs,
             description,
             materialized,
+            // a url locates a single seed's data, so it is never inherited
+            url: _,
+            unique_key,
             // Adapter specific configs
             __warehouse_specific_config__: warehouse_specific_config,
         } = self;
@@ -582,6 +595,7 @@ impl DefaultTo<SeedConfig> for SeedConfig {
                 persist_docs,
                 description,
                 materialized,
+                unique_key,
             ]
         );
     }
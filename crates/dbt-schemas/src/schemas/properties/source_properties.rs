@@ -1,11 +1,13 @@
 use crate::schemas::common::DbtQuoting;
 use crate::schemas::common::FreshnessDefinition;
+use crate::schemas::common::{deep_merge_meta, merge_tags};
 use crate::schemas::data_tests::DataTests;
 use crate::schemas::dbt_column::ColumnProperties;
 use crate::schemas::project::SourceConfig;
 use crate::schemas::serde::StringOrArrayOfStrings;
 use crate::schemas::serde::bool_or_string_bool;
 use dbt_common::serde_utils::Omissible;
+use dbt_common::{ErrorCode, FsResult, fs_err};
 use dbt_serde_yaml::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
@@ -30,6 +32,67 @@ pub struct SourceProperties {
     pub tables: Option<Vec<Tables>>,
 }
 
+impl SourceProperties {
+    /// Check that the source config doesn't set both `loaded_at_field` and
+    /// `loaded_at_query`. Tables are checked separately by [Tables::validate_loaded_at].
+    pub fn validate_loaded_at(&self) -> FsResult<()> {
+        let Some(config) = &self.config else {
+            return Ok(());
+        };
+        validate_loaded_at(
+            &format!("source '{}'", self.name),
+            config.loaded_at_field.as_deref(),
+            config.loaded_at_query.as_deref(),
+        )
+    }
+
+    /// Resolve the configs of every table of this source, with each table
+    /// inheriting the freshness, tags, meta, quoting, enabled, event time and
+    /// loaded_at settings of the source unless it overrides them.
+    ///
+    /// Only the source's own properties are considered, on top of
+    /// `package_quoting`; configs set in `dbt_project.yml` are not applied.
+    pub fn flatten_tables(&self, package_quoting: DbtQuoting) -> Vec<ResolvedTable> {
+        let config = self.config.clone().unwrap_or_default();
+        let properties_quoting = merge_quoting(&package_quoting, None, config.quoting);
+        let source_tags: Option<Vec<String>> = config.tags.clone().map(|tags| tags.into());
+
+        self.tables
+            .iter()
+            .flatten()
+            .map(|table| {
+                let table_config = table.config.clone().unwrap_or_default();
+                let (loaded_at_field, loaded_at_query) = merge_loaded_at(
+                    (
+                        config.loaded_at_field.clone(),
+                        config.loaded_at_query.clone(),
+                    ),
+                    (table_config.loaded_at_field, table_config.loaded_at_query),
+                );
+                ResolvedTable {
+                    name: table.name.clone(),
+                    enabled: merge_enabled(config.enabled, table_config.enabled),
+                    freshness: merge_freshness(config.freshness.as_ref(), &table_config.freshness),
+                    tags: merge_tags(
+                        source_tags.clone(),
+                        table_config.tags.map(|tags| tags.into()),
+                    )
+                    .unwrap_or_default(),
+                    meta: deep_merge_meta(config.meta.clone(), table_config.meta)
+                        .unwrap_or_default(),
+                    quoting: merge_quoting(&properties_quoting, self.quoting, table.quoting),
+                    loaded_at_field,
+                    loaded_at_query,
+                    event_time: merge_event_time(
+                        config.event_time.clone(),
+                        table_config.event_time,
+                    ),
+                }
+            })
+            .collect()
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Clone, JsonSchema)]
 pub struct Tables {
@@ -45,6 +108,153 @@ pub struct Tables {
     pub tests: Option<Vec<DataTests>>,
 }
 
+impl Tables {
+    /// Check that the table config doesn't set both `loaded_at_field` and
+    /// `loaded_at_query`.
+    pub fn validate_loaded_at(&self) -> FsResult<()> {
+        let Some(config) = &self.config else {
+            return Ok(());
+        };
+        validate_loaded_at(
+            &format!("table '{}'", self.name),
+            config.loaded_at_field.as_deref(),
+            config.loaded_at_query.as_deref(),
+        )
+    }
+}
+
+/// A table of a source with the configs it inherits from the source resolved,
+/// as returned by [SourceProperties::flatten_tables].
+#[derive(Debug, Clone)]
+pub struct ResolvedTable {
+    pub name: String,
+    pub enabled: bool,
+    pub freshness: Option<FreshnessDefinition>,
+    pub tags: Vec<String>,
+    pub meta: BTreeMap<String, YmlValue>,
+    pub quoting: DbtQuoting,
+    pub loaded_at_field: Option<String>,
+    pub loaded_at_query: Option<String>,
+    pub event_time: Option<String>,
+}
+
+/// Fail if both `loaded_at_field` and `loaded_at_query` are set (and non-empty)
+/// at the same level, since it's ambiguous which one freshness checks should use,
+/// or if `loaded_at_query` is obviously not a valid query.
+fn validate_loaded_at(
+    level: &str,
+    loaded_at_field: Option<&str>,
+    loaded_at_query: Option<&str>,
+) -> FsResult<()> {
+    match (loaded_at_field, loaded_at_query) {
+        (Some(field), Some(query)) if !field.is_empty() && !query.is_empty() => Err(fs_err!(
+            ErrorCode::InvalidConfig,
+            "loaded_at_field and loaded_at_query cannot be set at the same time in {level}, \
+             loaded_at_field: {field:?}, loaded_at_query: {query:?}"
+        )),
+        // an empty query is the same as no query
+        (_, Some(query)) if !query.is_empty() => check_query_syntax(query).map_err(|reason| {
+            fs_err!(
+                ErrorCode::InvalidConfig,
+                "Invalid loaded_at_query in {level}: {reason}, loaded_at_query: {query:?}"
+            )
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Fail if the freshness `filter` of `level` is obviously not a valid
+/// predicate. The `collect_freshness` macro pastes it into the freshness
+/// query as is (`where {{ filter }}`), so it gets the same checks as
+/// `loaded_at_query`.
+pub fn validate_freshness_filter(
+    level: &str,
+    freshness: Option<&FreshnessDefinition>,
+) -> FsResult<()> {
+    match freshness.and_then(|freshness| freshness.filter.as_deref()) {
+        Some(filter) => check_query_syntax(filter).map_err(|reason| {
+            fs_err!(
+                ErrorCode::InvalidConfig,
+                "Invalid freshness filter in {level}: {reason}, filter: {filter:?}"
+            )
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Lightweight check that catches the mistakes in a query that are obvious
+/// without a SQL parser: a blank query, unclosed quotes or comments and
+/// unbalanced parentheses.
+fn check_query_syntax(query: &str) -> Result<(), &'static str> {
+    if query.trim().is_empty() {
+        return Err("the query is blank");
+    }
+    // backslashes escape quotes in some dialects ('it\'s') but not in others
+    // ('C:\'), so only a query that is invalid either way is rejected
+    check_query_tokens(query, true).or_else(|_| check_query_tokens(query, false))
+}
+
+fn check_query_tokens(query: &str, backslash_escapes: bool) -> Result<(), &'static str> {
+    let mut depth = 0usize;
+    let mut prev = ' ';
+    let mut rest = query;
+    while let Some(c) = rest.chars().next() {
+        rest = &rest[c.len_utf8()..];
+        match c {
+            // doubled quotes ('') are consumed as two adjacent literals
+            '\'' | '"' | '`' => {
+                let mut escaped = false;
+                let (end, _) = rest
+                    .char_indices()
+                    .find(|&(_, next)| {
+                        let closes = next == c && !escaped;
+                        escaped = backslash_escapes && next == '\\' && !escaped;
+                        closes
+                    })
+                    .ok_or("unclosed quote")?;
+                rest = &rest[end + c.len_utf8()..];
+            }
+            // dollar-quoted strings ($$...$$ or $tag$...$tag$), unless the `$`
+            // is part of an identifier or a positional parameter ($1)
+            '$' if !(prev.is_alphanumeric() || prev == '_') => {
+                if let Some(tag_end) = rest.find('$')
+                    && is_dollar_quote_tag(&rest[..tag_end])
+                {
+                    let delimiter = &query[query.len() - rest.len() - 1..][..tag_end + 2];
+                    let body = &rest[tag_end + 1..];
+                    let end = body.find(delimiter).ok_or("unclosed quote")?;
+                    rest = &body[end + delimiter.len()..];
+                }
+            }
+            '-' if rest.starts_with('-') => {
+                rest = rest.find('\n').map_or("", |end| &rest[end..]);
+            }
+            '/' if rest.starts_with('*') => {
+                let end = rest[1..].find("*/").ok_or("unclosed comment")?;
+                rest = &rest[1 + end + 2..];
+            }
+            '(' => depth += 1,
+            ')' => {
+                depth = depth.checked_sub(1).ok_or("unbalanced parentheses")?;
+            }
+            _ => {}
+        }
+        prev = c;
+    }
+    if depth == 0 {
+        Ok(())
+    } else {
+        Err("unbalanced parentheses")
+    }
+}
+
+/// Whether `tag` can appear between the dollar signs of a dollar quote: it is
+/// empty or an identifier that doesn't start with a digit.
+fn is_dollar_quote_tag(tag: &str) -> bool {
+    !tag.starts_with(|c: char| c.is_ascii_digit())
+        && tag.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Clone, JsonSchema, Default)]
 pub struct TablesConfig {
@@ -57,3 +267,608 @@ pub struct TablesConfig {
     pub loaded_at_field: Option<String>,
     pub loaded_at_query: Option<String>,
 }
+
+/// Resolve the quoting of a table from the quoting of its properties (the
+/// project/package and source config), its source and the table itself.
+pub fn merge_quoting(
+    properties_quoting: &DbtQuoting,
+    source_quoting: Option<DbtQuoting>,
+    table_quoting: Option<DbtQuoting>,
+) -> DbtQuoting {
+    // Merge field by field (table > source > project/package), so a table that only
+    // sets `identifier` still inherits the database and schema quoting of its source
+    let mut source_quoting = source_quoting.unwrap_or_default();
+    source_quoting.default_to(properties_quoting);
+    source_quoting.snowflake_ignore_case = source_quoting
+        .snowflake_ignore_case
+        .or(properties_quoting.snowflake_ignore_case);
+
+    let mut table_quoting = table_quoting.unwrap_or_default();
+    table_quoting.default_to(&source_quoting);
+    table_quoting.snowflake_ignore_case = table_quoting
+        .snowflake_ignore_case
+        .or(source_quoting.snowflake_ignore_case);
+    table_quoting
+}
+
+/// Resolve whether a table is enabled given its source.
+pub fn merge_enabled(source_enabled: Option<bool>, table_enabled: Option<bool>) -> bool {
+    // A table is disabled along with its source unless it explicitly sets
+    // enabled itself, in which case the table's value wins either way
+    table_enabled.or(source_enabled).unwrap_or(true)
+}
+
+/// Resolve the `event_time` of a table given its source.
+pub fn merge_event_time(
+    source_event_time: Option<String>,
+    table_event_time: Option<String>,
+) -> Option<String> {
+    // If table_config.event_time is set (Some), use it regardless of its value
+    // Only use source_event_time if table_event_time is None
+    table_event_time.or(source_event_time)
+}
+
+/// Resolve the `(loaded_at_field, loaded_at_query)` pair of a table given the
+/// pair of its source.
+///
+/// Each of them is inherited separately, so a source setting `loaded_at_field`
+/// and a table setting `loaded_at_query` leave the table with both, which is
+/// then rejected like setting both at the same level.
+pub fn merge_loaded_at(
+    source: (Option<String>, Option<String>),
+    table: (Option<String>, Option<String>),
+) -> (Option<String>, Option<String>) {
+    (table.0.or(source.0), table.1.or(source.1))
+}
+
+/// Resolve the freshness of a table given the freshness of its source.
+pub fn merge_freshness(
+    base: Option<&FreshnessDefinition>,
+    update: &Omissible<Option<FreshnessDefinition>>,
+) -> Option<FreshnessDefinition> {
+    match update {
+        // A present but 'null' freshness does not inherit from the base and inhibits freshness by returning None.
+        Omissible::Present(update) => update
+            .as_ref()
+            .and_then(|update| merge_freshness_unwrapped(base, Some(update))),
+        // If there is no freshness present in the update then it is inherited (merged) from the base.
+        Omissible::Omitted => merge_freshness_unwrapped(base, None),
+    }
+}
+
+/// Like [merge_freshness], once an explicit `null` freshness is ruled out.
+pub fn merge_freshness_unwrapped(
+    base: Option<&FreshnessDefinition>,
+    update: Option<&FreshnessDefinition>,
+) -> Option<FreshnessDefinition> {
+    match (base, update) {
+        // As long as a single element is present in update, override all of the elements in the base
+        // with the elements in the update.
+        // See mantle logic: https://github.com/dbt-labs/dbt-mantle/blob/847ab93f830d745c1c3d6609ead642b2bd07139a/core/dbt/parser/sources.py#L532-L542
+        // The mantle logic looks complicated but it is basically doing the same thing as the first
+        // statement of this comment. Especially look at the merge_freshness_time_thresholds function,
+        // which states that if an element of update is None, just return None for the specific element.
+        (_, Some(update)) => Some(update.clone()),
+        (Some(base), None) => Some(base.clone()),
+        (None, None) => Some(FreshnessDefinition::default()), // Provide default value if user never defined freshness https://dbtlabs.atlassian.net/browse/META-5461
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schemas::common::{FreshnessPeriod, FreshnessRules};
+
+    fn table(loaded_at_field: Option<&str>, loaded_at_query: Option<&str>) -> Tables {
+        Tables {
+            columns: None,
+            config: Some(TablesConfig {
+                loaded_at_field: loaded_at_field.map(str::to_string),
+                loaded_at_query: loaded_at_query.map(str::to_string),
+                ..Default::default()
+            }),
+            data_tests: None,
+            description: None,
+            external: None,
+            identifier: None,
+            loader: None,
+            name: "orders".to_string(),
+            quoting: None,
+            tests: None,
+        }
+    }
+
+    #[test]
+    fn test_validate_loaded_at_both_present() {
+        let err = table(
+            Some("_loaded_at"),
+            Some("select max(_loaded_at) from orders"),
+        )
+        .validate_loaded_at()
+        .unwrap_err();
+        assert!(err.to_string().contains("table 'orders'"), "{err}");
+    }
+
+    #[test]
+    fn test_validate_loaded_at_field_only() {
+        assert!(table(Some("_loaded_at"), None).validate_loaded_at().is_ok());
+        // an empty query is the same as no query
+        assert!(
+            table(Some("_loaded_at"), Some(""))
+                .validate_loaded_at()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_validate_loaded_at_query_only() {
+        let table = table(None, Some("select max(_loaded_at) from orders"));
+        assert!(table.validate_loaded_at().is_ok());
+    }
+
+    #[test]
+    fn test_validate_loaded_at_query_syntax() {
+        for query in [
+            "select max(_loaded_at) from orders where status = 'it''s done'",
+            "select max(\"Loaded At\") -- the latest load (if any\nfrom orders",
+            "/* ( */ select current_timestamp()",
+            // backslash-escaped quotes, and backslashes that escape nothing
+            r"select max(_loaded_at) from orders where status = 'it\'s done'",
+            r"select max(_loaded_at) from orders where path = 'C:\'",
+            // dollar-quoted strings
+            "select max(_loaded_at) from orders where status = $$it's (done$$",
+            "select max(_loaded_at) from orders where note = $note$a $$ 'b$note$",
+            "select max(_loaded_at) from orders where id = $1 and a$b = 'c'",
+        ] {
+            let result = table(None, Some(query)).validate_loaded_at();
+            assert!(result.is_ok(), "{query}: {result:?}");
+        }
+
+        for (query, reason) in [
+            ("  \n", "blank"),
+            (
+                "select max(_loaded_at from orders",
+                "unbalanced parentheses",
+            ),
+            (
+                "select max(_loaded_at)) from orders",
+                "unbalanced parentheses",
+            ),
+            ("select 'open from orders", "unclosed quote"),
+            ("select 1 /* from orders", "unclosed comment"),
+            // unclosed whether or not backslashes escape quotes
+            (r"select 'it\'s', 'open\' from orders", "unclosed quote"),
+            ("select $$open from orders", "unclosed quote"),
+            ("select $tag$open$$ from orders", "unclosed quote"),
+        ] {
+            let err = table(None, Some(query)).validate_loaded_at().unwrap_err();
+            assert_eq!(err.code, ErrorCode::InvalidConfig);
+            let message = err.to_string();
+            assert!(message.contains(reason), "{query}: {message}");
+            assert!(message.contains("table 'orders'"), "{query}: {message}");
+        }
+    }
+
+    #[test]
+    fn test_validate_freshness_filter() {
+        let freshness = |filter: &str| FreshnessDefinition {
+            filter: Some(filter.to_string()),
+            ..Default::default()
+        };
+        assert!(validate_freshness_filter("table 'orders'", None).is_ok());
+        assert!(
+            validate_freshness_filter("table 'orders'", Some(&FreshnessDefinition::default()))
+                .is_ok()
+        );
+        let filter = freshness("_etl_loaded_at >= date('2024-01-01')");
+        assert!(validate_freshness_filter("table 'orders'", Some(&filter)).is_ok());
+
+        for (filter, reason) in [
+            ("  ", "blank"),
+            ("status = 'done", "unclosed quote"),
+            ("(status = 'done'", "unbalanced parentheses"),
+        ] {
+            let err =
+                validate_freshness_filter("table 'orders'", Some(&freshness(filter))).unwrap_err();
+            assert_eq!(err.code, ErrorCode::InvalidConfig);
+            let message = err.to_string();
+            assert!(message.contains(reason), "{filter}: {message}");
+            assert!(message.contains("table 'orders'"), "{filter}: {message}");
+        }
+    }
+
+    #[test]
+    fn test_merge_loaded_at() {
+        let field = || Some("_loaded_at".to_string());
+        let query = || Some("select max(_loaded_at) from orders".to_string());
+        assert_eq!(
+            merge_loaded_at((field(), None), (None, None)),
+            (field(), None)
+        );
+        assert_eq!(
+            merge_loaded_at((None, query()), (field(), None)),
+            (field(), query())
+        );
+        // a table query doesn't replace the source field, both are set
+        assert_eq!(
+            merge_loaded_at((field(), None), (None, query())),
+            (field(), query())
+        );
+        // but a table can clear what it inherits with an empty value
+        assert_eq!(
+            merge_loaded_at((field(), None), (Some(String::new()), query())),
+            (Some(String::new()), query())
+        );
+    }
+
+    #[test]
+    fn test_flatten_tables_inherits_source_configs() {
+        let source_freshness = FreshnessDefinition {
+            error_after: Some(FreshnessRules {
+                count: Some(12),
+                period: Some(FreshnessPeriod::hour),
+            }),
+            warn_after: None,
+            filter: None,
+        };
+        let table_freshness = FreshnessDefinition {
+            error_after: None,
+            warn_after: Some(FreshnessRules {
+                count: Some(1),
+                period: Some(FreshnessPeriod::day),
+            }),
+            filter: None,
+        };
+
+        let mut orders = table(None, None);
+        orders.config = Some(TablesConfig {
+            freshness: Omissible::Present(Some(table_freshness.clone())),
+            tags: Some(StringOrArrayOfStrings::String("finance".to_string())),
+            ..Default::default()
+        });
+        orders.quoting = Some(quoting(None, None, Some(true)));
+        let mut customers = table(None, None);
+        customers.name = "customers".to_string();
+        customers.config = None;
+        let mut payments = table(None, None);
+        payments.name = "payments".to_string();
+        payments.config = Some(TablesConfig {
+            enabled: Some(false),
+            freshness: Omissible::Present(None),
+            ..Default::default()
+        });
+
+        let source = SourceProperties {
+            config: Some(SourceConfig {
+                freshness: Some(source_freshness.clone()),
+                tags: Some(StringOrArrayOfStrings::ArrayOfStrings(vec![
+                    "raw".to_string(),
+                ])),
+                loaded_at_field: Some("_loaded_at".to_string()),
+                ..Default::default()
+            }),
+            database: None,
+            schema: None,
+            catalog: None,
+            description: None,
+            loader: None,
+            name: "shop".to_string(),
+            quoting: Some(quoting(None, Some(false), None)),
+            tables: Some(vec![orders, customers, payments]),
+        };
+
+        let tables = source.flatten_tables(quoting(Some(true), Some(true), Some(false)));
+        let names: Vec<_> = tables.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["orders", "customers", "payments"]);
+
+        let orders = &tables[0];
+        assert!(orders.enabled);
+        assert_eq!(orders.freshness, Some(table_freshness));
+        assert_eq!(orders.tags, ["finance", "raw"]);
+        assert_eq!(orders.quoting, quoting(Some(true), Some(false), Some(true)));
+        assert_eq!(orders.loaded_at_field.as_deref(), Some("_loaded_at"));
+
+        let customers = &tables[1];
+        assert!(customers.enabled);
+        assert_eq!(customers.freshness, Some(source_freshness));
+        assert_eq!(customers.tags, ["raw"]);
+        assert_eq!(
+            customers.quoting,
+            quoting(Some(true), Some(false), Some(false))
+        );
+
+        let payments = &tables[2];
+        assert!(!payments.enabled);
+        assert_eq!(payments.freshness, None);
+        assert_eq!(payments.tags, ["raw"]);
+    }
+
+    fn quoting(
+        database: Option<bool>,
+        schema: Option<bool>,
+        identifier: Option<bool>,
+    ) -> DbtQuoting {
+        DbtQuoting {
+            database,
+            schema,
+            identifier,
+            snowflake_ignore_case: None,
+        }
+    }
+
+    #[test]
+    fn test_merge_quoting_table_inherits_source() {
+        let properties = quoting(Some(false), Some(false), Some(false));
+        let source = DbtQuoting {
+            snowflake_ignore_case: Some(true),
+            ..quoting(Some(true), None, Some(true))
+        };
+        let result = merge_quoting(&properties, Some(source), None);
+        assert_eq!(
+            result,
+            DbtQuoting {
+                snowflake_ignore_case: Some(true),
+                ..quoting(Some(true), Some(false), Some(true))
+            }
+        );
+    }
+
+    #[test]
+    fn test_merge_quoting_table_partial_override() {
+        let properties = quoting(Some(false), Some(false), Some(false));
+        let source = quoting(Some(true), Some(true), None);
+        let table = quoting(None, None, Some(true));
+        let result = merge_quoting(&properties, Some(source), Some(table));
+        assert_eq!(result, quoting(Some(true), Some(true), Some(true)));
+
+        let table = quoting(None, Some(false), None);
+        let result = merge_quoting(&properties, Some(source), Some(table));
+        assert_eq!(result, quoting(Some(true), Some(false), Some(false)));
+    }
+
+    #[test]
+    fn test_merge_quoting_table_full_override() {
+        let properties = quoting(Some(true), Some(true), Some(true));
+        let source = DbtQuoting {
+            snowflake_ignore_case: Some(true),
+            ..quoting(Some(true), Some(true), Some(true))
+        };
+        let table = DbtQuoting {
+            snowflake_ignore_case: Some(false),
+            ..quoting(Some(false), Some(false), Some(false))
+        };
+        let result = merge_quoting(&properties, Some(source), Some(table));
+        assert_eq!(result, table);
+    }
+
+    #[test]
+    fn test_merge_enabled_source_disabled_propagates() {
+        assert!(!merge_enabled(Some(false), None));
+    }
+
+    #[test]
+    fn test_merge_enabled_table_override_enables() {
+        assert!(merge_enabled(Some(false), Some(true)));
+        // and the other way around
+        assert!(!merge_enabled(Some(true), Some(false)));
+    }
+
+    #[test]
+    fn test_merge_enabled_both_enabled() {
+        assert!(merge_enabled(Some(true), Some(true)));
+        assert!(merge_enabled(Some(true), None));
+        assert!(merge_enabled(None, None));
+    }
+
+    #[test]
+    fn test_merge_event_time_table_overrides_source() {
+        // When table_event_time is Some, it should always be used
+        let source_event_time = Some("source_timestamp".to_string());
+        let table_event_time = Some("table_timestamp".to_string());
+        let result = merge_event_time(source_event_time, table_event_time);
+        assert_eq!(result, Some("table_timestamp".to_string()));
+    }
+
+    #[test]
+    fn test_merge_event_time_uses_source_when_table_none() {
+        // When table_event_time is None, source_event_time should be used
+        let source_event_time = Some("source_timestamp".to_string());
+        let table_event_time = None;
+        let result = merge_event_time(source_event_time, table_event_time);
+        assert_eq!(result, Some("source_timestamp".to_string()));
+    }
+
+    #[test]
+    fn test_merge_event_time_both_none() {
+        // When both are None, result should be None
+        let source_event_time = None;
+        let table_event_time = None;
+        let result = merge_event_time(source_event_time, table_event_time);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_merge_event_time_empty_table_overrides() {
+        // Even empty string in table_event_time should override source
+        let source_event_time = Some("source_timestamp".to_string());
+        let table_event_time = Some("".to_string());
+        let result = merge_event_time(source_event_time, table_event_time);
+        assert_eq!(result, Some("".to_string()));
+    }
+
+    #[test]
+    fn test_merge_freshness_unwrapped_update_overrides_base() {
+        // When both base and update have values, update should override completely
+        let base = FreshnessDefinition {
+            error_after: Some(FreshnessRules {
+                count: Some(5),
+                period: Some(FreshnessPeriod::hour),
+            }),
+            warn_after: Some(FreshnessRules {
+                count: Some(3),
+                period: Some(FreshnessPeriod::hour),
+            }),
+            filter: Some("base_filter".to_string()),
+        };
+        let update = FreshnessDefinition {
+            error_after: Some(FreshnessRules {
+                count: Some(10),
+                period: Some(FreshnessPeriod::day),
+            }),
+            warn_after: None,
+            filter: None,
+        };
+
+        let result = merge_freshness_unwrapped(Some(&base), Some(&update));
+        assert_eq!(result, Some(update));
+    }
+
+    #[test]
+    fn test_merge_freshness_unwrapped_inherit_from_base() {
+        // When update is None, base should be inherited
+        let base = FreshnessDefinition {
+            error_after: Some(FreshnessRules {
+                count: Some(5),
+                period: Some(FreshnessPeriod::hour),
+            }),
+            warn_after: Some(FreshnessRules {
+                count: Some(3),
+                period: Some(FreshnessPeriod::hour),
+            }),
+            filter: None,
+        };
+
+        let result = merge_freshness_unwrapped(Some(&base), None);
+        assert_eq!(result, Some(base));
+    }
+
+    #[test]
+    fn test_merge_freshness_unwrapped_no_base() {
+        // When base is None but update has value, use update
+        let update = FreshnessDefinition {
+            error_after: Some(FreshnessRules {
+                count: Some(10),
+                period: Some(FreshnessPeriod::day),
+            }),
+            warn_after: None,
+            filter: None,
+        };
+
+        let result = merge_freshness_unwrapped(None, Some(&update));
+        assert_eq!(result, Some(update));
+    }
+
+    #[test]
+    fn test_merge_freshness_unwrapped_both_none() {
+        // When both are None, result should be None
+        let result = merge_freshness_unwrapped(None, None);
+        assert_eq!(result, Some(FreshnessDefinition::default()));
+    }
+
+    #[test]
+    fn test_merge_freshness_present_null_inhibits() {
+        // Present but null freshness should return None (inhibits freshness)
+        let base = FreshnessDefinition {
+            error_after: Some(FreshnessRules {
+                count: Some(5),
+                period: Some(FreshnessPeriod::hour),
+            }),
+            warn_after: None,
+            filter: None,
+        };
+
+        let update = Omissible::Present(None);
+        let result = merge_freshness(Some(&base), &update);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_merge_freshness_present_with_value() {
+        // Present with value should use the value
+        let base = FreshnessDefinition {
+            error_after: Some(FreshnessRules {
+                count: Some(5),
+                period: Some(FreshnessPeriod::hour),
+            }),
+            warn_after: None,
+            filter: None,
+        };
+
+        let update_value = FreshnessDefinition {
+            error_after: Some(FreshnessRules {
+                count: Some(10),
+                period: Some(FreshnessPeriod::day),
+            }),
+            warn_after: Some(FreshnessRules {
+                count: Some(5),
+                period: Some(FreshnessPeriod::day),
+            }),
+            filter: None,
+        };
+
+        let update = Omissible::Present(Some(update_value.clone()));
+        let result = merge_freshness(Some(&base), &update);
+        assert_eq!(result, Some(update_value));
+    }
+
+    #[test]
+    fn test_merge_freshness_omitted_inherits_base() {
+        // Omitted freshness should inherit from base
+        let base = FreshnessDefinition {
+            error_after: Some(FreshnessRules {
+                count: Some(5),
+                period: Some(FreshnessPeriod::hour),
+            }),
+            warn_after: None,
+            filter: None,
+        };
+
+        let update = Omissible::Omitted;
+        let result = merge_freshness(Some(&base), &update);
+        assert_eq!(result, Some(base));
+    }
+
+    #[test]
+    fn test_merge_freshness_omitted_no_base() {
+        // Omitted freshness with no base should return None
+        let update = Omissible::Omitted;
+        let result = merge_freshness(None, &update);
+        assert_eq!(result, Some(FreshnessDefinition::default()));
+    }
+
+    #[test]
+    fn test_merge_freshness_partial_update_overrides_completely() {
+        // Test that partial updates in the update completely override base
+        // This validates the comment about mantle logic
+        let base = FreshnessDefinition {
+            error_after: Some(FreshnessRules {
+                count: Some(5),
+                period: Some(FreshnessPeriod::hour),
+            }),
+            warn_after: Some(FreshnessRules {
+                count: Some(3),
+                period: Some(FreshnessPeriod::hour),
+            }),
+            filter: Some("base_filter".to_string()),
+        };
+
+        // Update only has error_after, but it should still completely replace base
+        let update_value = FreshnessDefinition {
+            error_after: Some(FreshnessRules {
+                count: Some(10),
+                period: Some(FreshnessPeriod::day),
+            }),
+            warn_after: None,
+            filter: None,
+        };
+
+        let update = Omissible::Present(Some(update_value.clone()));
+        let result = merge_freshness(Some(&base), &update);
+
+        // The result should be exactly the update, not a merge
+        assert_eq!(result, Some(update_value));
+        // Specifically verify that warn_after and filter are None, not inherited from base
+        assert!(result.as_ref().unwrap().warn_after.is_none());
+        assert!(result.as_ref().unwrap().filter.is_none());
+    }
+}
@@ -49,6 +49,11 @@ pub enum Backend {
     DatabricksODBC,
     /// Redshift driver implementation (ODBC).
     RedshiftODBC,
+    /// Microsoft SQL Server driver implementation (ADBC).
+    #[allow(clippy::upper_case_acronyms)]
+    MSSQL,
+    /// ClickHouse driver implementation (ADBC).
+    ClickHouse,
     /// Generic ADBC driver implementation.
     ///
     /// This variant is fully dynamic and experimental. Features might not work reliably and fail
@@ -76,12 +81,45 @@ impl Display for Backend {
             Backend::DatabricksODBC => write!(f, "Databricks"),
             Backend::RedshiftODBC => write!(f, "Redshift"),
             Backend::Salesforce => write!(f, "Salesforce"),
+            Backend::MSSQL => write!(f, "SQL Server"),
+            Backend::ClickHouse => write!(f, "ClickHouse"),
             Backend::Generic { library_name, .. } => write!(f, "Generic({library_name})"),
         }
     }
 }
 
 impl Backend {
+    /// Every backend with a built-in driver, i.e. all of them but
+    /// [Backend::Generic], which is parameterized by a dynamic library.
+    pub fn all() -> &'static [Backend] {
+        // Adding a variant fails to compile here until it is listed below
+        match Backend::Snowflake {
+            Backend::Snowflake
+            | Backend::BigQuery
+            | Backend::Postgres
+            | Backend::Databricks
+            | Backend::Redshift
+            | Backend::Salesforce
+            | Backend::DatabricksODBC
+            | Backend::RedshiftODBC
+            | Backend::MSSQL
+            | Backend::ClickHouse
+            | Backend::Generic { .. } => {}
+        }
+        &[
+            Backend::Snowflake,
+            Backend::BigQuery,
+            Backend::Postgres,
+            Backend::Databricks,
+            Backend::Redshift,
+            Backend::Salesforce,
+            Backend::DatabricksODBC,
+            Backend::RedshiftODBC,
+            Backend::MSSQL,
+            Backend::ClickHouse,
+        ]
+    }
+
     pub fn adbc_library_name(&self) -> Option<&'static str> {
         match self {
             Backend::Snowflake => Some("adbc_driver_snowflake"),
@@ -90,6 +128,8 @@ impl Backend {
             Backend::Databricks => Some("adbc_driver_databricks"),
             Backend::Salesforce => Some("adbc_driver_salesforce"),
             Backend::Redshift => Some("adbc_driver_redshift"),
+            Backend::MSSQL => Some("adbc_driver_mssql"),
+            Backend::ClickHouse => Some("adbc_driver_clickhouse"),
             Backend::DatabricksODBC | Backend::RedshiftODBC => None, // these use ODBC
             Backend::Generic { library_name, .. } => Some(library_name),
         }
@@ -114,6 +154,8 @@ impl Backend {
             | Backend::Databricks
             | Backend::Redshift
             | Backend::Salesforce
+            | Backend::MSSQL
+            | Backend::ClickHouse
             | Backend::Generic { .. } => FFIProtocol::Adbc,
             Backend::DatabricksODBC | Backend::RedshiftODBC => FFIProtocol::Odbc,
         }
@@ -355,11 +397,13 @@ impl AdbcDriver {
                 Self::try_load_driver_through_cdn_cache(backend, adbc_version)
             }
             // Drivers that are not published to the dbt Labs CDN.
-            Backend::Generic { .. } => Self::try_load_driver_from_name(
-                backend.adbc_library_name().unwrap(),
-                backend.adbc_driver_entrypoint(),
-                adbc_version,
-            ),
+            Backend::MSSQL | Backend::ClickHouse | Backend::Generic { .. } => {
+                Self::try_load_driver_from_name(
+                    backend.adbc_library_name().unwrap(),
+                    backend.adbc_driver_entrypoint(),
+                    adbc_version,
+                )
+            }
             // ODBC drivers.
             Backend::DatabricksODBC | Backend::RedshiftODBC => Err(Error::with_message_and_status(
                 format!(
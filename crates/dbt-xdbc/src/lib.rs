@@ -44,6 +44,7 @@ pub use statement::Statement;
 pub mod query_ctx;
 pub use query_ctx::QueryCtx;
 
+pub mod pool;
 pub mod semaphore;
 pub mod sql;
 
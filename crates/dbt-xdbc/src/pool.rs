@@ -0,0 +1,221 @@
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::semaphore::{PermitGuard, Semaphore};
+
+/// A fixed-size pool of pre-created resources (e.g. [Connection]s).
+///
+/// Checkouts are capped by a [Semaphore] holding one permit per resource, so
+/// a permit is always paired with an idle resource in the pool.
+///
+/// [Connection]: crate::Connection
+pub struct Pool<T> {
+    idle: Mutex<Vec<T>>,
+    semaphore: Semaphore,
+}
+
+impl<T> Pool<T> {
+    /// Create a pool that hands out the given resources.
+    ///
+    /// Panics if `resources` is empty, since nothing could ever be checked out.
+    pub fn new(resources: Vec<T>) -> Self {
+        assert!(!resources.is_empty(), "a pool needs at least one resource");
+        let max = resources.len() as u32;
+        Self {
+            idle: Mutex::new(resources),
+            semaphore: Semaphore::new(max),
+        }
+    }
+
+    /// Get the number of resources in the pool.
+    pub fn max(&self) -> u32 {
+        self.semaphore.max()
+    }
+
+    /// Get the number of resources that are not checked out (racy snapshot).
+    pub fn available(&self) -> u32 {
+        self.semaphore.available_permits()
+    }
+
+    /// Check out a resource, blocking until one is available.
+    #[must_use]
+    pub fn get(&self) -> Pooled<'_, T> {
+        let permit = self.semaphore.acquire();
+        self.checkout(permit)
+    }
+
+    /// Try to check out a resource without blocking.
+    #[must_use]
+    pub fn try_get(&self) -> Option<Pooled<'_, T>> {
+        let permit = self.semaphore.try_acquire()?;
+        Some(self.checkout(permit))
+    }
+
+    /// Check out a resource, blocking for at most `timeout`.
+    #[must_use]
+    pub fn get_timeout(&self, timeout: Duration) -> Option<Pooled<'_, T>> {
+        let permit = self.semaphore.acquire_timeout(timeout)?;
+        Some(self.checkout(permit))
+    }
+
+    fn checkout<'a>(&'a self, permit: PermitGuard<'a, false>) -> Pooled<'a, T> {
+        let resource = self
+            .idle
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .pop()
+            .expect("a permit guarantees an idle resource");
+        Pooled {
+            pool: self,
+            resource: Some(resource),
+            _permit: permit,
+        }
+    }
+}
+
+impl<T> fmt::Debug for Pool<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pool")
+            .field("semaphore", &self.semaphore)
+            .finish()
+    }
+}
+
+/// A resource checked out of a [Pool] that is returned to it when dropped.
+pub struct Pooled<'a, T> {
+    pool: &'a Pool<T>,
+    resource: Option<T>,
+    /// Released after [Drop::drop] puts the resource back in the pool.
+    _permit: PermitGuard<'a, false>,
+}
+
+impl<T> Deref for Pooled<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        self.resource.as_ref().unwrap()
+    }
+}
+
+impl<T> DerefMut for Pooled<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        self.resource.as_mut().unwrap()
+    }
+}
+
+impl<T> Drop for Pooled<'_, T> {
+    fn drop(&mut self) {
+        if let Some(resource) = self.resource.take() {
+            // don't panic in drop if another thread poisoned the lock
+            let mut idle = self.pool.idle.lock().unwrap_or_else(|e| e.into_inner());
+            idle.push(resource);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::thread;
+
+    struct MockConnection {
+        id: u32,
+        queries: u32,
+    }
+
+    fn mock_pool(max: u32) -> Pool<MockConnection> {
+        Pool::new(
+            (0..max)
+                .map(|id| MockConnection { id, queries: 0 })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_pool_checkout_and_return() {
+        let pool = mock_pool(2);
+        assert_eq!(pool.max(), 2);
+
+        let mut conn0 = pool.get();
+        conn0.queries += 1;
+        let conn1 = pool.try_get().unwrap();
+        assert_ne!(conn0.id, conn1.id);
+        assert_eq!(pool.available(), 0);
+        assert!(pool.try_get().is_none());
+        assert!(pool.get_timeout(Duration::from_millis(10)).is_none());
+
+        let id0 = conn0.id;
+        drop(conn0);
+        assert_eq!(pool.available(), 1);
+        let conn = pool.get();
+        assert_eq!(conn.id, id0);
+        assert_eq!(conn.queries, 1);
+    }
+
+    #[test]
+    fn test_pool_never_exceeds_max() {
+        const MAX: u32 = 3;
+        let pool = Arc::new(mock_pool(MAX));
+        let in_use = Arc::new(AtomicU32::new(0));
+        let peak = Arc::new(AtomicU32::new(0));
+
+        let handles = (0..8)
+            .map(|_| {
+                let pool = pool.clone();
+                let in_use = in_use.clone();
+                let peak = peak.clone();
+                thread::spawn(move || {
+                    for _ in 0..10 {
+                        let mut conn = pool.get();
+                        let n = in_use.fetch_add(1, Ordering::SeqCst) + 1;
+                        peak.fetch_max(n, Ordering::SeqCst);
+                        conn.queries += 1;
+                        thread::sleep(Duration::from_millis(1));
+                        in_use.fetch_sub(1, Ordering::SeqCst);
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert!(peak.load(Ordering::SeqCst) <= MAX);
+        assert_eq!(pool.available(), MAX);
+        let total = pool
+            .idle
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|c| c.queries)
+            .sum::<u32>();
+        assert_eq!(total, 80);
+    }
+
+    #[test]
+    #[should_panic(expected = "a pool needs at least one resource")]
+    fn test_pool_empty() {
+        let _ = mock_pool(0);
+    }
+
+    #[test]
+    fn test_pool_checkout_after_poisoned_lock() {
+        let pool = Arc::new(mock_pool(1));
+        let poisoner = pool.clone();
+        let _ = thread::spawn(move || {
+            let _idle = poisoner.idle.lock().unwrap();
+            panic!("poison the idle list");
+        })
+        .join();
+        assert!(pool.idle.is_poisoned());
+
+        let conn = pool.get();
+        assert_eq!(conn.id, 0);
+        drop(conn);
+        assert_eq!(pool.available(), 1);
+    }
+}
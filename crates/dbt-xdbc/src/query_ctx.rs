@@ -1,20 +1,36 @@
 use std::fmt;
 use std::str::FromStr;
+use std::time::Duration;
 
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// Phases in the order they are executed, so phases can be compared with `<`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub enum ExecutionPhase {
-    Unspecified,
-    Render,
-    Analyze,
-    Run,
+    Unspecified = 0,
+    Render = 1,
+    Analyze = 2,
+    Run = 3,
+}
+
+impl ExecutionPhase {
+    /// Returns true if this phase comes after `other`.
+    pub fn is_after(self, other: ExecutionPhase) -> bool {
+        self > other
+    }
+
+    /// Returns true if this phase comes before `other`.
+    pub fn is_before(self, other: ExecutionPhase) -> bool {
+        self < other
+    }
 }
 
 impl FromStr for ExecutionPhase {
     type Err = String;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
+            "unspecified" => Ok(ExecutionPhase::Unspecified),
             "render" => Ok(ExecutionPhase::Render),
             "analyze" => Ok(ExecutionPhase::Analyze),
             "run" => Ok(ExecutionPhase::Run),
@@ -35,22 +51,48 @@ impl fmt::Display for ExecutionPhase {
     }
 }
 
+/// Serialized as the same string as [fmt::Display].
+impl Serialize for ExecutionPhase {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ExecutionPhase {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
 /// Context carrying metadata associated with a query.
-#[derive(Clone, Debug)]
+///
+/// Serializable so that query metadata can be cached, with the timestamps
+/// in RFC 3339 format.
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct QueryCtx {
     // Model executing this query
     node_unique_id: Option<String>,
     // Execution Phase
     phase: Option<ExecutionPhase>,
+    // SQL of the query
+    sql: Option<String>,
     // Time this instance was created
     created_at: DateTime<Utc>,
+    // Time the query finished, see [QueryCtx::mark_completed]
+    completed_at: Option<DateTime<Utc>>,
     // Description (abribrary string) associated with the query
     desc: Option<String>,
+    // Id assigned to the query by the backend
+    query_id: Option<String>,
+    // Number of times the query was retried, 0 for the original attempt
+    #[serde(default)]
+    retry_attempt: u32,
 }
 
 impl Default for QueryCtx {
     fn default() -> Self {
-        QueryCtx::create(None, None, None)
+        QueryCtx::create(None, None, None, None)
     }
 }
 
@@ -58,13 +100,18 @@ impl QueryCtx {
     fn create(
         node_unique_id: Option<String>,
         phase: Option<ExecutionPhase>,
+        sql: Option<String>,
         desc: Option<String>,
     ) -> Self {
         Self {
             node_unique_id,
             phase,
+            sql,
             created_at: Utc::now(),
+            completed_at: None,
             desc,
+            query_id: None,
+            retry_attempt: 0,
         }
     }
 
@@ -73,7 +120,22 @@ impl QueryCtx {
     pub fn with_node_id(self, node_unique_id: impl Into<String>) -> Self {
         // We never allow unique id to be reassigned
         assert!(self.node_unique_id.is_none());
-        Self::create(Some(node_unique_id.into()), self.phase, self.desc)
+        Self {
+            node_unique_id: Some(node_unique_id.into()),
+            ..self
+        }
+    }
+
+    /// Creates a context for a sub-query derived from the query of this
+    /// context. The node id and phase are inherited, but the SQL is replaced,
+    /// the description is cleared and the creation time is reset.
+    pub fn child_query(&self, sql: impl Into<String>) -> Self {
+        Self::create(
+            self.node_unique_id.clone(),
+            self.phase,
+            Some(sql.into()),
+            None,
+        )
     }
 
     /// Create a new context by keeping other fields same and using
@@ -93,7 +155,48 @@ impl QueryCtx {
     /// Creates a new context by keeping other fields same and setting
     /// the given execution phase.
     pub fn with_phase(self, phase: ExecutionPhase) -> Self {
-        Self::create(self.node_unique_id, Some(phase), self.desc)
+        Self {
+            phase: Some(phase),
+            ..self
+        }
+    }
+
+    /// Creates a new context by keeping other fields same and recording
+    /// the current time as the completion time of the query.
+    pub fn mark_completed(&self) -> Self {
+        Self {
+            completed_at: Some(Utc::now()),
+            ..self.clone()
+        }
+    }
+
+    /// Creates a new context by keeping other fields same and setting the
+    /// id the backend assigned to the query.
+    ///
+    /// Unlike the node id and description, the query id can be reassigned.
+    pub fn with_query_id(&self, query_id: impl Into<String>) -> Self {
+        Self {
+            query_id: Some(query_id.into()),
+            ..self.clone()
+        }
+    }
+
+    /// Creates a new context by keeping other fields same and setting the
+    /// retry attempt of the query, where 0 is the original attempt.
+    pub fn with_retry_attempt(&self, retry_attempt: u32) -> Self {
+        Self {
+            retry_attempt,
+            ..self.clone()
+        }
+    }
+
+    /// Creates a new context by keeping other fields same but dropping the
+    /// SQL, for passing the metadata of a query around without its SQL.
+    pub fn without_sql(&self) -> Self {
+        Self {
+            sql: None,
+            ..self.clone()
+        }
     }
 
     /// Return unique node id associated with this context
@@ -101,6 +204,41 @@ impl QueryCtx {
         self.node_unique_id.as_ref()
     }
 
+    /// Returns the SQL of the query associated with this context
+    pub fn sql(&self) -> Option<&String> {
+        self.sql.as_ref()
+    }
+
+    /// Returns the length in bytes of the SQL of this context, or 0 if
+    /// there is no SQL.
+    pub fn sql_byte_len(&self) -> usize {
+        self.sql.as_ref().map_or(0, String::len)
+    }
+
+    /// Returns a copy of at most `max` bytes of the SQL of this context.
+    ///
+    /// The SQL is cut at the last character boundary that fits, so a UTF-8
+    /// sequence is never split. Unlike the SQL shown by [fmt::Display], the
+    /// prefix is neither trimmed nor marked with an ellipsis.
+    pub fn sql_byte_prefix(&self, max: usize) -> Option<String> {
+        let sql = self.sql.as_deref()?;
+        let mut end = max.min(sql.len());
+        while !sql.is_char_boundary(end) {
+            end -= 1;
+        }
+        Some(sql[..end].to_string())
+    }
+
+    /// Returns the number of statements in the SQL of this context, or 0
+    /// if there is no SQL.
+    ///
+    /// Statements are separated by top-level semicolons. Semicolons inside
+    /// string literals, quoted identifiers and comments don't count, and
+    /// neither do empty statements (e.g. after a trailing semicolon).
+    pub fn statement_count(&self) -> usize {
+        self.sql.as_deref().map_or(0, count_statements)
+    }
+
     /// Returns time this instance was created.
     pub fn created_at(&self) -> DateTime<Utc> {
         self.created_at
@@ -111,22 +249,230 @@ impl QueryCtx {
         self.created_at.to_rfc3339()
     }
 
+    /// Returns time the query was marked as completed, if it was.
+    pub fn completed_at(&self) -> Option<DateTime<Utc>> {
+        self.completed_at
+    }
+
+    /// Returns the time between the creation of this context and the
+    /// completion of the query, or `None` if the query isn't completed.
+    ///
+    /// Wall-clock adjustments between the two instants yield a zero duration.
+    pub fn elapsed(&self) -> Option<Duration> {
+        self.completed_at.map(|completed_at| {
+            (completed_at - self.created_at)
+                .to_std()
+                .unwrap_or_default()
+        })
+    }
+
     /// Returns a clone of the description associated with the
     /// context.
     pub fn desc(&self) -> Option<&String> {
         self.desc.as_ref()
     }
 
+    /// Returns the id the backend assigned to the query, if known.
+    pub fn query_id(&self) -> Option<&String> {
+        self.query_id.as_ref()
+    }
+
     /// Returns the Execution Phase
     pub fn phase(&self) -> Option<ExecutionPhase> {
         self.phase
     }
+
+    /// Returns the retry attempt of the query, 0 for the original attempt.
+    pub fn retry_attempt(&self) -> u32 {
+        self.retry_attempt
+    }
+
+    /// Compare all the fields of two contexts except for their timestamps
+    /// (`created_at` and `completed_at`), which differ from one run to another.
+    pub fn eq_ignoring_time(&self, other: &QueryCtx) -> bool {
+        // destructure so that new fields can't be forgotten here
+        let QueryCtx {
+            node_unique_id,
+            phase,
+            sql,
+            created_at: _,
+            completed_at: _,
+            desc,
+            query_id,
+            retry_attempt,
+        } = self;
+        *node_unique_id == other.node_unique_id
+            && *phase == other.phase
+            && *sql == other.sql
+            && *desc == other.desc
+            && *query_id == other.query_id
+            && *retry_attempt == other.retry_attempt
+    }
+}
+
+/// Get the contexts that were created for the given execution phase.
+///
+/// Contexts without a phase don't match any phase, not even
+/// [ExecutionPhase::Unspecified].
+pub fn filter_by_phase(ctxs: &[QueryCtx], phase: ExecutionPhase) -> Vec<&QueryCtx> {
+    ctxs.iter().filter(|ctx| ctx.phase == Some(phase)).collect()
+}
+
+/// Maximum number of characters of SQL shown when displaying a [QueryCtx].
+const SQL_PREVIEW_CHARS: usize = 120;
+
+/// Display the context on a single line for logging.
+///
+/// The context doesn't know the adapter type, log sites should add it when needed.
+impl fmt::Display for QueryCtx {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let phase = self.phase.unwrap_or(ExecutionPhase::Unspecified);
+        write!(f, "phase={phase}")?;
+        if let Some(node_id) = &self.node_unique_id {
+            write!(f, " node_id={node_id}")?;
+        }
+        if let Some(desc) = &self.desc {
+            write!(f, " desc={desc:?}")?;
+        }
+        if self.retry_attempt > 0 {
+            write!(f, " retry_attempt={}", self.retry_attempt)?;
+        }
+        if let Some(sql) = &self.sql {
+            write!(f, " sql={:?}", sql_preview(sql))?;
+        }
+        Ok(())
+    }
+}
+
+/// Truncate `sql` to [SQL_PREVIEW_CHARS] characters (not bytes, so a UTF-8
+/// sequence is never split) and mark the truncation with an ellipsis.
+fn sql_preview(sql: &str) -> String {
+    let sql = sql.trim();
+    match sql.char_indices().nth(SQL_PREVIEW_CHARS) {
+        Some((end, _)) => format!("{}…", &sql[..end]),
+        None => sql.to_string(),
+    }
+}
+
+fn count_statements(sql: &str) -> usize {
+    let bytes = sql.as_bytes();
+    let mut count = 0;
+    // whether the current statement has anything other than whitespace and comments
+    let mut non_empty = false;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            quote @ (b'\'' | b'"' | b'`') => {
+                // doubled quotes ('') are consumed as two adjacent literals
+                non_empty = true;
+                i += 1;
+                while i < bytes.len() && bytes[i] != quote {
+                    if bytes[i] == b'\\' && quote == b'\'' {
+                        i += 1;
+                    }
+                    i += 1;
+                }
+            }
+            b'-' if bytes.get(i + 1) == Some(&b'-') => {
+                while i < bytes.len() && bytes[i] != b'\n' {
+                    i += 1;
+                }
+            }
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i += 2;
+                while i < bytes.len() && !(bytes[i] == b'*' && bytes.get(i + 1) == Some(&b'/')) {
+                    i += 1;
+                }
+                i += 1;
+            }
+            b';' => {
+                if non_empty {
+                    count += 1;
+                }
+                non_empty = false;
+            }
+            b if b.is_ascii_whitespace() => {}
+            _ => non_empty = true,
+        }
+        i += 1;
+    }
+    if non_empty {
+        count += 1;
+    }
+    count
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_execution_phase_order() {
+        use ExecutionPhase::*;
+        let phases = [Unspecified, Render, Analyze, Run];
+        assert!(phases.is_sorted());
+        for (i, phase) in phases.iter().enumerate() {
+            for (j, other) in phases.iter().enumerate() {
+                assert_eq!(phase.is_after(*other), i > j, "{phase} after {other}");
+                assert_eq!(phase.is_before(*other), i < j, "{phase} before {other}");
+            }
+            // the ordering doesn't change the textual representation
+            if *phase != Unspecified {
+                assert_eq!(phase.to_string().parse::<ExecutionPhase>(), Ok(*phase));
+            }
+        }
+
+        let ctx = QueryCtx::default().with_phase(Run);
+        assert!(ctx.phase().is_some_and(|p| p.is_after(Analyze)));
+        assert!(
+            !QueryCtx::default()
+                .phase()
+                .is_some_and(|p| p.is_after(Analyze))
+        );
+    }
+
+    #[test]
+    fn test_serde_roundtrip() {
+        let ctx = QueryCtx::default()
+            .with_node_id("model.p.m")
+            .with_phase(ExecutionPhase::Analyze)
+            .child_query("select 1")
+            .with_desc("probe")
+            .with_query_id("01b2")
+            .mark_completed();
+        let json = serde_json::to_value(&ctx).unwrap();
+        assert_eq!(json["phase"], "analyze");
+        let created_at = json["created_at"].as_str().unwrap();
+        assert_eq!(
+            DateTime::parse_from_rfc3339(created_at).unwrap(),
+            ctx.created_at()
+        );
+
+        let restored: QueryCtx = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.node_id(), ctx.node_id());
+        assert_eq!(restored.phase(), ctx.phase());
+        assert_eq!(restored.sql(), ctx.sql());
+        assert_eq!(restored.desc(), ctx.desc());
+        assert_eq!(restored.query_id(), ctx.query_id());
+        assert_eq!(restored.created_at(), ctx.created_at());
+        assert_eq!(restored.completed_at(), ctx.completed_at());
+        assert_eq!(restored.retry_attempt(), 0);
+
+        let restored: QueryCtx =
+            serde_json::from_str(&serde_json::to_string(&QueryCtx::default()).unwrap()).unwrap();
+        assert_eq!(restored.phase(), None);
+        assert_eq!(restored.completed_at(), None);
+
+        assert!(serde_json::from_str::<ExecutionPhase>("\"compile\"").is_err());
+        for phase in [ExecutionPhase::Unspecified, ExecutionPhase::Run] {
+            let json = serde_json::to_string(&phase).unwrap();
+            assert_eq!(
+                serde_json::from_str::<ExecutionPhase>(&json).unwrap(),
+                phase
+            );
+        }
+    }
+
     #[test]
     fn test_desc() {
         let query_ctx = QueryCtx::default().with_desc("this is a really good query");
@@ -150,4 +496,260 @@ mod tests {
     fn test_unique_id_twice() {
         QueryCtx::default().with_node_id("123").with_node_id("abc");
     }
+
+    #[test]
+    fn test_query_id() {
+        let ctx = QueryCtx::default().with_node_id("model.a");
+        assert!(ctx.query_id().is_none());
+
+        let ctx = ctx.with_query_id("01b2c3d4");
+        assert_eq!(ctx.query_id().unwrap(), "01b2c3d4");
+        assert_eq!(ctx.node_id().unwrap(), "model.a");
+
+        // the id can be refined once the backend reports it
+        let ctx = ctx.with_query_id("01b2c3d4-0000-0001");
+        assert_eq!(ctx.query_id().unwrap(), "01b2c3d4-0000-0001");
+
+        // a sub-query gets its own id
+        assert!(ctx.child_query("select 1").query_id().is_none());
+    }
+
+    #[test]
+    fn test_builders_keep_other_fields() {
+        let ctx = QueryCtx::default()
+            .with_query_id("01b2")
+            .with_retry_attempt(2)
+            .mark_completed();
+        let ctx = ctx.with_phase(ExecutionPhase::Run).with_node_id("model.a");
+        assert_eq!(ctx.phase(), Some(ExecutionPhase::Run));
+        assert_eq!(ctx.node_id().unwrap(), "model.a");
+        assert_eq!(ctx.query_id().unwrap(), "01b2");
+        assert_eq!(ctx.retry_attempt(), 2);
+        assert!(ctx.completed_at().is_some());
+    }
+
+    #[test]
+    fn test_retry_attempt() {
+        let ctx = QueryCtx::default()
+            .with_node_id("model.a")
+            .child_query("select 1");
+        assert_eq!(ctx.retry_attempt(), 0);
+
+        let mut retry = ctx.clone();
+        for attempt in 1..=3 {
+            retry = retry.with_retry_attempt(retry.retry_attempt() + 1);
+            assert_eq!(retry.retry_attempt(), attempt);
+            assert_eq!(retry.sql(), ctx.sql());
+        }
+        // the original context is left untouched
+        assert_eq!(ctx.retry_attempt(), 0);
+        // a sub-query starts over
+        assert_eq!(retry.child_query("select 2").retry_attempt(), 0);
+        // but moving the retry on to another phase doesn't
+        let retry = retry.with_phase(ExecutionPhase::Run);
+        assert_eq!(retry.retry_attempt(), 3);
+
+        assert_eq!(
+            retry.to_string(),
+            r#"phase=run node_id=model.a retry_attempt=3 sql="select 1""#
+        );
+        let json = serde_json::to_value(&retry).unwrap();
+        assert_eq!(json["retry_attempt"], 3);
+        let restored: QueryCtx = serde_json::from_value(json).unwrap();
+        assert_eq!(restored.retry_attempt(), 3);
+    }
+
+    #[test]
+    fn test_without_sql() {
+        let ctx = QueryCtx::default()
+            .with_node_id("model.a")
+            .with_phase(ExecutionPhase::Run)
+            .child_query("select 1")
+            .with_desc("main query")
+            .with_query_id("01b2")
+            .mark_completed();
+        let stripped = ctx.without_sql();
+        assert!(stripped.sql().is_none());
+        assert_eq!(stripped.statement_count(), 0);
+        assert_eq!(stripped.node_id(), ctx.node_id());
+        assert_eq!(stripped.phase(), ctx.phase());
+        assert_eq!(stripped.desc(), ctx.desc());
+        assert_eq!(stripped.query_id(), ctx.query_id());
+        assert_eq!(stripped.created_at(), ctx.created_at());
+        assert_eq!(stripped.completed_at(), ctx.completed_at());
+        assert_eq!(
+            stripped.to_string(),
+            r#"phase=run node_id=model.a desc="main query""#
+        );
+        // the original context is left untouched
+        assert_eq!(ctx.sql().unwrap(), "select 1");
+    }
+
+    #[test]
+    fn test_child_query() {
+        let parent = QueryCtx::default()
+            .with_node_id("model.a")
+            .with_phase(ExecutionPhase::Run)
+            .with_desc("main query");
+        std::thread::sleep(std::time::Duration::from_millis(2));
+        let child = parent.child_query("select 1");
+        assert_eq!(child.node_id().unwrap(), "model.a");
+        assert_eq!(child.phase(), Some(ExecutionPhase::Run));
+        assert_eq!(child.sql().unwrap(), "select 1");
+        assert_ne!(child.sql(), parent.sql());
+        assert!(child.desc().is_none());
+        assert!(child.created_at() > parent.created_at());
+
+        // a child context can be described on its own
+        let child = child.with_desc("helper query");
+        assert_eq!(child.desc().unwrap(), "helper query");
+    }
+
+    #[test]
+    fn test_elapsed() {
+        let ctx = QueryCtx::default().with_node_id("model.a");
+        assert!(ctx.completed_at().is_none());
+        assert!(ctx.elapsed().is_none());
+
+        std::thread::sleep(Duration::from_millis(2));
+        let completed = ctx.mark_completed();
+        assert_eq!(completed.node_id().unwrap(), "model.a");
+        assert_eq!(completed.created_at(), ctx.created_at());
+        assert!(completed.completed_at().unwrap() > ctx.created_at());
+        assert!(completed.elapsed().unwrap() >= Duration::from_millis(2));
+        // the original context is left untouched
+        assert!(ctx.elapsed().is_none());
+    }
+
+    #[test]
+    fn test_statement_count() {
+        assert_eq!(QueryCtx::default().statement_count(), 0);
+
+        let table = vec![
+            (line!(), "select 1", 1),
+            (line!(), "select 1;", 1),
+            (
+                line!(),
+                "create table t (a int); insert into t values (1); select * from t;",
+                3,
+            ),
+            (line!(), "select 'a;b' as x", 1),
+            (line!(), "select 'it''s; fine', \"a;b\" from t", 1),
+            (line!(), "select 1; -- done; really\n", 1),
+            (line!(), "/* one; two */ select 1; select 2", 2),
+            (line!(), " ; ;  ", 0),
+        ];
+        for (line, sql, expected) in table {
+            let ctx = QueryCtx::default().child_query(sql);
+            assert_eq!(ctx.statement_count(), expected, "{sql} from line {line}");
+        }
+    }
+
+    #[test]
+    fn test_sql_byte_len_and_prefix() {
+        let ctx = QueryCtx::default();
+        assert_eq!(ctx.sql_byte_len(), 0);
+        assert_eq!(ctx.sql_byte_prefix(10), None);
+
+        let ctx = ctx.child_query("select 1");
+        assert_eq!(ctx.sql_byte_len(), 8);
+        assert_eq!(ctx.sql_byte_prefix(6).unwrap(), "select");
+        assert_eq!(ctx.sql_byte_prefix(100).unwrap(), "select 1");
+        assert_eq!(ctx.sql_byte_prefix(0).unwrap(), "");
+
+        // "é" takes 2 bytes, a cut in its middle backs off to the boundary before it
+        let ctx = ctx.child_query("select 'été'");
+        assert_eq!(ctx.sql_byte_len(), 14);
+        assert_eq!(ctx.sql_byte_prefix(9).unwrap(), "select '");
+        assert_eq!(ctx.sql_byte_prefix(10).unwrap(), "select 'é");
+        assert_eq!(ctx.sql_byte_prefix(11).unwrap(), "select 'ét");
+    }
+
+    #[test]
+    fn test_display() {
+        let ctx = QueryCtx::default();
+        assert_eq!(ctx.to_string(), "phase=unspecified");
+
+        let ctx = QueryCtx::default()
+            .with_node_id("model.a")
+            .with_phase(ExecutionPhase::Run)
+            .with_desc("main query")
+            .child_query("select 1")
+            .with_desc("helper");
+        assert_eq!(
+            ctx.to_string(),
+            r#"phase=run node_id=model.a desc="helper" sql="select 1""#
+        );
+
+        // truncation counts characters, not bytes
+        let sql = format!("select '{}'", "é".repeat(200));
+        let preview = sql_preview(&sql);
+        assert_eq!(preview.chars().count(), SQL_PREVIEW_CHARS + 1);
+        assert!(preview.ends_with("é…"));
+        let ctx = QueryCtx::default().child_query(sql);
+        assert!(ctx.to_string().ends_with("…\""));
+    }
+
+    #[test]
+    fn test_filter_by_phase() {
+        let ctxs = vec![
+            QueryCtx::default()
+                .with_phase(ExecutionPhase::Run)
+                .with_desc("a"),
+            QueryCtx::default(),
+            QueryCtx::default()
+                .with_phase(ExecutionPhase::Analyze)
+                .with_desc("b"),
+            QueryCtx::default()
+                .with_phase(ExecutionPhase::Run)
+                .with_desc("c"),
+            QueryCtx::default().with_phase(ExecutionPhase::Unspecified),
+        ];
+
+        let descs = |phase| -> Vec<_> {
+            filter_by_phase(&ctxs, phase)
+                .into_iter()
+                .map(|ctx| ctx.desc().map(String::as_str))
+                .collect()
+        };
+        assert_eq!(descs(ExecutionPhase::Run), [Some("a"), Some("c")]);
+        assert_eq!(descs(ExecutionPhase::Analyze), [Some("b")]);
+        assert!(descs(ExecutionPhase::Render).is_empty());
+        // the context without a phase isn't included
+        assert_eq!(descs(ExecutionPhase::Unspecified), [None]);
+    }
+
+    #[test]
+    fn test_eq_ignoring_time() {
+        let build = || {
+            QueryCtx::default()
+                .with_node_id("model.p.m")
+                .with_phase(ExecutionPhase::Run)
+                .child_query("select 1")
+                .with_desc("probe")
+                .with_query_id("01b2")
+        };
+        let ctx = build();
+        std::thread::sleep(Duration::from_millis(2));
+        let later = build().mark_completed();
+        assert_ne!(ctx.created_at(), later.created_at());
+        assert!(ctx.eq_ignoring_time(&later));
+        assert!(later.eq_ignoring_time(&ctx));
+
+        assert!(!ctx.eq_ignoring_time(&ctx.with_retry_attempt(1)));
+        assert!(!ctx.eq_ignoring_time(&ctx.without_sql()));
+        assert!(!ctx.eq_ignoring_time(&ctx.with_query_id("03c4")));
+
+        // contexts that differ only in their description or phase
+        let build = |desc: &str, phase| {
+            QueryCtx::default()
+                .with_node_id("model.p.m")
+                .with_phase(phase)
+                .with_desc(desc)
+        };
+        let ctx = build("probe", ExecutionPhase::Run);
+        assert!(ctx.eq_ignoring_time(&build("probe", ExecutionPhase::Run)));
+        assert!(!ctx.eq_ignoring_time(&build("other", ExecutionPhase::Run)));
+        assert!(!ctx.eq_ignoring_time(&build("probe", ExecutionPhase::Analyze)));
+    }
 }
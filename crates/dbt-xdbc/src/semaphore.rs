@@ -1,5 +1,9 @@
 use std::fmt;
 use std::sync::atomic::{AtomicU32, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use dbt_cancel::CancellationToken;
 
 /// General-case semaphore implementation.
 ///
@@ -69,6 +73,16 @@ impl AtomicSemaphoreBase {
         self.try_acquire_impl(old, 1)
     }
 
+    pub fn try_acquire_many(&self, ask: u32) -> bool {
+        let old = self.a.load(Ordering::Acquire);
+        self.try_acquire_impl(old, ask)
+    }
+
+    /// Try to acquire a permit, giving up once `timeout` has elapsed.
+    pub fn acquire_timeout(&self, timeout: Duration) -> bool {
+        retry_with_backoff(timeout, || self.try_acquire())
+    }
+
     pub fn acquire_many(&self, ask: u32) {
         debug_assert!(ask > 0, "cannot acquire zero permits");
         let mut insufficient = (ask - 1).min(self.a.load(Ordering::Relaxed));
@@ -84,41 +98,346 @@ impl AtomicSemaphoreBase {
     }
 }
 
+/// Bounds of the sleep between attempts in [retry_with_backoff].
+const MIN_BACKOFF: Duration = Duration::from_micros(50);
+const MAX_BACKOFF: Duration = Duration::from_millis(10);
+
+/// Call `attempt` until it succeeds or `timeout` has elapsed.
+///
+/// `atomic_wait::wait` can't time out, so this sleeps between attempts
+/// with an exponential backoff (capped by the remaining time) instead.
+fn retry_with_backoff(timeout: Duration, attempt: impl Fn() -> bool) -> bool {
+    let deadline = Instant::now() + timeout;
+    let mut backoff = MIN_BACKOFF;
+    loop {
+        if attempt() {
+            return true;
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            return false;
+        }
+        thread::sleep(backoff.min(deadline - now));
+        backoff = (backoff * 2).min(MAX_BACKOFF);
+    }
+}
+
+/// Like [retry_with_backoff], but keeps trying until `token` is cancelled
+/// instead of until a deadline. Returns false once cancelled.
+fn retry_until_cancelled(token: &CancellationToken, attempt: impl Fn() -> bool) -> bool {
+    let mut backoff = MIN_BACKOFF;
+    loop {
+        if token.is_cancelled() {
+            return false;
+        }
+        if attempt() {
+            return true;
+        }
+        thread::sleep(backoff);
+        backoff = (backoff * 2).min(MAX_BACKOFF);
+    }
+}
+
+/// Like [retry_with_backoff], but keeps trying until `attempt` succeeds, for
+/// waits that can't count on being woken up.
+fn retry_until_success(attempt: impl Fn() -> bool) {
+    let mut backoff = MIN_BACKOFF;
+    while !attempt() {
+        thread::sleep(backoff);
+        backoff = (backoff * 2).min(MAX_BACKOFF);
+    }
+}
+
+/// FIFO queue of threads waiting for a permit in a fair [Semaphore].
+///
+/// Ticket lock: every waiter takes a ticket and only the thread holding the
+/// ticket being served competes for a permit.
+struct TicketQueue {
+    next_ticket: AtomicU32,
+    now_serving: AtomicU32,
+}
+
+impl TicketQueue {
+    const fn new() -> Self {
+        Self {
+            next_ticket: AtomicU32::new(0),
+            now_serving: AtomicU32::new(0),
+        }
+    }
+
+    /// Block until it's the turn of the calling thread.
+    fn enter(&self) {
+        let ticket = self.next_ticket.fetch_add(1, Ordering::Relaxed);
+        loop {
+            let serving = self.now_serving.load(Ordering::Acquire);
+            if serving == ticket {
+                break;
+            }
+            atomic_wait::wait(&self.now_serving, serving);
+        }
+    }
+
+    /// Enter only if no other thread is waiting (or being served).
+    fn try_enter(&self) -> bool {
+        let serving = self.now_serving.load(Ordering::Acquire);
+        self.next_ticket
+            .compare_exchange(
+                serving,
+                serving.wrapping_add(1),
+                Ordering::Acquire,
+                Ordering::Relaxed,
+            )
+            .is_ok()
+    }
+
+    /// Let the next thread in line proceed.
+    fn leave(&self) {
+        self.now_serving.fetch_add(1, Ordering::Release);
+        atomic_wait::wake_all(&self.now_serving);
+    }
+}
+
 /// Counting semaphore implementation.
+///
+/// [Semaphore::new] creates an unfair semaphore: on release, all waiters are
+/// woken up and whoever wins the race gets the permit, so a thread can starve
+/// under heavy contention. [Semaphore::new_fair] creates a semaphore that
+/// grants permits in arrival order at the cost of serializing all waiters
+/// through a ticket queue (and a second round of wake-ups).
 pub struct Semaphore {
     /// The maximum number of permits the semaphore can hold.
     ///
     /// NOTE: If release() gets called more than this number, it will not
     /// panic, but will simply increase the count of available permits.
-    max: u32,
+    ///
+    /// Can grow at runtime with [Semaphore::add_permits].
+    max: AtomicU32,
     base: AtomicSemaphoreBase,
+    /// Waiters queue of a fair semaphore, `None` for an unfair one.
+    queue: Option<TicketQueue>,
 }
 
 impl Semaphore {
     pub const fn new(count: u32) -> Self {
         debug_assert!(count > 0, "Semaphore must allow for at least one permit");
         Self {
-            max: count,
+            max: AtomicU32::new(count),
+            base: AtomicSemaphoreBase::new(count),
+            queue: None,
+        }
+    }
+
+    /// Create a semaphore that grants permits in FIFO order.
+    pub const fn new_fair(count: u32) -> Self {
+        debug_assert!(count > 0, "Semaphore must allow for at least one permit");
+        Self {
+            max: AtomicU32::new(count),
             base: AtomicSemaphoreBase::new(count),
+            queue: Some(TicketQueue::new()),
         }
     }
 
-    /// Get the number of available permits the semaphore started with.
+    fn acquire_impl(&self) {
+        match &self.queue {
+            Some(queue) => {
+                queue.enter();
+                self.base.acquire();
+                queue.leave();
+            }
+            None => self.base.acquire(),
+        }
+    }
+
+    /// Run a non-blocking `attempt`, but on a fair semaphore only if no other
+    /// thread is waiting in line.
+    fn try_impl(&self, attempt: impl FnOnce() -> bool) -> bool {
+        match &self.queue {
+            Some(queue) => {
+                if !queue.try_enter() {
+                    return false;
+                }
+                let acquired = attempt();
+                queue.leave();
+                acquired
+            }
+            None => attempt(),
+        }
+    }
+
+    /// Get the maximum number of permits the semaphore holds.
+    ///
+    /// This is the number of permits the semaphore started with plus the ones
+    /// added with [Semaphore::add_permits].
     pub fn max(&self) -> u32 {
-        self.max
+        self.max.load(Ordering::Acquire)
+    }
+
+    /// Grow the capacity of the semaphore by `n` permits.
+    ///
+    /// The new permits are released right away, waking up blocked waiters.
+    pub fn add_permits(&self, n: u32) {
+        self.add_permits_impl(n, false);
+    }
+
+    fn add_permits_impl(&self, n: u32, force_wake: bool) {
+        self.max.fetch_add(n, Ordering::AcqRel);
+        self.base.release(n, force_wake);
+    }
+
+    /// Get the number of permits currently available.
+    ///
+    /// This is a racy snapshot: other threads may acquire or release permits
+    /// right after it's read, so only use it for diagnostics and heuristics.
+    pub fn available_permits(&self) -> u32 {
+        self.base.a.load(Ordering::Acquire)
+    }
+
+    /// Get a snapshot of the permit utilization of the semaphore for monitoring.
+    ///
+    /// Like [Semaphore::available_permits], this is racy: `max` and `available`
+    /// are read one after the other, so the numbers may be slightly off while
+    /// other threads acquire, release or add permits.
+    pub fn snapshot(&self) -> SemaphoreStats {
+        let max = self.max();
+        let available = self.available_permits();
+        SemaphoreStats {
+            max,
+            available,
+            // over-releasing can leave more permits available than the max
+            in_use: max.saturating_sub(available),
+        }
     }
 
     /// Acquire a permit, blocking until one is available.
     #[must_use]
     pub fn acquire(&self) -> PermitGuard<'_, false> {
-        self.base.acquire();
+        self.acquire_impl();
         PermitGuard { base: &self.base }
     }
 
+    /// Run `f` while holding a permit, blocking until one is available.
+    ///
+    /// The permit is released when `f` returns or panics.
+    pub fn scope<R>(&self, f: impl FnOnce() -> R) -> R {
+        let _permit = self.acquire();
+        f()
+    }
+
+    /// Acquire `n` permits one at a time, blocking until each is available,
+    /// and release them all together when the returned batch is dropped.
+    ///
+    /// Unlike [AcquireAllSemaphore::acquire_many], this is not all-or-nothing:
+    /// permits are held as they're acquired while waiting for the rest, so two
+    /// batches that together need more than [Semaphore::max] can deadlock.
+    #[must_use]
+    pub fn acquire_batch(&self, n: u32) -> PermitBatch<'_> {
+        // count permits as they're acquired so a partial batch is still released
+        let mut batch = PermitBatch {
+            base: &self.base,
+            n: 0,
+        };
+        for _ in 0..n {
+            self.acquire_impl();
+            batch.n += 1;
+        }
+        batch
+    }
+
+    /// Wait for `n` permits to be available and acquire them all at once.
+    ///
+    /// Useful for tasks that need a weighted share of the resources. Releases
+    /// on a [Semaphore] only wake up waiters when no permit was left, so the
+    /// caller polls with a backoff instead of sleeping until woken up. Prefer
+    /// [AcquireAllSemaphore::acquire_many] when this is the common case.
+    ///
+    /// Panics if `n` is greater than [Semaphore::max], which could never be
+    /// satisfied.
+    #[must_use]
+    pub fn acquire_many(&self, n: u32) -> PermitGuardMany<'_> {
+        assert!(
+            n <= self.max(),
+            "cannot acquire more permits than the semaphore holds"
+        );
+        match &self.queue {
+            Some(queue) => {
+                queue.enter();
+                retry_until_success(|| self.base.try_acquire_many(n));
+                queue.leave();
+            }
+            None => retry_until_success(|| self.base.try_acquire_many(n)),
+        }
+        PermitGuardMany {
+            base: &self.base,
+            n,
+        }
+    }
+
+    /// Run `f` while holding `n` permits, see [Semaphore::acquire_many].
+    ///
+    /// The permits are released when `f` returns or panics.
+    pub fn scope_many<R>(&self, n: u32, f: impl FnOnce() -> R) -> R {
+        let _permits = self.acquire_many(n);
+        f()
+    }
+
     /// Try to acquire a permit without blocking.
     #[must_use]
     pub fn try_acquire(&self) -> Option<PermitGuard<'_, false>> {
-        if self.base.try_acquire() {
+        if self.try_impl(|| self.base.try_acquire()) {
+            Some(PermitGuard { base: &self.base })
+        } else {
+            None
+        }
+    }
+
+    /// Try to acquire `n` permits at once without blocking.
+    ///
+    /// Returns `None` if fewer than `n` permits are available.
+    #[must_use]
+    pub fn try_acquire_many(&self, n: u32) -> Option<PermitGuardMany<'_>> {
+        if self.try_impl(|| self.base.try_acquire_many(n)) {
+            Some(PermitGuardMany {
+                base: &self.base,
+                n,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Acquire a permit, blocking for at most `timeout`.
+    ///
+    /// Returns `None` if no permit became available in time.
+    ///
+    /// NOTE: on a fair semaphore, the caller doesn't wait in line but only gets
+    /// a permit when nobody else is waiting.
+    #[must_use]
+    pub fn acquire_timeout(&self, timeout: Duration) -> Option<PermitGuard<'_, false>> {
+        let acquired = match self.queue {
+            Some(_) => retry_with_backoff(timeout, || self.try_impl(|| self.base.try_acquire())),
+            None => self.base.acquire_timeout(timeout),
+        };
+        if acquired {
+            Some(PermitGuard { base: &self.base })
+        } else {
+            None
+        }
+    }
+
+    /// Acquire a permit, blocking until one is available or `token` is cancelled.
+    ///
+    /// Returns `None` if the token is (or gets) cancelled, so that a Ctrl-C
+    /// doesn't leave the caller waiting for a permit that's no longer needed.
+    ///
+    /// NOTE: like [Semaphore::acquire_timeout], on a fair semaphore the caller
+    /// doesn't wait in line but only gets a permit when nobody else is waiting.
+    #[must_use]
+    pub fn acquire_cancellable(&self, token: &CancellationToken) -> Option<PermitGuard<'_, false>> {
+        let acquired = match self.queue {
+            Some(_) => retry_until_cancelled(token, || self.try_impl(|| self.base.try_acquire())),
+            None => retry_until_cancelled(token, || self.base.try_acquire()),
+        };
+        if acquired {
             Some(PermitGuard { base: &self.base })
         } else {
             None
@@ -130,7 +449,7 @@ impl Semaphore {
     ///
     /// Failing to do so may lead to deadlocks as acquired permits don't get released.
     pub fn unguarded_acquire(&self) {
-        self.base.acquire();
+        self.acquire_impl();
     }
 
     /// Undo the effect of [Semaphore::unguarded_acquire].
@@ -142,12 +461,24 @@ impl Semaphore {
 impl fmt::Debug for Semaphore {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Semaphore")
-            .field("max", &self.max)
+            .field("max", &self.max())
+            .field("fair", &self.queue.is_some())
             .field("available", &self.base.a.load(Ordering::Relaxed))
             .finish()
     }
 }
 
+/// Permit utilization of a [Semaphore], as returned by [Semaphore::snapshot].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SemaphoreStats {
+    /// The maximum number of permits the semaphore holds.
+    pub max: u32,
+    /// The number of permits currently available.
+    pub available: u32,
+    /// The number of permits currently acquired.
+    pub in_use: u32,
+}
+
 /// A counting semaphore that allows a thread to try to acquire all permits at once.
 ///
 /// This has a bit more overhead than [Semaphore], because it has to notify all waiting
@@ -163,11 +494,16 @@ impl AcquireAllSemaphore {
         }
     }
 
-    /// Get the number of available permits the semaphore started with.
+    /// Get the maximum number of permits the semaphore holds.
     pub fn max(&self) -> u32 {
         self.inner.max()
     }
 
+    /// Grow the capacity of the semaphore by `n` permits.
+    pub fn add_permits(&self, n: u32) {
+        self.inner.add_permits_impl(n, true);
+    }
+
     /// Acquire a permit, blocking until one is available.
     #[must_use]
     pub fn acquire(&self) -> PermitGuard<'_, true> {
@@ -209,21 +545,63 @@ impl AcquireAllSemaphore {
     /// ```
     #[must_use]
     pub fn acquire_all(&self) -> PermitGuardAll<'_> {
-        self.inner.base.acquire_many(self.inner.max);
-        PermitGuardAll { semaphore: self }
+        // the guard releases what was acquired even if the capacity grows meanwhile
+        let n = self.inner.max();
+        self.inner.base.acquire_many(n);
+        PermitGuardAll {
+            base: &self.inner.base,
+            n,
+        }
+    }
+
+    /// Wait for `n` permits to be available and acquire them all at once.
+    ///
+    /// Useful for tasks that need a weighted share of the resources. Unlike
+    /// [Semaphore::acquire_many], the caller sleeps until woken up, since every
+    /// release here notifies all waiters.
+    #[must_use]
+    pub fn acquire_many(&self, n: u32) -> PermitGuardMany<'_> {
+        debug_assert!(
+            n <= self.inner.max(),
+            "cannot acquire more permits than the semaphore holds"
+        );
+        self.inner.base.acquire_many(n);
+        PermitGuardMany {
+            base: &self.inner.base,
+            n,
+        }
+    }
+
+    /// Run `f` while holding `n` permits, see [AcquireAllSemaphore::acquire_many].
+    ///
+    /// The permits are released when `f` returns or panics.
+    pub fn scope_many<R>(&self, n: u32, f: impl FnOnce() -> R) -> R {
+        let _permits = self.acquire_many(n);
+        f()
+    }
+
+    /// Try to acquire `n` permits at once without blocking.
+    ///
+    /// Returns `None` if fewer than `n` permits are available.
+    #[must_use]
+    pub fn try_acquire_many(&self, n: u32) -> Option<PermitGuardMany<'_>> {
+        self.inner.try_acquire_many(n)
     }
 
     /// Like [Semaphore::acquire_all], but caller must ensure that
     /// [Semaphore::unguarded_release_all] is called.
     ///
     /// Failing to do so may lead to deadlocks as acquired permits don't get released.
+    ///
+    /// NOTE: [Semaphore::add_permits] must not be called in between, otherwise
+    /// more permits are released than were acquired. Prefer [Self::acquire_all].
     pub fn unguarded_acquire_all(&self) {
-        self.inner.base.acquire_many(self.inner.max);
+        self.inner.base.acquire_many(self.inner.max());
     }
 
     /// Undo the effect of [Semaphore::unguarded_acquire_all].
     pub fn unguarded_release_all(&self) {
-        self.inner.base.release(self.inner.max, true);
+        self.inner.base.release(self.inner.max(), true);
     }
 }
 
@@ -232,20 +610,90 @@ pub struct PermitGuard<'a, const FORCE_WAKE: bool> {
     base: &'a AtomicSemaphoreBase,
 }
 
+impl<const FORCE_WAKE: bool> PermitGuard<'_, FORCE_WAKE> {
+    /// Keep the permit acquired after the guard goes away.
+    ///
+    /// Useful to hand the permit over to code the guard can't travel to
+    /// (e.g. across an FFI callback). The permit must then be released with
+    /// `unguarded_release`, otherwise it's leaked and waiters may deadlock.
+    pub fn forget(self) {
+        std::mem::forget(self);
+    }
+}
+
 impl<const FORCE_WAKE: bool> Drop for PermitGuard<'_, FORCE_WAKE> {
     fn drop(&mut self) {
         self.base.release(1, FORCE_WAKE)
     }
 }
 
+/// A guard that releases the `n` permits acquired with `acquire_many` or
+/// `try_acquire_many` when dropped.
+pub struct PermitGuardMany<'a> {
+    base: &'a AtomicSemaphoreBase,
+    n: u32,
+}
+
+impl PermitGuardMany<'_> {
+    /// Get the number of permits held by this guard.
+    pub fn count(&self) -> u32 {
+        self.n
+    }
+}
+
+impl Drop for PermitGuardMany<'_> {
+    fn drop(&mut self) {
+        self.base.release(self.n, true)
+    }
+}
+
+/// A guard that releases the permits acquired with [Semaphore::acquire_batch]
+/// at once when dropped.
+pub struct PermitBatch<'a> {
+    base: &'a AtomicSemaphoreBase,
+    n: u32,
+}
+
+impl PermitBatch<'_> {
+    /// Get the number of permits held by this batch.
+    pub fn count(&self) -> u32 {
+        self.n
+    }
+}
+
+impl Drop for PermitBatch<'_> {
+    fn drop(&mut self) {
+        self.base.release(self.n, false)
+    }
+}
+
 /// A guard that releases all permits when dropped.
 pub struct PermitGuardAll<'a> {
-    semaphore: &'a AcquireAllSemaphore,
+    base: &'a AtomicSemaphoreBase,
+    /// The number of permits acquired (the capacity at the time).
+    n: u32,
+}
+
+impl PermitGuardAll<'_> {
+    /// Get the number of permits held by this guard: the semaphore's
+    /// [max](AcquireAllSemaphore::max) when they were acquired.
+    pub fn count(&self) -> u32 {
+        self.n
+    }
+
+    /// Keep all the permits acquired after the guard goes away.
+    ///
+    /// The permits must then be released with
+    /// [AcquireAllSemaphore::unguarded_release_all], otherwise they're leaked
+    /// and every other user of the semaphore deadlocks.
+    pub fn forget(self) {
+        std::mem::forget(self);
+    }
 }
 
 impl Drop for PermitGuardAll<'_> {
     fn drop(&mut self) {
-        self.semaphore.unguarded_release_all()
+        self.base.release(self.n, true)
     }
 }
 
@@ -270,6 +718,91 @@ mod tests {
         assert!(semaphore.try_acquire().is_none());
     }
 
+    #[test]
+    fn test_semaphore_available_permits() {
+        let semaphore = Semaphore::new(2);
+        assert_eq!(semaphore.available_permits(), 2);
+
+        let permit = semaphore.acquire();
+        assert_eq!(semaphore.available_permits(), 1);
+
+        drop(permit);
+        assert_eq!(semaphore.available_permits(), 2);
+    }
+
+    #[test]
+    fn test_semaphore_snapshot() {
+        let semaphore = Semaphore::new(3);
+        let stats = semaphore.snapshot();
+        assert_eq!(stats.in_use + stats.available, stats.max);
+        assert_eq!(
+            stats,
+            SemaphoreStats {
+                max: 3,
+                available: 3,
+                in_use: 0,
+            }
+        );
+
+        let _permit = semaphore.acquire();
+        let stats = semaphore.snapshot();
+        assert_eq!(stats.in_use + stats.available, stats.max);
+        assert_eq!(stats.in_use, 1);
+        assert_eq!(stats.available, 2);
+    }
+
+    #[test]
+    fn test_semaphore_acquire_timeout() {
+        let semaphore = Arc::new(Semaphore::new(1));
+        let permit = semaphore.acquire();
+
+        let start = Instant::now();
+        assert!(
+            semaphore
+                .acquire_timeout(Duration::from_millis(50))
+                .is_none()
+        );
+        assert!(start.elapsed() >= Duration::from_millis(50));
+
+        let sem = semaphore.clone();
+        let handle = thread::spawn(move || sem.acquire_timeout(Duration::from_secs(5)).is_some());
+        thread::sleep(Duration::from_millis(50));
+        drop(permit);
+        assert!(handle.join().unwrap());
+        assert_eq!(semaphore.available_permits(), 1);
+    }
+
+    #[test]
+    fn test_semaphore_acquire_cancellable() {
+        use dbt_cancel::CancellationTokenSource;
+
+        let semaphore = Arc::new(Semaphore::new(1));
+        let source = CancellationTokenSource::new();
+        let permit = semaphore.acquire_cancellable(&source.token()).unwrap();
+
+        let sem = semaphore.clone();
+        let token = source.token();
+        let cancelled = token.clone();
+        let handle = thread::spawn(move || sem.acquire_cancellable(&token).is_none());
+        thread::sleep(Duration::from_millis(50));
+        source.cancel();
+        assert!(
+            handle.join().unwrap(),
+            "cancelled waiter must not get a permit"
+        );
+
+        // a cancelled token gives up even if a permit is available
+        drop(permit);
+        assert!(semaphore.acquire_cancellable(&cancelled).is_none());
+        assert_eq!(semaphore.available_permits(), 1);
+
+        let fair = Semaphore::new_fair(1);
+        assert!(
+            fair.acquire_cancellable(&CancellationToken::never_cancels())
+                .is_some()
+        );
+    }
+
     #[test]
     fn test_semaphore_release_more_than_initial() {
         let semaphore = Semaphore::new(1);
@@ -352,6 +885,290 @@ mod tests {
         assert!(max_result <= 2);
     }
 
+    #[test]
+    fn test_semaphore_acquire_many() {
+        const MAX: u32 = 8;
+        let semaphore = Arc::new(AcquireAllSemaphore::new(MAX));
+        let in_use = Arc::new(AtomicU32::new(0));
+
+        let handles = (1..=6)
+            .map(|weight| {
+                let sem = semaphore.clone();
+                let in_use = in_use.clone();
+                thread::spawn(move || {
+                    for _ in 0..5 {
+                        let permits = sem.acquire_many(weight);
+                        let n = in_use.fetch_add(weight, Ordering::SeqCst) + weight;
+                        assert!(n <= MAX, "{n} permits in use");
+                        thread::sleep(Duration::from_millis(1));
+                        in_use.fetch_sub(weight, Ordering::SeqCst);
+                        drop(permits);
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(semaphore.inner.available_permits(), MAX);
+    }
+
+    #[test]
+    fn test_semaphore_acquire_many_plain() {
+        const MAX: u32 = 8;
+        for semaphore in [Semaphore::new(MAX), Semaphore::new_fair(MAX)] {
+            let semaphore = Arc::new(semaphore);
+            let in_use = Arc::new(AtomicU32::new(0));
+
+            let handles = (1..=6)
+                .map(|weight| {
+                    let sem = semaphore.clone();
+                    let in_use = in_use.clone();
+                    thread::spawn(move || {
+                        for _ in 0..5 {
+                            let permits = sem.acquire_many(weight);
+                            let n = in_use.fetch_add(weight, Ordering::SeqCst) + weight;
+                            assert!(n <= MAX, "{n} permits in use");
+                            thread::sleep(Duration::from_millis(1));
+                            in_use.fetch_sub(weight, Ordering::SeqCst);
+                            drop(permits);
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+            for handle in handles {
+                handle.join().unwrap();
+            }
+            assert_eq!(semaphore.available_permits(), MAX);
+        }
+    }
+
+    #[test]
+    fn test_semaphore_acquire_many_woken_by_single_releases() {
+        let semaphore = Arc::new(Semaphore::new(3));
+        let held = [
+            semaphore.acquire(),
+            semaphore.acquire(),
+            semaphore.acquire(),
+        ];
+        let waiter = {
+            let sem = semaphore.clone();
+            thread::spawn(move || sem.acquire_many(2).count())
+        };
+        // only the first release finds no permit left and wakes anyone up
+        for permit in held {
+            thread::sleep(Duration::from_millis(5));
+            drop(permit);
+        }
+        assert_eq!(waiter.join().unwrap(), 2);
+        assert_eq!(semaphore.available_permits(), 3);
+    }
+
+    #[test]
+    fn test_semaphore_scope() {
+        use std::panic::{AssertUnwindSafe, catch_unwind};
+
+        let semaphore = Semaphore::new(2);
+        let n = semaphore.scope(|| {
+            assert_eq!(semaphore.available_permits(), 1);
+            42
+        });
+        assert_eq!(n, 42);
+        assert_eq!(semaphore.available_permits(), 2);
+
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            semaphore.scope(|| panic!("query failed"));
+        }));
+        assert!(result.is_err());
+        assert_eq!(semaphore.available_permits(), 2);
+
+        let semaphore = Semaphore::new(4);
+        semaphore.scope_many(3, || {
+            assert_eq!(semaphore.available_permits(), 1);
+        });
+        assert_eq!(semaphore.available_permits(), 4);
+
+        let semaphore = AcquireAllSemaphore::new(4);
+        semaphore.scope_many(3, || {
+            assert_eq!(semaphore.inner.available_permits(), 1);
+        });
+        assert_eq!(semaphore.inner.available_permits(), 4);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            semaphore.scope_many(4, || panic!("query failed"));
+        }));
+        assert!(result.is_err());
+        assert_eq!(semaphore.inner.available_permits(), 4);
+    }
+
+    #[test]
+    fn test_semaphore_try_acquire_many() {
+        let semaphore = Semaphore::new(4);
+        let permit = semaphore.acquire();
+
+        assert!(semaphore.try_acquire_many(4).is_none());
+        assert_eq!(semaphore.available_permits(), 3);
+
+        let permits = semaphore.try_acquire_many(3).unwrap();
+        assert_eq!(semaphore.available_permits(), 0);
+        assert!(semaphore.try_acquire_many(1).is_none());
+
+        drop(permits);
+        drop(permit);
+        assert_eq!(semaphore.available_permits(), 4);
+        assert!(semaphore.try_acquire_many(4).is_some());
+    }
+
+    #[test]
+    fn test_permit_guard_count() {
+        let semaphore = AcquireAllSemaphore::new(4);
+        let permits = semaphore.acquire_many(3);
+        assert_eq!(permits.count(), 3);
+        assert_eq!(semaphore.try_acquire_many(1).unwrap().count(), 1);
+        drop(permits);
+
+        let all = semaphore.acquire_all();
+        assert_eq!(all.count(), 4);
+        drop(all);
+
+        // the guard keeps the count it acquired even if the capacity grows
+        let all = semaphore.acquire_all();
+        semaphore.add_permits(2);
+        assert_eq!(all.count(), 4);
+        assert_eq!(semaphore.max(), 6);
+        drop(all);
+        assert_eq!(semaphore.inner.available_permits(), 6);
+    }
+
+    #[test]
+    fn test_semaphore_acquire_batch() {
+        let semaphore = Semaphore::new(4);
+        let batch = semaphore.acquire_batch(3);
+        assert_eq!(batch.count(), 3);
+        assert_eq!(semaphore.available_permits(), 1);
+        let _permit = semaphore.try_acquire().unwrap();
+        assert!(semaphore.try_acquire().is_none());
+
+        drop(batch);
+        assert_eq!(semaphore.available_permits(), 3);
+        assert_eq!(semaphore.acquire_batch(3).count(), 3);
+        assert_eq!(semaphore.available_permits(), 3);
+    }
+
+    #[test]
+    fn test_semaphore_add_permits() {
+        let semaphore = Arc::new(Semaphore::new(1));
+        let permit = semaphore.acquire();
+
+        let (tx, rx) = channel();
+        let sem = semaphore.clone();
+        let handle = thread::spawn(move || {
+            let _permit = sem.acquire();
+            tx.send(()).unwrap();
+        });
+        // the waiter stays blocked while the only permit is held
+        assert!(rx.recv_timeout(Duration::from_millis(50)).is_err());
+
+        semaphore.add_permits(1);
+        assert_eq!(semaphore.max(), 2);
+        rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        handle.join().unwrap();
+
+        drop(permit);
+        assert_eq!(semaphore.available_permits(), 2);
+    }
+
+    #[test]
+    fn test_acquire_all_after_add_permits() {
+        let semaphore = AcquireAllSemaphore::new(2);
+        semaphore.add_permits(2);
+        assert_eq!(semaphore.max(), 4);
+
+        let permits = semaphore.acquire_all();
+        assert_eq!(semaphore.inner.available_permits(), 0);
+        drop(permits);
+        assert_eq!(semaphore.inner.available_permits(), 4);
+    }
+
+    #[test]
+    fn test_fair_semaphore_grants_in_arrival_order() {
+        const THREADS: usize = 6;
+        const ROUNDS: usize = 20;
+        let semaphore = Arc::new(Semaphore::new_fair(1));
+        let grants = Arc::new(std::sync::Mutex::new(Vec::new()));
+
+        let handles = (0..THREADS)
+            .map(|id| {
+                let sem = semaphore.clone();
+                let grants = grants.clone();
+                thread::spawn(move || {
+                    for _ in 0..ROUNDS {
+                        let permit = sem.acquire();
+                        grants.lock().unwrap().push(id);
+                        thread::sleep(Duration::from_micros(200));
+                        drop(permit);
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        // A thread that releases the permit goes to the back of the line, so
+        // at most all the other threads get a turn before it gets the next one.
+        let grants = grants.lock().unwrap();
+        assert_eq!(grants.len(), THREADS * ROUNDS);
+        for id in 0..THREADS {
+            let turns = grants
+                .iter()
+                .enumerate()
+                .filter(|(_, g)| **g == id)
+                .map(|(i, _)| i)
+                .collect::<Vec<_>>();
+            for pair in turns.windows(2) {
+                let others = pair[1] - pair[0] - 1;
+                assert!(others < THREADS, "thread {id} waited for {others} turns");
+            }
+        }
+        assert_eq!(semaphore.available_permits(), 1);
+    }
+
+    #[test]
+    fn test_fair_semaphore_try_acquire() {
+        let semaphore = Semaphore::new_fair(2);
+        let permit = semaphore.try_acquire().unwrap();
+        let permits = semaphore.try_acquire_many(1).unwrap();
+        assert!(semaphore.try_acquire().is_none());
+        drop(permit);
+        drop(permits);
+        assert!(
+            semaphore
+                .acquire_timeout(Duration::from_millis(10))
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_permit_guard_forget() {
+        let semaphore = Semaphore::new(2);
+        semaphore.acquire().forget();
+        assert_eq!(semaphore.available_permits(), 1);
+
+        let permit = semaphore.acquire();
+        assert!(semaphore.try_acquire().is_none());
+        drop(permit);
+        // the forgotten permit stays taken until released by hand
+        assert_eq!(semaphore.available_permits(), 1);
+        semaphore.unguarded_release();
+        assert_eq!(semaphore.available_permits(), 2);
+
+        let semaphore = AcquireAllSemaphore::new(3);
+        semaphore.acquire_all().forget();
+        assert!(semaphore.try_acquire().is_none());
+        semaphore.unguarded_release_all();
+        assert_eq!(semaphore.inner.available_permits(), 3);
+    }
+
     #[test]
     fn test_semaphore_wait_signals() {
         let main: Arc<Semaphore> = Arc::new(Semaphore::new(1));
@@ -382,4 +1199,11 @@ mod tests {
     fn test_semaphore_zero_permits() {
         let _ = Semaphore::new(0);
     }
+
+    #[test]
+    #[should_panic(expected = "cannot acquire more permits than the semaphore holds")]
+    fn test_semaphore_acquire_many_more_than_max() {
+        let semaphore = Semaphore::new(2);
+        let _ = semaphore.acquire_many(3);
+    }
 }
@@ -1,5 +1,7 @@
 use core::fmt;
 
+use serde::{Deserialize, Serialize};
+
 use crate::Backend;
 
 use super::is_keyword_ignore_ascii_case;
@@ -23,7 +25,8 @@ use super::tokenizer::QuotingStyle;
 /// as `Plain("MyTable")`. This is important for dialects like Snowflake
 /// where unquoted identifiers are normalized to uppercase, so the quotes
 /// are necessary to preserve the original casing.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value")]
 pub enum Ident {
     /// Identifier that was not quoted in the input source.
     Plain(String),
@@ -125,6 +128,11 @@ pub const fn quote_char(backend: Backend) -> char {
         BigQuery | Databricks | DatabricksODBC => '`',
         Snowflake => '"',
         Redshift | RedshiftODBC | Postgres | Salesforce => '"',
+        // SQL Server also accepts [brackets], but double quotes are
+        // standard when QUOTED_IDENTIFIER is ON (the default)
+        MSSQL => '"',
+        // ClickHouse accepts both backticks and double quotes
+        ClickHouse => '`',
         Generic { .. } => '"',
     }
 }
@@ -135,6 +143,8 @@ pub const fn canonical_quote(backend: Backend) -> QuotingStyle {
     match backend {
         BigQuery | Databricks | DatabricksODBC => QuotingStyle::Backtick,
         Snowflake | Redshift | RedshiftODBC | Postgres | Salesforce => QuotingStyle::Double,
+        MSSQL => QuotingStyle::Double,
+        ClickHouse => QuotingStyle::Backtick,
         Generic { .. } => QuotingStyle::Double,
     }
 }
@@ -158,7 +168,9 @@ pub fn is_valid_ident_char(c: char, backend: Backend) -> bool {
         | Redshift
         | RedshiftODBC
         | Salesforce
+        | ClickHouse
         | Generic { .. } => c.is_alphanumeric() || c == '_',
+        MSSQL => c.is_alphanumeric() || ['_', '@', '#', '$'].contains(&c),
     }
 }
 
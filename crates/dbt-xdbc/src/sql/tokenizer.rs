@@ -1,5 +1,7 @@
 use core::fmt;
 
+use serde::{Deserialize, Serialize};
+
 #[derive(Debug)]
 pub enum Token<'source> {
     LParen,
@@ -58,7 +60,7 @@ impl fmt::Display for Token<'_> {
 }
 
 /// The type of quote used for quoted identifiers or string literals.
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
 pub enum QuotingStyle {
     /// Single quote: '.
     Single,
@@ -68,6 +70,8 @@ pub enum QuotingStyle {
     Backtick,
     /// U&"..." style quoted identifier like in PostgreSQL.
     UAndDouble,
+    /// Square brackets: [...] like in SQL Server.
+    Bracket,
 }
 
 impl QuotingStyle {
@@ -77,6 +81,7 @@ impl QuotingStyle {
             QuotingStyle::Double => "\"",
             QuotingStyle::Backtick => "`",
             QuotingStyle::UAndDouble => "U&\"",
+            QuotingStyle::Bracket => "[",
         }
     }
 
@@ -86,6 +91,7 @@ impl QuotingStyle {
             QuotingStyle::Double => "\"",
             QuotingStyle::Backtick => "`",
             QuotingStyle::UAndDouble => "\"",
+            QuotingStyle::Bracket => "]",
         }
     }
 }
@@ -99,6 +105,7 @@ impl TryFrom<u8> for QuotingStyle {
             b'\'' => Ok(QuotingStyle::Single),
             b'"' => Ok(QuotingStyle::Double),
             b'`' => Ok(QuotingStyle::Backtick),
+            b'[' => Ok(QuotingStyle::Bracket),
             _ => Err(()),
         }
     }
@@ -118,6 +125,16 @@ impl<'source> Tokenizer<'source> {
         Tokenizer { input, position: 0 }
     }
 
+    /// Byte offset of the next unconsumed character in the input.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The source text between two positions returned by [Tokenizer::position].
+    pub fn source(&self, start: usize, end: usize) -> &'source str {
+        &self.input[start..end]
+    }
+
     /// Looks at the current byte without consuming it.
     fn _peek_byte(&self) -> Option<u8> {
         let input = self.input.as_bytes();
@@ -180,6 +197,11 @@ impl<'source> Tokenizer<'source> {
             match b {
                 b'(' | b')' | b'[' | b']' | b'<' | b'>' => break,
                 b',' | b':' => break,
+                b'"' if self.input[start..self.position].eq_ignore_ascii_case("U&") => {
+                    // U&"..." quoted identifier like in PostgreSQL
+                    self.position += 1;
+                    return Some(self.rest_of_quoted_word(start, b));
+                }
                 b'\'' | b'"' | b'`' => break,
                 _ if is_whitespace(b) => break,
                 _ => {
@@ -221,6 +243,21 @@ impl<'source> Tokenizer<'source> {
         Some(token)
     }
 
+    /// Consumes a `[...]` quoted identifier (like in SQL Server) if it is the next
+    /// token. A `]]` inside the brackets escapes a `]`.
+    ///
+    /// Brackets are delimiters in other dialects (e.g. `INT[]` in PostgreSQL), so
+    /// [Tokenizer::next] never produces these and parsers have to opt into them.
+    pub fn next_bracket_quoted(&mut self) -> Option<Token<'source>> {
+        self.skip_whitespace();
+        let start = self.position;
+        if self._peek_byte() != Some(b'[') {
+            return None;
+        }
+        self.position += 1;
+        Some(self.rest_of_quoted_word(start, b']'))
+    }
+
     /// Consumes the next token if and only if it matches the provided token.
     pub fn match_(&mut self, pred: impl FnOnce(Token<'source>) -> bool) -> bool {
         let old_pos = self.position;
@@ -233,6 +270,15 @@ impl<'source> Tokenizer<'source> {
         false
     }
 
+    /// Returns the next two tokens without consuming them.
+    pub fn peek2(&mut self) -> (Option<Token<'source>>, Option<Token<'source>>) {
+        let old_pos = self.position;
+        let first = self.next();
+        let second = self.next();
+        self.position = old_pos;
+        (first, second)
+    }
+
     /// Peeks at the next token and applies the provided function to it. If the function
     /// returns `None`, the tokenizer's position is reset to its previous state.
     pub fn peek_and_then<T>(&mut self, f: impl FnOnce(Token<'source>) -> Option<T>) -> Option<T> {
@@ -304,6 +350,26 @@ mod tests {
         assert_eq!(tokenizer.next(), Some(Token::Word("S☃NOWMA☃N")));
     }
 
+    #[test]
+    fn test_peek2() {
+        let mut tokenizer = Tokenizer::new("DOUBLE PRECISION, x");
+        assert_eq!(
+            tokenizer.peek2(),
+            (Some(Token::Word("DOUBLE")), Some(Token::Word("PRECISION")))
+        );
+        // nothing was consumed
+        assert_eq!(tokenizer.next(), Some(Token::Word("DOUBLE")));
+        assert_eq!(
+            tokenizer.peek2(),
+            (Some(Token::Word("PRECISION")), Some(Token::Comma))
+        );
+        tokenizer.next();
+        tokenizer.next();
+        assert_eq!(tokenizer.peek2(), (Some(Token::Word("x")), None));
+        tokenizer.next();
+        assert_eq!(tokenizer.peek2(), (None, None));
+    }
+
     fn all_tokens<'source>(input: &'source str) -> Vec<Token<'source>> {
         let mut tokenizer = Tokenizer::new(input);
         let mut tokens = Vec::new();
@@ -409,6 +475,30 @@ mod tests {
                     Token::Colon,
                 ],
             ),
+            // U&"..." quoted identifiers
+            (
+                line!(),
+                r#"U&"d\0061t" u&"a""b" U&x"#,
+                vec![
+                    Token::Word(r#"U&"d\0061t""#),
+                    Token::Word(r#"u&"a""b""#),
+                    Token::Word("U&x"),
+                ],
+            ),
+            (
+                line!(),
+                r#"U&"d!0061t" UESCAPE '!'"#,
+                vec![
+                    Token::Word(r#"U&"d!0061t""#),
+                    Token::Word("UESCAPE"),
+                    Token::Word("'!'"),
+                ],
+            ),
+            (
+                line!(),
+                r#"xU&"a""#,
+                vec![Token::Word("xU&"), Token::Word(r#""a""#)],
+            ),
             (
                 line!(),
                 "(a REAL)",
@@ -430,4 +520,29 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_tokenizing_of_bracket_quoted_words() {
+        let mut tokenizer = Tokenizer::new(" [a]]b] INT");
+        assert_eq!(tokenizer.next_bracket_quoted(), Some(Token::Word("[a]]b]")));
+        assert_eq!(tokenizer.next(), Some(Token::Word("INT")));
+
+        let mut tokenizer = Tokenizer::new("[my col],");
+        assert_eq!(
+            tokenizer.next_bracket_quoted(),
+            Some(Token::Word("[my col]"))
+        );
+        assert_eq!(tokenizer.next(), Some(Token::Comma));
+
+        // unclosed brackets consume the rest of the input
+        let mut tokenizer = Tokenizer::new("[a]]");
+        assert_eq!(tokenizer.next_bracket_quoted(), Some(Token::Word("[a]]")));
+        assert_eq!(tokenizer.next(), None);
+
+        // nothing is consumed if the next token is not bracket-quoted
+        let mut tokenizer = Tokenizer::new("a[]");
+        assert_eq!(tokenizer.next_bracket_quoted(), None);
+        assert_eq!(tokenizer.next(), Some(Token::Word("a")));
+        assert_eq!(tokenizer.next(), Some(Token::LBracket));
+    }
 }
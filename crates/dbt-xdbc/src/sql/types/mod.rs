@@ -4,17 +4,18 @@ use std::fmt;
 use std::num::ParseIntError;
 use std::sync::Arc;
 
-use arrow_schema::{DataType, Field, Fields, IntervalUnit, TimeUnit};
+use arrow_schema::{DataType, Field, Fields, IntervalUnit, Schema, TimeUnit};
+use serde::{Deserialize, Serialize};
 
 use crate::Backend;
 
 use super::ident::Ident;
-use super::tokenizer::{Token, Tokenizer};
+use super::tokenizer::{QuotingStyle, Token, Tokenizer};
 
 #[cfg(test)]
 mod tests;
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, Serialize, Deserialize)]
 pub enum DateTimeField {
     Year,
     Month,
@@ -52,26 +53,25 @@ impl DateTimeField {
         // In PostgreSQL, the sub-second fields are expressed as
         // `SECOND` followed by a precision, e.g. `SECOND(3)`.
         if matches!(backend, Postgres | Redshift | RedshiftODBC) {
-            match self {
-                Millisecond => {
-                    out.push_str("SECOND(3)");
-                    Ok(())
-                }
-                Microsecond => {
-                    out.push_str("SECOND(6)");
-                    Ok(())
-                }
-                Nanosecond => {
-                    out.push_str("SECOND(9)");
-                    Ok(())
-                }
-                _ => write!(out, "{self}"),
-            }
+            self.write_as_second_precision(out)
         } else {
             write!(out, "{self}")
         }
     }
 
+    /// Write the sub-second fields the way the SQL standard does, as `SECOND`
+    /// followed by a precision.
+    fn write_as_second_precision(&self, out: &mut String) -> fmt::Result {
+        use DateTimeField::*;
+        use fmt::Write as _;
+        match self {
+            Millisecond => write!(out, "SECOND(3)"),
+            Microsecond => write!(out, "SECOND(6)"),
+            Nanosecond => write!(out, "SECOND(9)"),
+            _ => write!(out, "{self}"),
+        }
+    }
+
     fn from_precision(p: u8) -> Self {
         use DateTimeField::*;
         match p {
@@ -84,7 +84,8 @@ impl DateTimeField {
     }
 }
 
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "value")]
 pub enum TimeZoneSpec {
     /// WITH LOCAL TIME ZONE, TIMESTAMP_LTZ
     Local,
@@ -94,6 +95,10 @@ pub enum TimeZoneSpec {
     Without,
     // no specification (e.g. TIMESTAMP)
     Unspecified,
+    /// A fixed offset from UTC in minutes, e.g. `TIMESTAMP(3) +05:00`
+    Offset(i16),
+    /// A named time zone, e.g. ClickHouse `DateTime64(3, 'Asia/Tokyo')`
+    Named(String),
 }
 
 impl TimeZoneSpec {
@@ -114,8 +119,12 @@ impl TimeZoneSpec {
             // PostgreSQL TIMESTAMP WITHOUT TIME ZONE can be rendered as TIMESTAMP
             (Postgres | Redshift | RedshiftODBC, Without) => Ok(()),
 
+            // Only generic backends are assumed to accept a fixed offset in the
+            // type, others fall back to the closest time zone spec they have
+            (Generic { .. }, Offset(minutes)) => write!(out, " {}", format_utc_offset(*minutes)),
+
             (_, Local) => write!(out, " WITH LOCAL TIME ZONE"),
-            (_, With) => write!(out, " WITH TIME ZONE"),
+            (_, With | Offset(_) | Named(_)) => write!(out, " WITH TIME ZONE"),
             (_, Without) => write!(out, " WITHOUT TIME ZONE"),
 
             (_, Unspecified) => Ok(()),
@@ -138,7 +147,10 @@ impl TimeZoneSpec {
 
             // TIMETZ and TIMESTAMPTZ in PostgreSQL which doesn't have
             // a type that is specifically for local time zone.
-            (Postgres | Redshift | RedshiftODBC | Salesforce, Local | With) => {
+            (
+                Postgres | Redshift | RedshiftODBC | Salesforce,
+                Local | With | Offset(_) | Named(_),
+            ) => {
                 debug_assert!(
                     !matches!(self, Local),
                     "PostgreSQL does not have a TIMESTAMP WITH LOCAL TIME ZONE type"
@@ -152,10 +164,10 @@ impl TimeZoneSpec {
             // (TIMESTAMP or TIMESTAMP_LTZ) and WITHOUT TIME ZONE (TIMESTAMP_NTZ).
             (Databricks | DatabricksODBC, Unspecified) => Ok(()),
             (Databricks | DatabricksODBC, Without) => write!(out, "_NTZ"),
-            (Databricks | DatabricksODBC, With) => Ok(()),
+            (Databricks | DatabricksODBC, With | Offset(_) | Named(_)) => Ok(()),
 
             (_, Local) => write!(out, "_LTZ"),
-            (_, With) => write!(out, "_TZ"),
+            (_, With | Offset(_) | Named(_)) => write!(out, "_TZ"),
             (_, Without) => write!(out, "_NTZ"),
 
             // No suffix for unspecified time zone spec.
@@ -171,12 +183,12 @@ impl TimeZoneSpec {
         }
     }
 
-    pub fn is_with_time_zone(self, backend: Backend) -> bool {
+    pub fn is_with_time_zone(&self, backend: Backend) -> bool {
         use Backend::*;
         use TimeZoneSpec::*;
         match (backend, self) {
             // Databricks TIMESTAMP has WITH LOCAL TIME ZONE semantics by default
-            (Databricks, Unspecified | With | Local) => true,
+            (Databricks, Unspecified | With | Local | Offset(_) | Named(_)) => true,
 
             (Snowflake, Unspecified) => {
                 // Users can run `ALTER SESSION SET TIMESTAMP_TYPE_MAPPING = TIMESTAMP_TZ;`
@@ -184,15 +196,96 @@ impl TimeZoneSpec {
                 debug_assert!(
                     false,
                     "Snowflake TIMESTAMP without a time zone spec is ambiguous. \
-Avoid constructing Snowflake TIME/TIMESTAMP types without an explicit time zone specification."
+Use SqlType::resolve_timezone() to pick a time zone spec before asking about it."
                 );
                 false
             }
 
-            (_, With | Local) => true,
+            (_, With | Local | Offset(_) | Named(_)) => true,
             (_, Without | Unspecified) => false,
         }
     }
+
+    /// Turn [TimeZoneSpec::Unspecified] into a concrete spec.
+    ///
+    /// Backends that give `TIMESTAMP` a fixed meaning resolve to it regardless
+    /// of the policy. The policy only applies when the meaning of a `TIMESTAMP`
+    /// depends on session state (e.g. `TIMESTAMP_TYPE_MAPPING` in Snowflake).
+    fn resolve(&self, backend: Backend, policy: TimestampPolicy) -> Result<TimeZoneSpec, String> {
+        use Backend::*;
+        use TimeZoneSpec::*;
+        match (backend, self) {
+            (_, Local | With | Without | Offset(_) | Named(_)) => Ok(self.clone()),
+
+            // Databricks TIMESTAMP has WITH LOCAL TIME ZONE semantics by default
+            (Databricks | DatabricksODBC, Unspecified) => Ok(Local),
+            (BigQuery | Postgres | Redshift | RedshiftODBC | Salesforce | MSSQL, Unspecified) => {
+                Ok(Without)
+            }
+            // ClickHouse DateTime64 without a time zone is shown in the server time zone
+            (ClickHouse, Unspecified) => Ok(Local),
+
+            (Snowflake | Generic { .. }, Unspecified) => match policy {
+                TimestampPolicy::DefaultToNtz => Ok(Without),
+                TimestampPolicy::DefaultToTz => Ok(With),
+                TimestampPolicy::Error => Err(format!(
+                    "{backend} TIMESTAMP without a time zone spec is ambiguous"
+                )),
+            },
+        }
+    }
+}
+
+/// Format an offset from UTC in minutes as `(+|-)HH:MM`.
+fn format_utc_offset(minutes: i16) -> String {
+    let sign = if minutes < 0 { '-' } else { '+' };
+    let minutes = minutes.unsigned_abs();
+    format!("{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+}
+
+/// What to do with a `TIMESTAMP` that has no time zone spec when the backend
+/// doesn't give it a fixed meaning. See [SqlType::resolve_timezone].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum TimestampPolicy {
+    /// Resolve to WITHOUT TIME ZONE (TIMESTAMP_NTZ).
+    DefaultToNtz,
+    /// Resolve to WITH TIME ZONE (TIMESTAMP_TZ).
+    DefaultToTz,
+    /// Fail instead of guessing.
+    Error,
+}
+
+/// Renderings that some pipelines expect in place of the native type, see
+/// [SqlType::write_compat].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct CompatOptions {
+    /// Render `BOOLEAN` as `SMALLINT`, for tables that store booleans as 0/1.
+    pub boolean_as_smallint: bool,
+}
+
+impl StringAttrs {
+    /// Write the ` CHARACTER SET cs COLLATE collation` suffix of a string type.
+    ///
+    /// The character set comes first, as in MySQL. It's only kept on generic
+    /// backends (e.g. a MySQL ADBC driver) since no other backend supports it.
+    fn write_suffix(&self, backend: Backend, out: &mut String) -> fmt::Result {
+        if let Some(charset) = &self.charset
+            && matches!(backend, Backend::Generic { .. })
+        {
+            write!(out, " CHARACTER SET {charset}")?;
+        }
+        if let Some(collate_spec) = &self.collate_spec {
+            write!(out, " COLLATE {collate_spec}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns true if the backend keeps NCHAR/NVARCHAR as distinct spellings of
+/// character types. Other backends render them as CHAR/VARCHAR.
+fn has_national_char_types(backend: Backend) -> bool {
+    use Backend::*;
+    matches!(backend, Snowflake | MSSQL | Generic { .. })
 }
 
 pub fn default_time_unit(backend: Backend) -> TimeUnit {
@@ -202,6 +295,10 @@ pub fn default_time_unit(backend: Backend) -> TimeUnit {
         Snowflake | Databricks | DatabricksODBC => Nanosecond,
         BigQuery | Redshift | RedshiftODBC => Microsecond,
         Postgres | Salesforce => Microsecond,
+        // DATETIME2 and TIME default to a precision of 7 (100ns)
+        MSSQL => Nanosecond,
+        // DateTime64 has no default precision, but milliseconds are the most common
+        ClickHouse => Millisecond,
         Generic { .. } => Microsecond, // a reasonable default
     }
 }
@@ -235,12 +332,16 @@ pub fn suitable_time_unit(precision: u8) -> TimeUnit {
 }
 
 /// Additional attributes for string types.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct StringAttrs {
+    /// The raw token found after CHARACTER SET (or CHARSET).
+    pub charset: Option<String>,
     pub collate_spec: Option<String>,
+    /// Whether the type was spelled as a national character type (NCHAR, NVARCHAR).
+    pub national: bool,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StructField {
     pub name: Ident,
     pub sql_type: SqlType,
@@ -263,6 +364,30 @@ impl StructField {
         self.comment_tok = Some(comment);
         self
     }
+
+    /// A field of a row whose fields have no names, named after its 1-based
+    /// `position` the way Trino subscripts it (`r[1]`).
+    pub fn positional(position: usize, sql_type: SqlType, nullable: bool) -> Self {
+        Self::new(Ident::plain(position.to_string()), sql_type, nullable)
+    }
+}
+
+/// Whether `second` is the rest of a type name that starts with `first`, like
+/// the `PRECISION` of `DOUBLE PRECISION`, rather than the type of a field named
+/// `first`.
+fn continues_type_name(first: &str, second: &str) -> bool {
+    eqi(first, "INTERVAL")
+        || ["PRECISION", "VARYING", "UNSIGNED", "WITH", "WITHOUT"]
+            .iter()
+            .any(|w| eqi(second, w))
+}
+
+/// Whether `fields` are all [positional](StructField::positional), in order.
+fn are_positional(fields: &[StructField]) -> bool {
+    fields
+        .iter()
+        .enumerate()
+        .all(|(i, field)| matches!(&field.name, Ident::Plain(s) if *s == (i + 1).to_string()))
 }
 
 /// Syntactic representation of SQL types.
@@ -271,7 +396,11 @@ impl StructField {
 /// realized in the context of a specific [SQL backend](`crate::Backend`).
 /// But this enum aims to be a common representation that can be used
 /// across different backends with slight tweaks in the behavior.
-#[derive(Debug, Clone)] // DO NOT derive PartialEq or Eq, use `to_string(backend)` for comparisons!
+///
+/// Types serialize to backend-independent JSON (e.g. to ship them between
+/// processes), unlike their string representation.
+#[derive(Debug, Clone, Serialize, Deserialize)] // DO NOT derive PartialEq or Eq, use `to_string(backend)` for comparisons!
+#[serde(tag = "kind", content = "value")]
 pub enum SqlType {
     /// BOOLEAN
     Boolean,
@@ -283,6 +412,11 @@ pub enum SqlType {
     Integer,
     /// BIGINT
     BigInt,
+    /// UInt8 / UInt16 / UInt32 / UInt64 / <INT> UNSIGNED, with the width in bits
+    ///
+    /// Backends without unsigned integers use the narrowest signed type that
+    /// holds the whole range, see [SqlType::signed_for_unsigned].
+    UnsignedInteger(u8),
     /// REAL
     Real,
     /// FLOAT [ '(' precision ')' ]
@@ -290,14 +424,18 @@ pub enum SqlType {
     /// DOUBLE PRECISION
     Double,
     /// (DECIMAL | NUMERIC) [ '(' precision [ ',' scale ] ')' ]
+    ///
+    /// The scale can be negative (e.g. `NUMERIC(10, -2)` rounds to hundreds)
+    /// on the backends that allow it, see [SqlType::validate].
     Numeric(Option<(u8, Option<i8>)>),
     /// (BIGDECIMAL | BIGNUMERIC) [ '(' precision [ ',' scale ] ')' ]
     BigNumeric(Option<(u8, Option<i8>)>),
     /// (CHAR | CHARACTER | NCHAR | NATIONAL CHAR) [ '(' length ')' ]
-    Char(Option<usize>),
+    /// [ CHARACTER SET charset ] [ COLLATE collation ]
+    Char(Option<usize>, StringAttrs),
     /// ((VARCHAR | CHARACTER VARYING) [ '(' length ')' ] |
     ///  (NVARCHAR | NATIONAL CHAR VARYING) [ '(' length ')' ])
-    /// [ COLLATE collation ]
+    /// [ CHARACTER SET charset ] [ COLLATE collation ]
     Varchar(Option<usize>, StringAttrs),
     /// TEXT
     Text,
@@ -319,27 +457,44 @@ pub enum SqlType {
         precision: Option<u8>,
         time_zone_spec: TimeZoneSpec,
     },
-    /// DATETIME is different from timestamps in BigQuery.
-    DateTime,
+    /// DATETIME [ '(' precision ')' ] is different from timestamps in BigQuery.
+    DateTime { precision: Option<u8> },
     /// INTERVAL [
     ///        <start field> TO <end field>
     ///      | <single datetime field>
     /// ]
-    Interval(Option<(DateTimeField, Option<DateTimeField>)>),
+    ///
+    /// followed by the optional leading field precision, e.g. the 3 of
+    /// `INTERVAL DAY(3) TO SECOND(6)` on Oracle-like (generic) backends. Other
+    /// backends have no leading precision and drop it.
+    Interval(Option<(DateTimeField, Option<DateTimeField>)>, Option<u8>),
     /// JSON
     Json,
     /// JSONB
     Jsonb,
-    /// GEOMETRY
-    Geometry,
-    /// GEOGRAPHY
-    Geography,
+    /// GEOMETRY with an optional SRID (spatial reference system identifier)
+    Geometry(Option<u32>),
+    /// GEOGRAPHY with an optional SRID (spatial reference system identifier)
+    Geography(Option<u32>),
     /// ARRAY
     Array(Option<Box<SqlType>>),
+    /// ARRAY<T NOT NULL>, an array whose elements can't be null (Databricks
+    /// `containsNull = false` arrays)
+    NonNullArray(Box<SqlType>),
     /// STRUCT, STRUCT<>, STRUCT<...>
     Struct(Option<Vec<StructField>>),
+    /// ROW(...), the struct type of Trino (generic backends), whose fields can
+    /// be [positional](StructField::positional) (`ROW(INTEGER, VARCHAR)`).
+    /// Other backends render it as a struct.
+    Row(Vec<StructField>),
     /// MAP <key type, value type>
     Map(Option<(Box<SqlType>, Box<SqlType>)>),
+    /// UUID / UNIQUEIDENTIFIER
+    Uuid,
+    /// INET
+    Inet,
+    /// CIDR
+    Cidr,
     /// VARIANT
     Variant,
     /// VOID
@@ -358,6 +513,253 @@ impl SqlType {
         SqlType::Varchar(max_len, Default::default())
     }
 
+    /// The narrowest signed type that holds every value of an unsigned
+    /// integer that is `bits` wide.
+    pub fn signed_for_unsigned(bits: u8) -> Self {
+        match bits {
+            0..=8 => SqlType::SmallInt,
+            9..=16 => SqlType::Integer,
+            17..=32 => SqlType::BigInt,
+            // UInt64 values don't fit in a BIGINT
+            _ => SqlType::Numeric(Some((20, Some(0)))),
+        }
+    }
+
+    /// Resolve the type of a (possibly nested) struct field given a path of field names.
+    ///
+    /// Returns `None` if any segment of the path doesn't name a field of a
+    /// `Struct` type. An empty path resolves to `self`.
+    ///
+    /// Fields that were quoted in the source are matched case-sensitively, plain
+    /// fields are matched case-insensitively.
+    pub fn field_type(&self, path: &[&str]) -> Option<&SqlType> {
+        let mut current = self;
+        for segment in path {
+            let (SqlType::Struct(Some(fields)) | SqlType::Row(fields)) = current else {
+                return None;
+            };
+            let field = fields.iter().find(|field| match &field.name {
+                Ident::Plain(name) => eqi(name, segment),
+                Ident::Unquoted(_, name) => name == segment,
+            })?;
+            current = &field.sql_type;
+        }
+        Some(current)
+    }
+
+    /// Returns the element type of an `ARRAY` or the value type of a `MAP`.
+    ///
+    /// Returns `None` for other types and for arrays and maps without type
+    /// parameters (e.g. Snowflake's untyped `ARRAY`).
+    pub fn element_type(&self) -> Option<&SqlType> {
+        match self {
+            SqlType::Array(Some(inner)) | SqlType::NonNullArray(inner) => Some(inner),
+            SqlType::Map(Some((_, value))) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the key type of a `MAP`, or `None` for other types.
+    pub fn key_type(&self) -> Option<&SqlType> {
+        match self {
+            SqlType::Map(Some((key, _))) => Some(key),
+            _ => None,
+        }
+    }
+
+    /// Returns the fields of a `STRUCT` or `ROW`, or `None` for other types and
+    /// structs without declared fields.
+    pub fn fields(&self) -> Option<&[StructField]> {
+        match self {
+            SqlType::Struct(Some(fields)) | SqlType::Row(fields) => Some(fields),
+            _ => None,
+        }
+    }
+
+    /// The number of levels of nesting in this type: 1 for scalar types and
+    /// untyped containers, 2 for `ARRAY<INT>`, and so on.
+    ///
+    /// This is the measure bounded by [MAX_NESTING_DEPTH] when parsing.
+    pub fn depth(&self) -> usize {
+        let inner = match self {
+            SqlType::Array(Some(inner)) | SqlType::NonNullArray(inner) => inner.depth(),
+            SqlType::Map(Some((key, value))) => key.depth().max(value.depth()),
+            SqlType::Struct(Some(fields)) | SqlType::Row(fields) => fields
+                .iter()
+                .map(|field| field.sql_type.depth())
+                .max()
+                .unwrap_or(0),
+            _ => 0,
+        };
+        1 + inner
+    }
+
+    /// Check that `backend` accepts this (possibly nested) type.
+    ///
+    /// The parser is permissive on purpose, so this catches types that parse
+    /// but would fail at query time. Currently this rejects the nested arrays
+    /// (`ARRAY<ARRAY<T>>`) that BigQuery disallows, the `TIME WITH [LOCAL]
+    /// TIME ZONE` types that Snowflake and BigQuery don't have (the renderer
+    /// still emits those for debugging) and negative numeric scales outside of
+    /// Postgres and generic (e.g. Oracle) backends.
+    pub fn validate(&self, backend: Backend) -> Result<(), String> {
+        use SqlType::*;
+        match self {
+            Time {
+                time_zone_spec:
+                    TimeZoneSpec::With
+                    | TimeZoneSpec::Local
+                    | TimeZoneSpec::Offset(_)
+                    | TimeZoneSpec::Named(_),
+                ..
+            } if matches!(backend, Backend::Snowflake | Backend::BigQuery) => Err(format!(
+                "{backend} does not support '{}': use TIME, or a TIMESTAMP type to keep the time zone",
+                self.to_string(backend),
+            )),
+            Numeric(Some((_, Some(scale)))) | BigNumeric(Some((_, Some(scale))))
+                if *scale < 0
+                    && !matches!(backend, Backend::Postgres | Backend::Generic { .. }) =>
+            {
+                Err(format!(
+                    "{backend} does not support a negative scale in '{}'",
+                    self.to_string(backend),
+                ))
+            }
+            Array(Some(inner)) | NonNullArray(inner) => {
+                if backend == Backend::BigQuery && matches!(**inner, Array(_) | NonNullArray(_)) {
+                    return Err(format!(
+                        "BigQuery does not support nested arrays such as '{}': \
+                         wrap the inner array in a struct, e.g. ARRAY<STRUCT<x {}>>",
+                        self.to_string(backend),
+                        inner.to_string(backend),
+                    ));
+                }
+                inner.validate(backend)
+            }
+            Struct(Some(fields)) | Row(fields) => fields
+                .iter()
+                .try_for_each(|field| field.sql_type.validate(backend)),
+            Map(Some((key, value))) => {
+                key.validate(backend)?;
+                value.validate(backend)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// A struct like this `STRUCT` or `ROW`, but with the given `fields`.
+    fn with_fields(&self, fields: Vec<StructField>) -> SqlType {
+        match self {
+            SqlType::Row(_) => SqlType::Row(fields),
+            _ => SqlType::Struct(Some(fields)),
+        }
+    }
+
+    /// Replace every [TimeZoneSpec::Unspecified] timestamp in this (possibly nested)
+    /// type with a concrete time zone spec according to `policy`.
+    ///
+    /// Fails only if `policy` is [TimestampPolicy::Error] and a timestamp is
+    /// ambiguous on `backend`.
+    pub fn resolve_timezone(
+        &self,
+        backend: Backend,
+        policy: TimestampPolicy,
+    ) -> Result<SqlType, String> {
+        use SqlType::*;
+        let resolved = match self {
+            Timestamp {
+                precision,
+                time_zone_spec,
+            } => Timestamp {
+                precision: *precision,
+                time_zone_spec: time_zone_spec.resolve(backend, policy)?,
+            },
+            Array(Some(inner)) => Array(Some(Box::new(inner.resolve_timezone(backend, policy)?))),
+            NonNullArray(inner) => NonNullArray(Box::new(inner.resolve_timezone(backend, policy)?)),
+            Struct(Some(fields)) | Row(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|field| {
+                        Ok(StructField {
+                            name: field.name.clone(),
+                            sql_type: field.sql_type.resolve_timezone(backend, policy)?,
+                            nullable: field.nullable,
+                            comment_tok: field.comment_tok.clone(),
+                        })
+                    })
+                    .collect::<Result<Vec<_>, String>>()?;
+                self.with_fields(fields)
+            }
+            Map(Some((key, value))) => Map(Some((
+                Box::new(key.resolve_timezone(backend, policy)?),
+                Box::new(value.resolve_timezone(backend, policy)?),
+            ))),
+            _ => self.clone(),
+        };
+        Ok(resolved)
+    }
+
+    /// Replace the types in this (possibly nested) type that `compat` asks to
+    /// render differently.
+    fn apply_compat(&self, compat: CompatOptions) -> SqlType {
+        use SqlType::*;
+        match self {
+            Boolean if compat.boolean_as_smallint => SmallInt,
+            Array(Some(inner)) => Array(Some(Box::new(inner.apply_compat(compat)))),
+            NonNullArray(inner) => NonNullArray(Box::new(inner.apply_compat(compat))),
+            Struct(Some(fields)) | Row(fields) => self.with_fields(
+                fields
+                    .iter()
+                    .map(|field| StructField {
+                        name: field.name.clone(),
+                        sql_type: field.sql_type.apply_compat(compat),
+                        nullable: field.nullable,
+                        comment_tok: field.comment_tok.clone(),
+                    })
+                    .collect(),
+            ),
+            Map(Some((key, value))) => Map(Some((
+                Box::new(key.apply_compat(compat)),
+                Box::new(value.apply_compat(compat)),
+            ))),
+            _ => self.clone(),
+        }
+    }
+
+    /// Parse the [SqlType::Other] types in this (possibly nested) type again,
+    /// replacing those that the parser now recognizes.
+    ///
+    /// Schemas stored by an older version can contain `Other` types that this
+    /// version knows about. Types that still don't parse to anything else are
+    /// left as they are.
+    pub fn reparse(&self, backend: Backend) -> SqlType {
+        use SqlType::*;
+        match self {
+            Other(s) => match SqlType::parse(backend, s) {
+                Ok((sql_type, _)) if !matches!(sql_type, Other(_)) => sql_type,
+                _ => self.clone(),
+            },
+            Array(Some(inner)) => Array(Some(Box::new(inner.reparse(backend)))),
+            NonNullArray(inner) => NonNullArray(Box::new(inner.reparse(backend))),
+            Struct(Some(fields)) | Row(fields) => self.with_fields(
+                fields
+                    .iter()
+                    .map(|field| StructField {
+                        name: field.name.clone(),
+                        sql_type: field.sql_type.reparse(backend),
+                        nullable: field.nullable,
+                        comment_tok: field.comment_tok.clone(),
+                    })
+                    .collect(),
+            ),
+            Map(Some((key, value))) => Map(Some((
+                Box::new(key.reparse(backend)),
+                Box::new(value.reparse(backend)),
+            ))),
+            _ => self.clone(),
+        }
+    }
+
     /// Extract the SQL type and nullability from an Arrow `Field`.
     ///
     /// This is a lossless conversion if the SQL type is stored in the
@@ -368,44 +770,228 @@ impl SqlType {
         match type_string {
             Some(type_str) => {
                 let (sql_type, nullable) = Self::parse(backend, type_str)?;
+                let sql_type = sql_type.with_srid_from(field.metadata());
                 let nullable = nullable || field.is_nullable();
                 Ok((sql_type, nullable))
             }
             None => {
-                let sql_type = Self::_from_arrow_type(backend, field.data_type());
+                let sql_type = Self::_from_arrow_field(backend, field);
                 Ok((sql_type, field.is_nullable()))
             }
         }
     }
 
+    /// Extract the name, SQL type and nullability of every field of an Arrow
+    /// `Schema`, in order, with [SqlType::from_field].
+    pub fn from_arrow_schema(
+        backend: Backend,
+        schema: &Schema,
+    ) -> Result<Vec<(String, Self, bool)>, String> {
+        schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let (sql_type, nullable) = Self::from_field(backend, field)
+                    .map_err(|err| format!("Column '{}': {err}", field.name()))?;
+                Ok((field.name().clone(), sql_type, nullable))
+            })
+            .collect()
+    }
+
     /// Convert the SQL type to an Arrow `Field`.
     ///
     /// It encodes the SQL type as metadata in the Arrow field and picks the best
     /// Arrow `DataType` that matches for the SQL type.
     pub fn to_field(&self, backend: Backend, name: String, nullable: bool) -> Field {
+        self.to_arrow_field_with_metadata_override(
+            backend,
+            name,
+            nullable,
+            metadata_sql_type_key(backend),
+        )
+    }
+
+    /// Like [SqlType::to_field], but writes the SQL type under `metadata_key`
+    /// instead of the backend's own key, e.g. the cross-backend `type_text`
+    /// for schemas consumed by other tools.
+    ///
+    /// [SqlType::from_field] only reads the SQL type back if `metadata_key`
+    /// is one of the keys it checks for `backend`.
+    pub fn to_arrow_field_with_metadata_override(
+        &self,
+        backend: Backend,
+        name: String,
+        nullable: bool,
+        metadata_key: &str,
+    ) -> Field {
         let data_type = self.pick_best_arrow_type(backend);
         let mut metadata = HashMap::new();
+        metadata.insert(metadata_key.to_string(), self.to_string(backend));
+        self.insert_extension_metadata(backend, &mut metadata);
+        Field::new(name, data_type, nullable).with_metadata(metadata)
+    }
+
+    /// Tag the metadata of an Arrow `Field` holding this SQL type with an
+    /// Arrow extension type name when the storage type alone is not enough
+    /// to tell the SQL type apart (e.g. network addresses stored as strings).
+    fn insert_extension_metadata(&self, backend: Backend, metadata: &mut HashMap<String, String>) {
+        let (extension_name, extension_metadata) = match self {
+            SqlType::Inet => (NETWORK_ADDRESS_EXTENSION_NAME, "inet".to_string()),
+            SqlType::Cidr => (NETWORK_ADDRESS_EXTENSION_NAME, "cidr".to_string()),
+            SqlType::Geography(srid) => {
+                let srid = match backend {
+                    Backend::Snowflake => srid.or(Some(SNOWFLAKE_GEOGRAPHY_SRID)),
+                    _ => *srid,
+                };
+                (GEOARROW_WKT_EXTENSION_NAME, geoarrow_metadata(srid, true))
+            }
+            SqlType::Geometry(srid) => {
+                (GEOARROW_WKT_EXTENSION_NAME, geoarrow_metadata(*srid, false))
+            }
+            _ => return,
+        };
         metadata.insert(
-            metadata_sql_type_key(backend).to_string(),
-            self.to_string(backend),
+            ARROW_EXTENSION_NAME_KEY.to_string(),
+            extension_name.to_string(),
         );
-        Field::new(name, data_type, nullable).with_metadata(metadata)
+        metadata.insert(ARROW_EXTENSION_METADATA_KEY.to_string(), extension_metadata);
+    }
+
+    /// Fill in the SRID of a geospatial type from the GeoArrow metadata of a field.
+    fn with_srid_from(self, metadata: &HashMap<String, String>) -> SqlType {
+        let srid = || {
+            metadata
+                .get(ARROW_EXTENSION_METADATA_KEY)
+                .and_then(|m| srid_from_geoarrow_metadata(m))
+        };
+        match self {
+            SqlType::Geometry(None) => SqlType::Geometry(srid()),
+            SqlType::Geography(None) => SqlType::Geography(srid()),
+            sql_type => sql_type,
+        }
     }
 
     /// Parse the SQL type and return it along with a boolean indicating if its nullable.
+    ///
+    /// Types nested more than [MAX_NESTING_DEPTH] levels deep are rejected.
     pub fn parse(backend: Backend, input: &str) -> Result<(SqlType, bool), String> {
-        let mut parser = Parser::new(input);
+        Self::parse_with_nesting_limit(backend, input, MAX_NESTING_DEPTH)
+    }
+
+    /// Like [SqlType::parse], but rejects types whose [depth](SqlType::depth)
+    /// exceeds `limit` instead of [MAX_NESTING_DEPTH].
+    pub fn parse_with_nesting_limit(
+        backend: Backend,
+        input: &str,
+        limit: usize,
+    ) -> Result<(SqlType, bool), String> {
+        let mut parser = Parser::new(input).with_nesting_limit(limit);
         parser
             .parse(backend)
             .map_err(|err| format!("Failed to parse SQL type '{input}': {err}"))
     }
 
+    /// Like [SqlType::parse], but also returns the raw expression of a
+    /// `DEFAULT <expr>` clause in a column definition (e.g. `0` in
+    /// `INTEGER DEFAULT 0 NOT NULL`).
+    pub fn parse_with_default(
+        backend: Backend,
+        input: &str,
+    ) -> Result<(SqlType, bool, Option<String>), String> {
+        let mut parser = Parser::new(input);
+        parser
+            .parse_column_type(backend)
+            .map(|(ty, nullable, default)| (ty, nullable, default.map(str::to_string)))
+            .map_err(|err| format!("Failed to parse SQL type '{input}': {err}"))
+    }
+
     pub fn to_string(&self, backend: Backend) -> String {
         let mut out = String::new();
         self.write(backend, &mut out).unwrap();
         out
     }
 
+    /// Render the type for every backend, for debugging and test failure messages.
+    ///
+    /// Types a backend can't express get the same best-effort rendering as
+    /// [SqlType::to_string] produces for it.
+    pub fn render_all_backends(&self) -> Vec<(Backend, String)> {
+        let generic = Backend::Generic {
+            library_name: "generic",
+            entrypoint: None,
+        };
+        Backend::all()
+            .iter()
+            .copied()
+            .chain([generic])
+            .map(|backend| (backend, self.to_string(backend)))
+            .collect()
+    }
+
+    /// Canonicalize a SQL type string into the preferred spelling of the backend.
+    ///
+    /// Useful for comparing user-provided types against warehouse metadata, e.g.
+    /// `number(10,0)` becomes `NUMBER(10, 0)` on Snowflake. A `NOT NULL`
+    /// constraint in the input is preserved.
+    pub fn normalize(backend: Backend, input: &str) -> Result<String, String> {
+        let (sql_type, nullable) = Self::parse(backend, input)?;
+        let mut out = String::new();
+        sql_type
+            .write_with_nullability(backend, nullable, &mut out)
+            .unwrap();
+        Ok(out)
+    }
+
+    /// Compare two types like their `to_string(backend)` would, except that
+    /// struct fields are matched by name instead of position.
+    ///
+    /// Useful for comparing schemas where the column order differs.
+    pub fn structurally_equal_unordered(&self, other: &SqlType, backend: Backend) -> bool {
+        use SqlType::*;
+        match (self, other) {
+            (Struct(Some(fields)), Struct(Some(other_fields)))
+            | (Row(fields), Row(other_fields)) => {
+                fields.len() == other_fields.len()
+                    && fields.iter().all(|field| {
+                        let name = field.name.display(backend).to_string();
+                        other_fields.iter().any(|other_field| {
+                            other_field.name.display(backend).to_string() == name
+                                && other_field.nullable == field.nullable
+                                && field
+                                    .sql_type
+                                    .structurally_equal_unordered(&other_field.sql_type, backend)
+                        })
+                    })
+            }
+            (Array(Some(inner)), Array(Some(other_inner)))
+            | (NonNullArray(inner), NonNullArray(other_inner)) => {
+                inner.structurally_equal_unordered(other_inner, backend)
+            }
+            (Map(Some((key, value))), Map(Some((other_key, other_value)))) => {
+                key.structurally_equal_unordered(other_key, backend)
+                    && value.structurally_equal_unordered(other_value, backend)
+            }
+            _ => self.to_string(backend) == other.to_string(backend),
+        }
+    }
+
+    /// Like [SqlType::write], but with the compatibility renderings selected
+    /// in `compat` (e.g. `BOOLEAN` as `SMALLINT`).
+    pub fn write_compat(
+        &self,
+        backend: Backend,
+        compat: CompatOptions,
+        out: &mut String,
+    ) -> fmt::Result {
+        self.apply_compat(compat).write(backend, out)
+    }
+
+    pub fn to_string_compat(&self, backend: Backend, compat: CompatOptions) -> String {
+        let mut out = String::new();
+        self.write_compat(backend, compat, &mut out).unwrap();
+        out
+    }
+
     /// Render a SQL type string in the preferred syntax for a given backend.
     pub fn write(&self, backend: Backend, out: &mut String) -> fmt::Result {
         use Backend::*;
@@ -418,7 +1004,7 @@ impl SqlType {
             (BigQuery, Real | Float(_) | Double) => {
                 write!(out, "FLOAT64")
             }
-            (BigQuery, Char(_) | Varchar(..) | Text | Clob) => {
+            (BigQuery, Char(..) | Varchar(..) | Text | Clob) => {
                 write!(out, "STRING")
             }
             (BigQuery, Blob | Binary(_)) => write!(out, "BYTES"),
@@ -461,7 +1047,10 @@ impl SqlType {
                 // Snowflake does not have a TIME WITH TIME ZONE type
                 match time_zone_spec {
                     TimeZoneSpec::Unspecified | TimeZoneSpec::Without => Ok(()),
-                    TimeZoneSpec::Local | TimeZoneSpec::With => {
+                    TimeZoneSpec::Local
+                    | TimeZoneSpec::With
+                    | TimeZoneSpec::Offset(_)
+                    | TimeZoneSpec::Named(_) => {
                         // for debugging purposes, we still render these invalid specs
                         time_zone_spec.write_with_leading_space(backend, out)
                     }
@@ -481,13 +1070,19 @@ impl SqlType {
                     None => Ok(()),
                 }
             }
-            (Snowflake, DateTime) => write!(out, "TIMESTAMP_NTZ"),
+            (Snowflake, DateTime { precision }) => match precision {
+                Some(p) => write!(out, "TIMESTAMP_NTZ({p})"),
+                None => write!(out, "TIMESTAMP_NTZ"),
+            },
             // }}}
 
             // PostgreSQL {{{
             (Postgres | Redshift | RedshiftODBC, TinyInt) => write!(out, "SMALLINT"),
             (Postgres | Redshift | RedshiftODBC, Binary(_) | Blob) => write!(out, "BYTEA"),
-            (Postgres | Redshift | RedshiftODBC, DateTime) => write!(out, "TIMESTAMP"),
+            (Postgres | Redshift | RedshiftODBC, DateTime { precision }) => match precision {
+                Some(p) => write!(out, "TIMESTAMP({p})"),
+                None => write!(out, "TIMESTAMP"),
+            },
             (
                 Postgres | Redshift | RedshiftODBC,
                 Timestamp {
@@ -508,13 +1103,22 @@ impl SqlType {
             },
             (Postgres | Redshift | RedshiftODBC, Float(_)) => write!(out, "REAL"),
             (Postgres | Redshift | RedshiftODBC, Clob) => write!(out, "TEXT"),
-            (Postgres | Redshift | RedshiftODBC | Salesforce, Array(Some(inner))) => {
+            (
+                Postgres | Redshift | RedshiftODBC | Salesforce,
+                Array(Some(inner)) | NonNullArray(inner),
+            ) => {
                 inner.write(backend, out)?;
                 write!(out, "[]")
             }
             // }}}
 
             // Databricks {{{
+            // https://docs.databricks.com/en/sql/language-manual/sql-ref-datatypes.html
+            (Databricks | DatabricksODBC, Boolean) => write!(out, "BOOLEAN"),
+            (Databricks | DatabricksODBC, TinyInt) => write!(out, "TINYINT"),
+            (Databricks | DatabricksODBC, SmallInt) => write!(out, "SMALLINT"),
+            (Databricks | DatabricksODBC, Integer) => write!(out, "INT"),
+            (Databricks | DatabricksODBC, BigInt) => write!(out, "BIGINT"),
             (Databricks | DatabricksODBC, Binary(_) | Blob) => {
                 // max_len for BINARY is ignored because Databricks doesn't support it
                 write!(out, "BINARY")
@@ -545,19 +1149,147 @@ impl SqlType {
             }
             (Databricks | DatabricksODBC, Real | Float(_)) => write!(out, "FLOAT"),
             (Databricks | DatabricksODBC, Double) => write!(out, "DOUBLE"),
-            (Databricks | DatabricksODBC, DateTime) => write!(out, "TIMESTAMP_NTZ"),
+            // Databricks timestamps always have microsecond precision
+            (Databricks | DatabricksODBC, DateTime { .. }) => write!(out, "TIMESTAMP_NTZ"),
             (Databricks | DatabricksODBC, Timestamp { time_zone_spec, .. }) => {
                 write!(out, "TIMESTAMP")?;
                 time_zone_spec.write_single_token_suffix(backend, out)
             }
             // }}}
 
+            // SQL Server {{{
+            (MSSQL, Boolean) => write!(out, "BIT"),
+            // Variable-length strings are always rendered as NVARCHAR, which stores
+            // UTF-16, while the encoding of VARCHAR depends on the collation. This
+            // includes the unbounded TEXT and CLOB. Fixed-length CHAR/NCHAR keep
+            // their spelling.
+            (MSSQL, Varchar(max_len, attrs)) => {
+                match max_len {
+                    Some(len) if *len > 0 => write!(out, "NVARCHAR({len})")?,
+                    _ => write!(out, "NVARCHAR(MAX)")?,
+                }
+                if let Some(collate_spec) = &attrs.collate_spec {
+                    write!(out, " COLLATE {collate_spec}")?;
+                }
+                Ok(())
+            }
+            (MSSQL, Text | Clob) => write!(out, "NVARCHAR(MAX)"),
+            (MSSQL, Binary(Some(len))) if *len > 0 => write!(out, "VARBINARY({len})"),
+            (MSSQL, Binary(_) | Blob) => write!(out, "VARBINARY(MAX)"),
+            (MSSQL, Time { precision, .. }) => match precision {
+                // SQL Server has no TIME WITH TIME ZONE type
+                Some(p) => write!(out, "TIME({p})"),
+                None => write!(out, "TIME"),
+            },
+            // TIMESTAMP is a synonym for ROWVERSION in SQL Server, not a date/time type
+            (
+                MSSQL,
+                Timestamp {
+                    precision,
+                    time_zone_spec,
+                },
+            ) => {
+                match time_zone_spec {
+                    TimeZoneSpec::With
+                    | TimeZoneSpec::Local
+                    | TimeZoneSpec::Offset(_)
+                    | TimeZoneSpec::Named(_) => write!(out, "DATETIMEOFFSET")?,
+                    TimeZoneSpec::Without | TimeZoneSpec::Unspecified => write!(out, "DATETIME2")?,
+                }
+                match precision {
+                    Some(p) => write!(out, "({p})"),
+                    None => Ok(()),
+                }
+            }
+            (MSSQL, Uuid) => write!(out, "UNIQUEIDENTIFIER"),
+            // }}}
+
+            // ClickHouse {{{
+            (ClickHouse, Boolean) => write!(out, "Bool"),
+            (ClickHouse, TinyInt) => write!(out, "Int8"),
+            (ClickHouse, SmallInt) => write!(out, "Int16"),
+            (ClickHouse, Integer) => write!(out, "Int32"),
+            (ClickHouse, BigInt) => write!(out, "Int64"),
+            (ClickHouse, UnsignedInteger(bits)) => write!(out, "UInt{bits}"),
+            (ClickHouse, Real) => write!(out, "Float32"),
+            (ClickHouse, Float(Some(p))) if *p <= 24 => write!(out, "Float32"),
+            (ClickHouse, Float(_) | Double) => write!(out, "Float64"),
+            (ClickHouse, Numeric(None)) => write!(out, "Decimal"),
+            (ClickHouse, Numeric(Some((p, None)))) => write!(out, "Decimal({p})"),
+            (ClickHouse, Numeric(Some((p, Some(s))))) => write!(out, "Decimal({p}, {s})"),
+            // ClickHouse strings are arbitrary sequences of bytes
+            (ClickHouse, Char(Some(len), _) | Binary(Some(len))) if *len > 0 => {
+                write!(out, "FixedString({len})")
+            }
+            (ClickHouse, Char(..) | Varchar(..) | Text | Clob | Binary(_) | Blob) => {
+                write!(out, "String")
+            }
+            (ClickHouse, Inet | Cidr) => write!(out, "String"),
+            (ClickHouse, Date) => write!(out, "Date"),
+            (ClickHouse, Time { precision, .. }) => match precision {
+                Some(p) => write!(out, "Time64({p})"),
+                None => write!(out, "Time"),
+            },
+            (ClickHouse, DateTime { precision }) => match precision {
+                Some(p) => write!(out, "DateTime64({p})"),
+                None => write!(out, "DateTime"),
+            },
+            (
+                ClickHouse,
+                Timestamp {
+                    precision,
+                    time_zone_spec,
+                },
+            ) => {
+                let precision =
+                    precision.unwrap_or_else(|| time_unit_to_precision(default_time_unit(backend)));
+                match time_zone_spec {
+                    // DateTime('tz') is the second-precision spelling
+                    TimeZoneSpec::Named(time_zone) if precision == 0 => {
+                        write!(out, "DateTime('{time_zone}')")
+                    }
+                    TimeZoneSpec::Named(time_zone) => {
+                        write!(out, "DateTime64({precision}, '{time_zone}')")
+                    }
+                    TimeZoneSpec::With => write!(out, "DateTime64({precision}, 'UTC')"),
+                    _ => write!(out, "DateTime64({precision})"),
+                }
+            }
+            // Array elements and map values are not nullable unless wrapped in
+            // Nullable(...), which only non-null arrays leave out
+            (ClickHouse, Array(Some(inner)) | NonNullArray(inner)) => {
+                write!(out, "Array(")?;
+                inner.write_with_nullability(backend, matches!(self, Array(_)), out)?;
+                write!(out, ")")
+            }
+            (ClickHouse, Map(Some((key, value)))) => {
+                write!(out, "Map(")?;
+                key.write(backend, out)?;
+                write!(out, ", ")?;
+                value.write_with_nullability(backend, true, out)?;
+                write!(out, ")")
+            }
+            (ClickHouse, Uuid) => write!(out, "UUID"),
+            (ClickHouse, Json) => write!(out, "JSON"),
+            (ClickHouse, Void) => write!(out, "Nothing"),
+            // }}}
+
             // Generic SQL / Fallback logic {{{
             (_, Boolean) => write!(out, "BOOLEAN"),
             (_, TinyInt) => write!(out, "TINYINT"),
             (_, SmallInt) => write!(out, "SMALLINT"),
             (_, Integer) => write!(out, "INT"),
             (_, BigInt) => write!(out, "BIGINT"),
+            (Generic { .. }, UnsignedInteger(bits)) => {
+                let signed = match bits {
+                    0..=8 => "TINYINT",
+                    9..=16 => "SMALLINT",
+                    17..=32 => "INT",
+                    _ => "BIGINT",
+                };
+                write!(out, "{signed} UNSIGNED")
+            }
+            (_, UnsignedInteger(bits)) => SqlType::signed_for_unsigned(*bits).write(backend, out),
 
             (_, Real) => write!(out, "REAL"),
             (_, Float(Some(p))) => write!(out, "FLOAT({p})"),
@@ -571,24 +1303,28 @@ impl SqlType {
             (_, BigNumeric(Some((p, None)))) => write!(out, "BIGNUMERIC({p})"),
             (_, BigNumeric(Some((p, Some(s))))) => write!(out, "BIGNUMERIC({p}, {s})"),
 
-            (_, Char(None)) => write!(out, "CHAR"),
-            (_, Char(Some(len))) => {
+            (_, Char(len, attrs)) => {
+                if attrs.national && has_national_char_types(backend) {
+                    write!(out, "N")?;
+                }
                 write!(out, "CHAR")?;
-                if *len > 0 {
+                if let Some(len) = len
+                    && *len > 0
+                {
                     write!(out, "({len})")?;
                 }
-                Ok(())
+                attrs.write_suffix(backend, out)
             }
             (_, Varchar(max_len, attrs)) => {
+                if attrs.national && has_national_char_types(backend) {
+                    write!(out, "N")?;
+                }
                 write!(out, "VARCHAR")?;
                 let max_len = max_len.unwrap_or(0);
                 if max_len > 0 {
                     write!(out, "({max_len})")?;
                 }
-                if let Some(collate_spec) = &attrs.collate_spec {
-                    write!(out, " COLLATE {collate_spec}")?;
-                }
-                Ok(())
+                attrs.write_suffix(backend, out)
             }
             (_, Text) => write!(out, "TEXT"),
             (_, Clob) => write!(out, "CLOB"),
@@ -616,7 +1352,10 @@ impl SqlType {
                 }?;
                 time_zone_spec.write_with_leading_space(backend, out)
             }
-            (_, DateTime) => write!(out, "DATETIME"),
+            (_, DateTime { precision }) => match precision {
+                Some(p) => write!(out, "DATETIME({p})"),
+                None => write!(out, "DATETIME"),
+            },
             (
                 _,
                 Timestamp {
@@ -631,7 +1370,17 @@ impl SqlType {
                 time_zone_spec.write_with_leading_space(backend, out)
             }
 
-            (_, Interval(qualifier)) => match qualifier {
+            (Generic { .. }, Interval(Some((start, end)), Some(leading_precision))) => {
+                write!(out, "INTERVAL {start}({leading_precision})")?;
+                match end {
+                    Some(end) => {
+                        write!(out, " TO ")?;
+                        end.write_as_second_precision(out)
+                    }
+                    None => Ok(()),
+                }
+            }
+            (_, Interval(qualifier, _)) => match qualifier {
                 None => write!(out, "INTERVAL"),
                 Some((start, end)) => {
                     write!(out, "INTERVAL ")?;
@@ -647,30 +1396,46 @@ impl SqlType {
 
             (_, Json) => write!(out, "JSON"),
             (_, Jsonb) => write!(out, "JSONB"),
-            (_, Geometry) => write!(out, "GEOMETRY"),
-            (_, Geography) => write!(out, "GEOGRAPHY"),
+            // The SRID is not part of the type syntax, it's kept in the Arrow metadata
+            (BigQuery, Geometry(_)) => {
+                // BigQuery only has a spherical GEOGRAPHY type
+                tracing::debug!("rendering GEOMETRY as GEOGRAPHY on BigQuery");
+                write!(out, "GEOGRAPHY")
+            }
+            (_, Geometry(_)) => write!(out, "GEOMETRY"),
+            (_, Geography(_)) => write!(out, "GEOGRAPHY"),
             (_, Array(None)) => write!(out, "ARRAY"),
-            (backend, Array(Some(inner))) => {
+            (backend, Array(Some(inner)) | NonNullArray(inner)) => {
                 match backend {
                     Snowflake => write!(out, "ARRAY(")?,
                     _ => write!(out, "ARRAY<")?,
                 }
-                inner.write(backend, out)?;
+                // Only Databricks can declare the elements of an array as NOT NULL
+                let elements_nullable =
+                    matches!(self, Array(_)) || !matches!(backend, Databricks | DatabricksODBC);
+                inner.write_with_nullability(backend, elements_nullable, out)?;
                 match backend {
                     Snowflake => write!(out, ")"),
                     _ => write!(out, ">"),
                 }
             }
+            (Snowflake, Struct(None)) => write!(out, "OBJECT"),
             (_, Struct(None)) => write!(out, "STRUCT"),
-            (_, Struct(Some(fields))) => {
+            (_, Struct(Some(fields)) | Row(fields)) => {
+                let row = matches!(backend, Generic { .. }) && matches!(self, Row(_));
                 match backend {
+                    _ if row => write!(out, "ROW(")?,
                     Snowflake => write!(out, "OBJECT(")?,
                     BigQuery | Databricks | DatabricksODBC => write!(out, "STRUCT<")?,
                     Postgres | Salesforce => write!(out, "(")?,
                     // Redshift doesn't support object/struct types
                     Redshift | RedshiftODBC => write!(out, "(")?,
-                    Generic { .. } => write!(out, "STRUCT<")?,
+                    // SQL Server doesn't support object/struct types
+                    MSSQL | Generic { .. } => write!(out, "STRUCT<")?,
+                    ClickHouse => write!(out, "Tuple(")?,
                 }
+                // Trino rows can leave out the field names
+                let positional = row && are_positional(fields);
                 for (i, field) in fields.iter().enumerate() {
                     let StructField {
                         name,
@@ -682,31 +1447,32 @@ impl SqlType {
                     if i > 0 {
                         write!(out, ", ")?;
                     }
-                    write!(
-                        out,
-                        "{}{}",
-                        name.display(backend),
-                        // Databricks allows a `:` between the name and type
-                        if matches!(backend, Databricks | DatabricksODBC) {
-                            ": "
-                        } else {
-                            " "
-                        }
-                    )?;
-                    sql_type.write(backend, out)?;
-                    if !nullable {
-                        write!(out, " NOT NULL")?;
+                    if !positional {
+                        write!(
+                            out,
+                            "{}{}",
+                            name.display(backend),
+                            // Databricks allows a `:` between the name and type
+                            if matches!(backend, Databricks | DatabricksODBC) {
+                                ": "
+                            } else {
+                                " "
+                            }
+                        )?;
                     }
+                    sql_type.write_with_nullability(backend, *nullable, out)?;
                     if let Some(tok) = comment_tok {
                         write!(out, " COMMENT {tok}")?;
                     }
                 }
                 match backend {
+                    _ if row => write!(out, ")"),
                     Snowflake => write!(out, ")"),
                     BigQuery | Databricks | DatabricksODBC => write!(out, ">"),
                     Postgres | Salesforce => write!(out, ")"),
                     Redshift | RedshiftODBC => write!(out, ")"),
-                    Generic { .. } => write!(out, ">"),
+                    MSSQL | Generic { .. } => write!(out, ">"),
+                    ClickHouse => write!(out, ")"),
                 }
             }
             (_, Map(None)) => write!(out, "MAP"),
@@ -717,6 +1483,13 @@ impl SqlType {
                 value.write(backend, out)?;
                 write!(out, ">")
             }
+            (BigQuery | Databricks | DatabricksODBC, Uuid) => write!(out, "STRING"),
+            (Snowflake, Uuid) => write!(out, "VARCHAR"),
+            (_, Uuid) => write!(out, "UUID"),
+            (BigQuery | Databricks | DatabricksODBC, Inet | Cidr) => write!(out, "STRING"),
+            (Snowflake, Inet | Cidr) => write!(out, "VARCHAR"),
+            (_, Inet) => write!(out, "INET"),
+            (_, Cidr) => write!(out, "CIDR"),
             (_, Variant) => write!(out, "VARIANT"),
             (_, Void) => write!(out, "VOID"),
             (_, Other(s)) => write!(out, "{s}"),
@@ -724,6 +1497,95 @@ impl SqlType {
         }
     }
 
+    /// Render the SQL type along with its nullability.
+    ///
+    /// ClickHouse encodes nullability in the type itself as `Nullable(T)`. Other
+    /// backends get a `NOT NULL` suffix when the type is not nullable.
+    pub fn write_with_nullability(
+        &self,
+        backend: Backend,
+        nullable: bool,
+        out: &mut String,
+    ) -> fmt::Result {
+        use SqlType::*;
+        use fmt::Write as _;
+        match backend {
+            Backend::ClickHouse => {
+                // Composite types (and JSON) can't be wrapped in Nullable(...) in ClickHouse
+                let wrap = nullable
+                    && !matches!(
+                        self,
+                        Array(_) | NonNullArray(_) | Struct(_) | Row(_) | Map(_) | Json
+                    );
+                if wrap {
+                    write!(out, "Nullable(")?;
+                }
+                self.write(backend, out)?;
+                if wrap {
+                    write!(out, ")")?;
+                }
+                Ok(())
+            }
+            _ => {
+                self.write(backend, out)?;
+                if !nullable {
+                    write!(out, " NOT NULL")?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Render the SQL type along with its nullability as a string.
+    ///
+    /// By default, nothing is emitted for nullable types. With `explicit_null`,
+    /// nullable types get a `NULL` suffix for DDL generators that don't want to
+    /// rely on the default (ClickHouse is always explicit through `Nullable(T)`).
+    pub fn to_string_with_nullability(
+        &self,
+        backend: Backend,
+        nullable: bool,
+        explicit_null: bool,
+    ) -> String {
+        let mut out = String::new();
+        self.write_with_nullability(backend, nullable, &mut out)
+            .unwrap();
+        if nullable && explicit_null && backend != Backend::ClickHouse {
+            out.push_str(" NULL");
+        }
+        out
+    }
+
+    /// Like [SqlType::_from_arrow_type], but takes the Arrow extension type
+    /// in the field metadata into account.
+    fn _from_arrow_field(backend: Backend, field: &Field) -> SqlType {
+        let metadata = field.metadata();
+        match metadata.get(ARROW_EXTENSION_NAME_KEY).map(String::as_str) {
+            Some(NETWORK_ADDRESS_EXTENSION_NAME) => {
+                match metadata
+                    .get(ARROW_EXTENSION_METADATA_KEY)
+                    .map(String::as_str)
+                {
+                    Some("cidr") => SqlType::Cidr,
+                    _ => SqlType::Inet,
+                }
+            }
+            // GeoArrow doesn't tell geometries from geographies (only edges differ)
+            Some(GEOARROW_WKT_EXTENSION_NAME) => {
+                let spherical = metadata
+                    .get(ARROW_EXTENSION_METADATA_KEY)
+                    .is_some_and(|m| m.contains(r#""edges":"spherical""#));
+                let sql_type = if spherical {
+                    SqlType::Geography(None)
+                } else {
+                    SqlType::Geometry(None)
+                };
+                sql_type.with_srid_from(metadata)
+            }
+            _ => Self::_from_arrow_type(backend, field.data_type()),
+        }
+    }
+
     /// Best-effort conversion from an Arrow `DataType` to a `SqlType`.
     ///
     /// Arrow types are less expressive than SQL types, so this function
@@ -734,14 +1596,20 @@ impl SqlType {
         match data_type {
             DataType::Null => SqlType::Varchar(None, Default::default()),
             DataType::Boolean => SqlType::Boolean,
-            DataType::Int8 | DataType::UInt8 | DataType::Int16 => SqlType::SmallInt,
-            DataType::UInt16 | DataType::Int32 => SqlType::Integer,
-            DataType::UInt32 | DataType::Int64 | DataType::UInt64 => SqlType::BigInt,
+            // backends without unsigned integers render a wider signed type
+            DataType::UInt8 | DataType::UInt16 | DataType::UInt32 | DataType::UInt64 => {
+                SqlType::UnsignedInteger(data_type.primitive_width().unwrap_or(8) as u8 * 8)
+            }
+            DataType::Int8 | DataType::Int16 => SqlType::SmallInt,
+            DataType::Int32 => SqlType::Integer,
+            DataType::Int64 => SqlType::BigInt,
             DataType::Float16 | DataType::Float32 => SqlType::Real,
             DataType::Float64 => SqlType::Double,
             DataType::Decimal128(p, s) | DataType::Decimal256(p, s) => {
                 // XXX: make these more succinct by looking up the defaults
                 // for each different backend.
+                //
+                // The scale is always kept explicit, even when it's 0.
                 SqlType::Numeric(Some((*p, Some(*s))))
             }
             DataType::Utf8View | DataType::Utf8 => SqlType::Varchar(None, Default::default()),
@@ -806,12 +1674,14 @@ impl SqlType {
             // Proposal for extending Arrow to support more SQL interval types:
             // https://docs.google.com/document/d/12ghQxWxyAhSQeZyy0IWiwJ02gTqFOgfYm8x851HZFLk/edit
             DataType::Interval(interval_unit) => match interval_unit {
-                IntervalUnit::YearMonth => {
-                    SqlType::Interval(Some((DateTimeField::Year, Some(DateTimeField::Month))))
-                }
-                IntervalUnit::DayTime => {
-                    SqlType::Interval(Some((DateTimeField::Day, Some(DateTimeField::Millisecond))))
-                }
+                IntervalUnit::YearMonth => SqlType::Interval(
+                    Some((DateTimeField::Year, Some(DateTimeField::Month))),
+                    None,
+                ),
+                IntervalUnit::DayTime => SqlType::Interval(
+                    Some((DateTimeField::Day, Some(DateTimeField::Millisecond))),
+                    None,
+                ),
                 // MonthDayNano was added to Arrow because it is closest to how Postgress
                 // and BigQuery model intervals.  Each field is independent (e.g. there is
                 // no constraint that nanoseconds have the same sign as days or that the
@@ -846,10 +1716,10 @@ impl SqlType {
                 // that needs no padding. So these can be stored contiguously in memory
                 // without wasting space.
                 // ```
-                IntervalUnit::MonthDayNano => SqlType::Interval(Some((
-                    DateTimeField::Month,
-                    Some(DateTimeField::Nanosecond),
-                ))),
+                IntervalUnit::MonthDayNano => SqlType::Interval(
+                    Some((DateTimeField::Month, Some(DateTimeField::Nanosecond))),
+                    None,
+                ),
             },
 
             // XXX: things get tricky here and conversions don't really work well yet
@@ -857,22 +1727,35 @@ impl SqlType {
             | DataType::LargeList(_)
             | DataType::ListView(_)
             | DataType::LargeListView(_) => SqlType::Array(None), // XXX
-            DataType::FixedSizeList(_, _) => SqlType::Other("ARRAY".to_string()),
+            // SQL arrays don't have a fixed length, so the dimension is dropped
+            DataType::FixedSizeList(field, _) => {
+                let inner = Self::_from_arrow_type(backend, field.data_type());
+                SqlType::Array(Some(Box::new(inner)))
+            }
             DataType::Struct(fields) => {
                 let mut sql_fields = Vec::with_capacity(fields.len());
                 for field in fields {
-                    let sql_type = Self::_from_arrow_type(backend, field.data_type());
+                    let sql_type = Self::_from_arrow_field(backend, field);
                     let nullable = field.is_nullable();
-                    // XXX: this is not necessarily correct, field names might contain
-                    // quote characters that need to be escaped (meaning they should exist
-                    // in a Ident::Unquoted). But we don't have that information here.
-                    let name = Ident::Plain(field.name().clone());
+                    // Arrow field names are raw, so quote the ones that can't be
+                    // written as plain identifiers on this backend
+                    let name = Ident::new(field.name().clone(), backend);
                     sql_fields.push(StructField::new(name, sql_type, nullable));
                 }
                 SqlType::Struct(Some(sql_fields))
             }
             DataType::Union(..) => SqlType::Other("UNION".to_string()),
-            DataType::Map(..) => SqlType::Map(None), // TODO: handle key/value types
+            // Sorted and unsorted maps are the same SQL type
+            DataType::Map(entries, _sorted) => match entries.data_type() {
+                // The entries of an Arrow map are a struct with the key and the
+                // value fields (in that order). Their names are not standardized.
+                DataType::Struct(kv) if kv.len() == 2 => {
+                    let key_type = Self::_from_arrow_type(backend, kv[0].data_type());
+                    let value_type = Self::_from_arrow_type(backend, kv[1].data_type());
+                    SqlType::Map(Some((Box::new(key_type), Box::new(value_type))))
+                }
+                _ => SqlType::Map(None),
+            },
             DataType::Dictionary(_, value_type) => Self::_from_arrow_type(backend, value_type),
             DataType::RunEndEncoded(_, values) => {
                 Self::_from_arrow_type(backend, values.as_ref().data_type())
@@ -880,6 +1763,22 @@ impl SqlType {
         }
     }
 
+    /// Like [SqlType::_from_arrow_type], but also returns whether the values
+    /// are dictionary-encoded, which some warehouses can store as an enum or
+    /// category type.
+    ///
+    /// The SQL type is always the type of the dictionary values.
+    pub fn from_arrow_type_with_encoding(
+        backend: Backend,
+        data_type: &DataType,
+    ) -> (SqlType, bool) {
+        let dictionary_encoded = matches!(data_type, DataType::Dictionary(..));
+        (
+            Self::_from_arrow_type(backend, data_type),
+            dictionary_encoded,
+        )
+    }
+
     /// DON'T USE THIS. Try to approximate the best Arrow [DataType] for this SQL type.
     ///
     /// Going from SQL types to Arrow types is lossy because SQL types are more expressive
@@ -1017,7 +1916,7 @@ impl SqlType {
             // "NUMBER" in Snowflake is an alias for "DECIMAL(38,0)"
             (Snowflake, Numeric(None) | BigNumeric(None)) => DataType::Decimal128(38, 0),
 
-            (Snowflake, DateTime) => arrow_timestamp(Some(9), None),
+            (Snowflake, DateTime { precision }) => arrow_timestamp(precision.or(Some(9)), None),
             // }}}
 
             // BigQuery {{{
@@ -1027,7 +1926,7 @@ impl SqlType {
 
             // BigQuery's DATETIME has microsecond precision
             // https://cloud.google.com/bigquery/docs/reference/standard-sql/data-types#datetime_type
-            (BigQuery, DateTime) => arrow_timestamp(Some(6), None),
+            (BigQuery, DateTime { .. }) => arrow_timestamp(Some(6), None),
 
             // BigQuery's TIME always has microsecond precision and no time zone
             // https://cloud.google.com/bigquery/docs/reference/standard-sql/data-types#time_type
@@ -1072,15 +1971,33 @@ impl SqlType {
                 DataType::Decimal128(38, 0)
             }
             // }}}
+
+            // SQL Server {{{
+            // The default precision is 18 and the default scale is 0
+            // https://learn.microsoft.com/en-us/sql/t-sql/data-types/decimal-and-numeric-transact-sql
+            (MSSQL, Numeric(None)) => DataType::Decimal128(18, 0),
+            // SQL Server's TINYINT is unsigned (0 to 255)
+            (MSSQL, TinyInt) => DataType::UInt8,
+            // }}}
             (_, TinyInt) => DataType::Int8,
             (_, SmallInt) => DataType::Int16,
             (_, Integer) => DataType::Int32,
             (_, BigInt) => DataType::Int64,
+            (ClickHouse | Generic { .. }, UnsignedInteger(bits)) => match bits {
+                0..=8 => DataType::UInt8,
+                9..=16 => DataType::UInt16,
+                17..=32 => DataType::UInt32,
+                _ => DataType::UInt64,
+            },
+            (_, UnsignedInteger(bits)) => {
+                SqlType::signed_for_unsigned(*bits).pick_best_arrow_type(backend)
+            }
 
             (_, Real) => DataType::Float32,
             (_, Float(_)) => DataType::Float32,
             (_, Double) => DataType::Float64,
 
+            // NUMERIC(p) means NUMERIC(p, 0) according to the SQL standard
             (_, Numeric(Some((p, s))) | BigNumeric(Some((p, s)))) => {
                 let (p, s) = (*p, s.unwrap_or(0));
                 if p <= 38 {
@@ -1094,7 +2011,7 @@ impl SqlType {
                 unimplemented!("defaults for DECIMAL on {backend:?}")
             }
 
-            (_, Char(_)) => DataType::Utf8,
+            (_, Char(..)) => DataType::Utf8,
             (_, Varchar(..)) => DataType::Utf8,
             (_, Text) => DataType::Utf8,
             (_, Clob) => DataType::Utf8,
@@ -1127,6 +2044,11 @@ impl SqlType {
                     // TIME's default precision on PostgreSQL is 6 (microseconds)
                     // https://www.postgresql.org/docs/current/datatype-datetime.html
                     (Postgres | Salesforce, None) => TimeUnit::Microsecond,
+                    // TIME's default precision on SQL Server is 7 (100 nanoseconds)
+                    // https://learn.microsoft.com/en-us/sql/t-sql/data-types/time-transact-sql
+                    (MSSQL, None) => TimeUnit::Nanosecond,
+                    // ClickHouse Time has a precision of seconds (Time64 carries one)
+                    (ClickHouse, None) => TimeUnit::Second,
                     (Generic { .. }, None) => {
                         // we pick microseconds as a reasonable default
                         TimeUnit::Microsecond
@@ -1147,6 +2069,13 @@ impl SqlType {
                 match (backend, time_zone_spec) {
                     (_, Local) => arrow_timestamp_with_local_tz(*precision),
                     (_, With) => arrow_timestamp_tz(*precision),
+                    // Arrow time zones can be fixed offsets
+                    (_, Offset(minutes)) => {
+                        arrow_timestamp(*precision, Some(format_utc_offset(*minutes).into()))
+                    }
+                    (_, Named(time_zone)) => {
+                        arrow_timestamp(*precision, Some(time_zone.as_str().into()))
+                    }
 
                     // Databricks TIMESTAMP and TIMESTAMP_LTZ are both local-tz timestamps
                     (Databricks, Without | Unspecified) => {
@@ -1160,9 +2089,9 @@ impl SqlType {
                 }
             }
             // A DATETIME is a timestamp without time zone information
-            (backend, DateTime) => DataType::Timestamp(default_time_unit(backend), None),
+            (_, DateTime { precision }) => arrow_timestamp(*precision, None),
 
-            (backend, Interval(fields)) => {
+            (backend, Interval(fields, _)) => {
                 use DateTimeField::*;
                 use IntervalUnit::*;
                 let interval_unit = match backend {
@@ -1259,6 +2188,8 @@ impl SqlType {
                     }
                     BigQuery | Postgres => MonthDayNano, // MonthDayNano is exactly what BQ and PG use internally
                     Salesforce => MonthDayNano,          // Salesforce seems to follow PostgreSQL
+                    MSSQL => MonthDayNano,               // SQL Server has no INTERVAL type
+                    ClickHouse => MonthDayNano,          // Interval types have a single unit
                     Generic { .. } => MonthDayNano,      // Reasonable default
                 };
                 DataType::Interval(interval_unit)
@@ -1266,9 +2197,17 @@ impl SqlType {
 
             (_, Json) => DataType::Utf8,
             (_, Jsonb) => unimplemented!("{}", self.to_string(backend)),
-            (_, Geometry) => unimplemented!("{}", self.to_string(backend)),
-            (_, Geography) => DataType::Utf8,
-            (_, Array(Some(inner_sql_type))) => {
+            // Geospatial values are stored in their text form and tagged with the
+            // `geoarrow.wkt` extension name (and the CRS) in the field metadata
+            (_, Geometry(_) | Geography(_)) => DataType::Utf8,
+            // SQL Server returns UNIQUEIDENTIFIER values as 16-byte binaries, like
+            // the canonical `arrow.uuid` extension type, other drivers return strings
+            (MSSQL, Uuid) => DataType::FixedSizeBinary(16),
+            (_, Uuid) => DataType::Utf8,
+            // Network addresses are stored in their text form and tagged with the
+            // `arrow.network_address` extension name in the field metadata
+            (_, Inet | Cidr) => DataType::Utf8,
+            (_, Array(Some(inner_sql_type)) | NonNullArray(inner_sql_type)) => {
                 let inner_sql_type_string = inner_sql_type.to_string(backend);
                 let inner_ty = inner_sql_type.pick_best_arrow_type(backend);
                 let inner_metadata = {
@@ -1277,14 +2216,17 @@ impl SqlType {
                         metadata_sql_type_key(backend).to_string(),
                         inner_sql_type_string,
                     );
+                    inner_sql_type.insert_extension_metadata(backend, &mut metadata);
                     metadata
                 };
-                let inner_field = Field::new("item", inner_ty, true).with_metadata(inner_metadata);
+                let inner_nullable = matches!(self, Array(_));
+                let inner_field =
+                    Field::new("item", inner_ty, inner_nullable).with_metadata(inner_metadata);
                 DataType::List(Arc::new(inner_field))
             }
             (_, Array(None)) => DataType::List(Arc::new(Field::new("item", DataType::Utf8, true))),
-            (_, Struct(fields)) => {
-                let arrow_fields = match fields {
+            (_, Struct(_) | Row(_)) => {
+                let arrow_fields = match self.fields() {
                     Some(struct_fields) => {
                         let arrow_fields_vec = struct_fields
                             .iter()
@@ -1318,6 +2260,7 @@ impl SqlType {
                                         if let Some(tok) = comment_tok {
                                             metadata.insert("comment".to_string(), tok.clone());
                                         }
+                                        sql_type.insert_extension_metadata(backend, &mut metadata);
                                         metadata
                                     };
                                     Field::new(name, inner_ty, *nullable).with_metadata(metadata)
@@ -1372,24 +2315,63 @@ impl SqlType {
 // reading we check all of them in order to be compatible with existing
 // schemas that might have been written using different metadata keys.
 const POSTGRES_KEYS: [&str; 2] = ["POSTGRES:type", "type_text"];
+// Salesforce schemas used to be written with the PostgreSQL key, keep reading it
+const SALESFORCE_KEYS: [&str; 3] = ["SALESFORCE:type", "type_text", "POSTGRES:type"];
 const SNOWFLAKE_KEYS: [&str; 2] = ["SNOWFLAKE:type", "type_text"];
 const BIGQUERY_KEYS: [&str; 4] = ["BIGQUERY:type", "type_text", "Type", "type"];
 const DATABRICKS_KEYS: [&str; 2] = ["DBX:type", "type_text"];
 const REDSHIFT_KEYS: [&str; 2] = ["REDSHIFT:type", "type_text"];
+const MSSQL_KEYS: [&str; 2] = ["MSSQL:type", "type_text"];
+const CLICKHOUSE_KEYS: [&str; 2] = ["CLICKHOUSE:type", "type_text"];
 const GENERIC_KEYS: [&str; 2] = ["SQL:type", "type_text"];
 
 fn metadata_type_candidate_keys(backend: Backend) -> &'static [&'static str] {
     match backend {
-        Backend::Postgres | Backend::Salesforce => &POSTGRES_KEYS,
+        Backend::Postgres => &POSTGRES_KEYS,
+        Backend::Salesforce => &SALESFORCE_KEYS,
         Backend::Snowflake => &SNOWFLAKE_KEYS,
         Backend::BigQuery => &BIGQUERY_KEYS,
         Backend::Databricks => &DATABRICKS_KEYS,
         Backend::Redshift | Backend::RedshiftODBC => &REDSHIFT_KEYS,
         Backend::DatabricksODBC => &DATABRICKS_KEYS,
+        Backend::MSSQL => &MSSQL_KEYS,
+        Backend::ClickHouse => &CLICKHOUSE_KEYS,
         Backend::Generic { .. } => &GENERIC_KEYS,
     }
 }
 
+// Keys of the Arrow extension type mechanism [1] and the extension names we use.
+//
+// [1] https://arrow.apache.org/docs/format/Columnar.html#format-metadata-extension-types
+const ARROW_EXTENSION_NAME_KEY: &str = "ARROW:extension:name";
+const ARROW_EXTENSION_METADATA_KEY: &str = "ARROW:extension:metadata";
+const NETWORK_ADDRESS_EXTENSION_NAME: &str = "arrow.network_address";
+// https://geoarrow.org/extension-types
+const GEOARROW_WKT_EXTENSION_NAME: &str = "geoarrow.wkt";
+
+/// Snowflake GEOGRAPHY values always use WGS 84.
+const SNOWFLAKE_GEOGRAPHY_SRID: u32 = 4326;
+
+/// Render the GeoArrow extension metadata (a JSON object) for the given SRID.
+fn geoarrow_metadata(srid: Option<u32>, spherical_edges: bool) -> String {
+    let mut entries = Vec::new();
+    if let Some(srid) = srid {
+        entries.push(format!(r#""crs":"EPSG:{srid}""#));
+    }
+    if spherical_edges {
+        entries.push(r#""edges":"spherical""#.to_string());
+    }
+    format!("{{{}}}", entries.join(","))
+}
+
+/// Extract the SRID from the `"crs":"EPSG:<srid>"` entry of GeoArrow metadata.
+fn srid_from_geoarrow_metadata(metadata: &str) -> Option<u32> {
+    let start = metadata.find("\"EPSG:")? + "\"EPSG:".len();
+    let digits = &metadata[start..];
+    let end = digits.find(|c: char| !c.is_ascii_digit())?;
+    digits[..end].parse().ok()
+}
+
 pub fn metadata_sql_type_key(backend: Backend) -> &'static str {
     metadata_type_candidate_keys(backend)[0]
 }
@@ -1412,6 +2394,16 @@ enum ParseError<'source> {
     ParseIntError(ParseIntError),
     UnclosedQuote(char),
     ExpectedDateTimeField,
+    InvalidUnicodeEscape(String),
+    NestingTooDeep {
+        limit: usize,
+    },
+    /// `MAP<>` or a map missing its key or value type.
+    EmptyMapType,
+    /// `ARRAY<>`, `ARRAY<,>` or an array with more than one element type.
+    MalformedArrayType,
+    /// A `(+|-)HH:MM` offset beyond 14 hours or with 60 minutes or more.
+    InvalidUtcOffset,
 }
 
 impl Error for ParseError<'_> {}
@@ -1429,6 +2421,23 @@ impl fmt::Display for ParseError<'_> {
                     "expected a date/time field (e.g. YEAR, DAY, SECOND, etc.)"
                 )
             }
+            ParseError::InvalidUnicodeEscape(msg) => {
+                write!(f, "invalid Unicode escape in identifier: {msg}")
+            }
+            ParseError::NestingTooDeep { limit } => {
+                write!(f, "type is nested more than {limit} levels deep")
+            }
+            ParseError::EmptyMapType => write!(
+                f,
+                "MAP needs a key and a value type (e.g. MAP<STRING, INT>)"
+            ),
+            ParseError::MalformedArrayType => {
+                write!(f, "ARRAY needs exactly one element type (e.g. ARRAY<INT>)")
+            }
+            ParseError::InvalidUtcOffset => write!(
+                f,
+                "UTC offsets must be between -14:00 and +14:00 (e.g. +05:30)"
+            ),
         }
     }
 }
@@ -1448,19 +2457,23 @@ impl From<ParseIntError> for ParseError<'_> {
 fn word2ident<'source>(word: String, backend: Backend) -> Result<Ident, ParseError<'source>> {
     let mut bytes = word.bytes();
     let first_byte = bytes.next().ok_or(ParseError::UnexpectedEndOfInput)?;
-    let is_quoted = [b'\'', b'"', b'`'].contains(&first_byte);
+    let is_quoted = [b'\'', b'"', b'`'].contains(&first_byte)
+        || (first_byte == b'[' && backend == Backend::MSSQL);
 
-    // TODO: handle the U&"..." quoted identifiers in PostgreSQL
+    if is_unicode_escaped_ident(&word, backend) {
+        return unicode_word2ident(&word, '\\');
+    }
 
     if is_quoted {
-        // If the first byte is a quote, the last byte must be the same quote.
+        // If the first byte is a quote, the last byte must be the same quote
+        // (or the matching closing bracket).
         // This is not enough to validate the quoted identifier, but it's a necessary
         // condition. More thorough validation is done in _unescape_quoted_ident.
         //
         // For instance, "abc"" passes this check, but is not a valid quoted identifier
         // because the last "" are a escaped "" and the closing quote is missing.
         let open_ended = match bytes.next_back() {
-            Some(b) => b != first_byte,
+            Some(b) => b != closing_quote(first_byte),
             None => true,
         };
         if open_ended {
@@ -1475,11 +2488,96 @@ fn word2ident<'source>(word: String, backend: Backend) -> Result<Ident, ParseErr
     }
 }
 
+/// Returns the closing quote byte for an opening quote byte.
+fn closing_quote(opening: u8) -> u8 {
+    match opening {
+        b'[' => b']',
+        quote => quote,
+    }
+}
+
+/// Returns true if the word is a `U&"..."` identifier and the backend supports them.
+fn is_unicode_escaped_ident(word: &str, backend: Backend) -> bool {
+    use Backend::*;
+    matches!(backend, Postgres | Redshift | RedshiftODBC)
+        && word.len() >= 3
+        && word.as_bytes()[..3].eq_ignore_ascii_case(b"U&\"")
+}
+
+/// Converts a `U&"..."` word to an identifier by decoding the Unicode escapes.
+///
+/// Escapes are either `\xxxx` (4 hex digits) or `\+xxxxxx` (6 hex digits), and
+/// a doubled escape character stands for itself. The escape character can be
+/// changed with a `UESCAPE` clause after the identifier [1].
+///
+/// The resulting [Ident] holds the decoded string and is quoted with plain double
+/// quotes, because the decoded string doesn't need any Unicode escapes to be rendered.
+///
+/// [1] https://www.postgresql.org/docs/current/sql-syntax-lexical.html#SQL-SYNTAX-IDENTIFIERS-UESCAPE
+fn unicode_word2ident<'source>(word: &str, escape: char) -> Result<Ident, ParseError<'source>> {
+    // U&"" is the shortest valid (although empty) identifier
+    if word.len() < 4 || !word.ends_with('"') {
+        return Err(ParseError::UnclosedQuote('"'));
+    }
+    let inner = word[3..word.len() - 1].replace("\"\"", "\"");
+
+    /// Reads the code point that comes after the escape character.
+    fn code_point<'source>(
+        chars: &mut std::str::Chars,
+        escape: char,
+    ) -> Result<u32, ParseError<'source>> {
+        let n = if chars.clone().next() == Some('+') {
+            chars.next();
+            6
+        } else {
+            4
+        };
+        let digits: String = chars.take(n).collect();
+        if digits.len() != n || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            let msg = format!("expected {n} hex digits after '{escape}'");
+            return Err(ParseError::InvalidUnicodeEscape(msg));
+        }
+        Ok(u32::from_str_radix(&digits, 16).unwrap())
+    }
+
+    let mut out = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != escape {
+            out.push(c);
+            continue;
+        }
+        if chars.clone().next() == Some(escape) {
+            chars.next();
+            out.push(escape);
+            continue;
+        }
+        let mut code = code_point(&mut chars, escape)?;
+        // UTF-16 surrogate pairs can be written as two consecutive escapes
+        if (0xD800..0xDC00).contains(&code) {
+            let low = match chars.next() {
+                Some(c) if c == escape => code_point(&mut chars, escape)?,
+                _ => 0,
+            };
+            if !(0xDC00..0xE000).contains(&low) {
+                let msg = format!("invalid surrogate pair starting with {code:04X}");
+                return Err(ParseError::InvalidUnicodeEscape(msg));
+            }
+            code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+        }
+        let c = char::from_u32(code).ok_or_else(|| {
+            ParseError::InvalidUnicodeEscape(format!("invalid code point {code:06X}"))
+        })?;
+        out.push(c);
+    }
+    Ok(Ident::Unquoted(QuotingStyle::Double, out))
+}
+
 /// Unescape a quoted identifier based on the backend rules.
 ///
 /// PRE-CONDITIONS:
-/// - `quote` is one of: `"`, `'`, or `` ` ``
-/// - `word` is quoted with the same quote character at the start and end.
+/// - `quote` is one of: `"`, `'`, `` ` ``, or `[`
+/// - `word` starts with `quote` and ends with the matching closing quote.
 fn _unescape_quoted_ident<'source>(
     word: &str,
     quote: u8,
@@ -1489,7 +2587,7 @@ fn _unescape_quoted_ident<'source>(
 
     debug_assert!(word.len() >= 2);
     debug_assert!(word.as_bytes()[0] == quote);
-    debug_assert!(word.as_bytes()[word.len() - 1] == quote);
+    debug_assert!(word.as_bytes()[word.len() - 1] == closing_quote(quote));
 
     let inner = &word[1..word.len() - 1];
     // TODO: review all the ident escaping rules for different backends here
@@ -1502,22 +2600,44 @@ fn _unescape_quoted_ident<'source>(
             // In SQL, single quotes are escaped by doubling them
             inner.replace("''", "'")
         }
+        (BigQuery | Databricks | DatabricksODBC | ClickHouse | Generic { .. }, b'`') => {
+            // Backticks are escaped by doubling them (also in MySQL)
+            inner.replace("``", "`")
+        }
+        (MSSQL, b'[') => {
+            // In SQL Server, a closing bracket is escaped by doubling it
+            inner.replace("]]", "]")
+        }
         _ => inner.to_string(),
     };
     Ok(unescaped_string)
 }
 
+/// The default bound on the [depth](SqlType::depth) of a parsed type, which
+/// keeps the recursive parser from overflowing the stack on hostile input.
+pub const MAX_NESTING_DEPTH: usize = 128;
+
 struct Parser<'source> {
     tokenizer: Tokenizer<'source>,
+    /// The number of types currently being parsed (one per level of nesting).
+    depth: usize,
+    nesting_limit: usize,
 }
 
 impl<'source> Parser<'source> {
     pub fn new(input: &'source str) -> Self {
         Parser {
             tokenizer: Tokenizer::new(input),
+            depth: 0,
+            nesting_limit: MAX_NESTING_DEPTH,
         }
     }
 
+    pub fn with_nesting_limit(mut self, limit: usize) -> Self {
+        self.nesting_limit = limit;
+        self
+    }
+
     // Basic token operations
 
     fn next(&mut self) -> Result<Token<'source>, ParseError<'source>> {
@@ -1543,6 +2663,23 @@ impl<'source> Parser<'source> {
         self.match_(Token::Word(word))
     }
 
+    /// Check if the next token is `tok` without consuming it.
+    fn peek_is(&mut self, tok: Token<'source>) -> bool {
+        self.tokenizer.peek2().0.is_some_and(|next| next == tok)
+    }
+
+    /// Consume the next two words if and only if they are `first` and `second`.
+    fn match_words(&mut self, first: &'source str, second: &'source str) -> bool {
+        match self.tokenizer.peek2() {
+            (Some(Token::Word(a)), Some(Token::Word(b))) if eqi(a, first) && eqi(b, second) => {
+                self.tokenizer.next();
+                self.tokenizer.next();
+                true
+            }
+            _ => false,
+        }
+    }
+
     fn next_int<T>(&mut self) -> Result<T, ParseError<'source>>
     where
         T: std::str::FromStr<Err = ParseIntError>,
@@ -1572,6 +2709,24 @@ impl<'source> Parser<'source> {
         }
     }
 
+    /// Parse optional parenthesized length that can also be `(MAX)` like in SQL Server.
+    ///
+    /// `(MAX)` is returned as `None` because that is how unbounded lengths are
+    /// represented in [SqlType].
+    fn max_length(&mut self) -> Result<Option<usize>, ParseError<'source>> {
+        if self.match_(Token::LParen) {
+            let value = if self.match_word("MAX") {
+                None
+            } else {
+                Some(self.next_int::<usize>()?)
+            };
+            self.expect(Token::RParen)?;
+            Ok(value)
+        } else {
+            Ok(None)
+        }
+    }
+
     fn precision_and_scale(&mut self) -> Result<Option<(u8, Option<i8>)>, ParseError<'source>> {
         if self.match_(Token::LParen) {
             let precision = self.next_int::<u8>()?;
@@ -1601,6 +2756,24 @@ impl<'source> Parser<'source> {
             self.expect(Token::Word("TIME"))?;
             self.expect(Token::Word("ZONE"))?;
             Ok(TimeZoneSpec::Without)
+        } else if let Some(hours) = self.tokenizer.peek_and_then(|tok| match tok {
+            Token::Word(w) if w.starts_with(['+', '-']) && w.len() > 1 => Some(w),
+            _ => None,
+        }) {
+            // a fixed offset from UTC: (+|-)HH:MM
+            self.expect(Token::Colon)?;
+            let negative = hours.starts_with('-');
+            let hours = hours[1..].parse::<u16>()?;
+            let minutes = self.next_int::<u16>()?;
+            if hours > 14 || minutes >= 60 || (hours == 14 && minutes > 0) {
+                return Err(ParseError::InvalidUtcOffset);
+            }
+            let offset = (hours * 60 + minutes) as i16;
+            Ok(TimeZoneSpec::Offset(if negative {
+                -offset
+            } else {
+                offset
+            }))
         } else {
             Ok(TimeZoneSpec::Unspecified)
         }
@@ -1637,20 +2810,31 @@ impl<'source> Parser<'source> {
         })
     }
 
+    /// Parse `<start field> [ '(' leading precision ')' ] [ TO <end field> ]`.
+    ///
+    /// A precision after a `SECOND` start field is left for the caller, as
+    /// it's the precision of the fractional seconds.
+    #[allow(clippy::type_complexity)]
     fn interval_qualifier(
         &mut self,
-    ) -> Result<Option<(DateTimeField, Option<DateTimeField>)>, ParseError<'source>> {
+    ) -> Result<Option<(DateTimeField, Option<u8>, Option<DateTimeField>)>, ParseError<'source>>
+    {
         if let Some(start) = self.datetime_field() {
+            let leading_precision = if matches!(start, DateTimeField::Second) {
+                None
+            } else {
+                self.precision::<u8>()?
+            };
             if self.match_word("TO") {
                 let end = self.datetime_field();
                 if end.is_some() {
                     // XXX: validate end is higher resolution than start unit?
-                    return Ok(Some((start, end)));
+                    return Ok(Some((start, leading_precision, end)));
                 } else {
                     return Err(ParseError::ExpectedDateTimeField);
                 }
             }
-            Ok(Some((start, None)))
+            Ok(Some((start, leading_precision, None)))
         } else {
             Ok(None)
         }
@@ -1660,23 +2844,162 @@ impl<'source> Parser<'source> {
         if self.match_word("NOT") {
             self.expect(Token::Word("NULL"))?;
             Ok(Some(false))
-        } else if self.match_word("NULLABLE") {
+        } else if self.match_word("NULLABLE") || self.match_word("NULL") {
             Ok(Some(true))
         } else {
             Ok(None)
         }
     }
 
+    /// Parse an optional `DEFAULT <expr>` clause and return the raw source of `<expr>`.
+    ///
+    /// The expression is not interpreted: it extends until the end of the input or
+    /// a nullability constraint outside of parentheses, so `DEFAULT NOW() NOT NULL`
+    /// yields `NOW()`. A leading `NULL` is always part of the expression.
+    fn default_expr(&mut self) -> Result<Option<&'source str>, ParseError<'source>> {
+        if !self.match_word("DEFAULT") {
+            return Ok(None);
+        }
+        let start = self.tokenizer.position();
+        let mut end = start;
+        let mut depth = 0usize;
+        while let Some(()) = self.tokenizer.peek_and_then(|tok| match tok {
+            Token::Word(w)
+                if end > start
+                    && depth == 0
+                    && (eqi(w, "NOT") || eqi(w, "NULL") || eqi(w, "NULLABLE")) =>
+            {
+                None
+            }
+            Token::LParen => {
+                depth += 1;
+                Some(())
+            }
+            Token::RParen => {
+                depth = depth.saturating_sub(1);
+                Some(())
+            }
+            _ => Some(()),
+        }) {
+            end = self.tokenizer.position();
+        }
+        if end == start {
+            return Err(ParseError::UnexpectedEndOfInput);
+        }
+        Ok(Some(self.tokenizer.source(start, end).trim()))
+    }
+
+    /// Capture the source of a type we don't model, starting at the word `first`,
+    /// verbatim: e.g. `RASTER(Polygon, 4326) extra`.
+    ///
+    /// Stops before a `[NOT] NULL`, `NULLABLE` or `DEFAULT` clause, or before a
+    /// `,` or closing bracket that belongs to an enclosing type (e.g. a struct
+    /// field list). Brackets inside the type must be balanced.
+    fn other_type(&mut self, first: &'source str) -> String {
+        let start = self.tokenizer.position();
+        let mut end = start;
+        let mut depth = 0usize;
+        while let Some(()) = self.tokenizer.peek_and_then(|tok| match tok {
+            Token::Word(w)
+                if depth == 0
+                    && (eqi(w, "NOT")
+                        || eqi(w, "NULL")
+                        || eqi(w, "NULLABLE")
+                        || eqi(w, "DEFAULT")) =>
+            {
+                None
+            }
+            Token::LParen | Token::LBracket | Token::LAngle => {
+                depth += 1;
+                Some(())
+            }
+            Token::RParen | Token::RBracket | Token::RAngle | Token::Comma if depth == 0 => None,
+            Token::RParen | Token::RBracket | Token::RAngle => {
+                depth -= 1;
+                Some(())
+            }
+            _ => Some(()),
+        }) {
+            end = self.tokenizer.position();
+        }
+        format!("{first}{}", self.tokenizer.source(start, end).trim_end())
+    }
+
+    /// Consume the `UNSIGNED` that MySQL allows after an integer type.
+    ///
+    /// The unsigned type is rendered back as such on [Backend::Generic], other
+    /// backends get the narrowest signed type that holds its whole range.
+    fn maybe_unsigned(&mut self, sql_type: SqlType) -> SqlType {
+        if !self.match_word("UNSIGNED") {
+            return sql_type;
+        }
+        match sql_type {
+            SqlType::TinyInt => SqlType::UnsignedInteger(8),
+            SqlType::SmallInt => SqlType::UnsignedInteger(16),
+            SqlType::Integer => SqlType::UnsignedInteger(32),
+            SqlType::BigInt => SqlType::UnsignedInteger(64),
+            sql_type => sql_type,
+        }
+    }
+
     /// Parse an identifier, which can be a quoted or unquoted word.
     #[allow(dead_code)]
     fn identifier(&mut self, backend: Backend) -> Result<Ident, ParseError<'source>> {
-        let tok = self.next()?;
+        let tok = self.next_ident_token(backend)?;
         match tok {
-            Token::Word(w) => word2ident(w.to_string(), backend),
+            Token::Word(w) => self.word_to_ident(w, backend),
             _ => Err(ParseError::Unexpected(tok)),
         }
     }
 
+    /// Like [Parser::next], but also recognizes `[...]` quoted identifiers on
+    /// backends that support them.
+    fn next_ident_token(
+        &mut self,
+        backend: Backend,
+    ) -> Result<Token<'source>, ParseError<'source>> {
+        if backend == Backend::MSSQL
+            && let Some(tok) = self.tokenizer.next_bracket_quoted()
+        {
+            return Ok(tok);
+        }
+        self.next()
+    }
+
+    /// Like [word2ident], but also consumes the `UESCAPE 'c'` clause that can
+    /// follow a `U&"..."` identifier.
+    fn word_to_ident(
+        &mut self,
+        word: &'source str,
+        backend: Backend,
+    ) -> Result<Ident, ParseError<'source>> {
+        if is_unicode_escaped_ident(word, backend) && self.match_word("UESCAPE") {
+            let escape = self.uescape_char()?;
+            return unicode_word2ident(word, escape);
+        }
+        word2ident(word.to_string(), backend)
+    }
+
+    /// Parse the `'c'` string literal that follows `UESCAPE`.
+    fn uescape_char(&mut self) -> Result<char, ParseError<'source>> {
+        let tok = self.next()?;
+        let escape = match tok {
+            Token::Word(w) if w.len() >= 2 && w.starts_with('\'') && w.ends_with('\'') => {
+                let mut chars = w[1..w.len() - 1].chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => c,
+                    _ => return Err(ParseError::Unexpected(tok)),
+                }
+            }
+            _ => return Err(ParseError::Unexpected(tok)),
+        };
+        if escape.is_ascii_hexdigit() || escape.is_whitespace() || "+'\"".contains(escape) {
+            let msg = format!("'{escape}' can't be used as an escape character");
+            return Err(ParseError::InvalidUnicodeEscape(msg));
+        }
+        Ok(escape)
+    }
+
     /// Parse a string literal.
     ///
     /// Currently used only on Databricks struct field comments (after COMMENT).
@@ -1701,16 +3024,29 @@ impl<'source> Parser<'source> {
         Ok(tok.to_string())
     }
 
+    /// Parse the `CHARACTER SET` and `COLLATE` clauses of a string type, in
+    /// either order (MySQL allows both).
     fn string_attrs(&mut self, backend: Backend) -> Result<StringAttrs, ParseError<'source>> {
+        let mut charset = None;
         let mut collate_spec = None;
         loop {
+            if charset.is_none()
+                && (self.match_words("CHARACTER", "SET") || self.match_word("CHARSET"))
+            {
+                charset = Some(self.next()?.to_string());
+                continue;
+            }
             if collate_spec.is_none() && self.match_word("COLLATE") {
                 collate_spec = Some(self.collation_spec(backend)?);
                 continue;
             }
             break;
         }
-        Ok(StringAttrs { collate_spec })
+        Ok(StringAttrs {
+            charset,
+            collate_spec,
+            ..Default::default()
+        })
     }
 
     /// Parse the inner fields of a struct type after `(` or after `STRUCT<`.
@@ -1723,10 +3059,10 @@ impl<'source> Parser<'source> {
     ) -> Result<Vec<StructField>, ParseError<'source>> {
         let mut fields = Vec::new();
         loop {
-            let tok = self.next()?;
+            let tok = self.next_ident_token(backend)?;
             let name = match tok {
                 tok if tok == terminator => break,
-                Token::Word(w) => word2ident(w.to_string(), backend)?,
+                Token::Word(w) => self.word_to_ident(w, backend)?,
                 _ => {
                     let e = ParseError::Unexpected(tok);
                     return Err(e);
@@ -1797,17 +3133,174 @@ impl<'source> Parser<'source> {
         &mut self,
         backend: Backend,
     ) -> Result<(SqlType, Option<bool>), ParseError<'source>> {
+        if backend == Backend::ClickHouse && self.match_word("Nullable") {
+            // ClickHouse encodes nullability in the type itself: Nullable(T)
+            self.expect(Token::LParen)?;
+            let sql_type = self.parse_unconstrained_type(backend)?;
+            self.expect(Token::RParen)?;
+            return Ok((sql_type, Some(true)));
+        }
         let sql_type = self.parse_unconstrained_type(backend)?;
         let nullable = self.nullable()?;
+        if backend == Backend::ClickHouse {
+            // ClickHouse types are not nullable unless wrapped in Nullable(T)
+            return Ok((sql_type, nullable.or(Some(false))));
+        }
         Ok((sql_type, nullable))
     }
 
+    /// Parse the fields of a Trino `ROW` after the `(`.
+    ///
+    /// The fields are either all named (`ROW(a INTEGER, b VARCHAR)`) or all
+    /// [positional](StructField::positional) (`ROW(INTEGER, VARCHAR)`). A name
+    /// is either quoted (`ROW("a b" INTEGER)`) or followed by a type name, while
+    /// a positional field is a type followed by `,`, `)`, its parameters or the
+    /// rest of a multi-word type name (`ROW(TIMESTAMP WITH TIME ZONE)`).
+    fn row_fields(&mut self, backend: Backend) -> Result<Vec<StructField>, ParseError<'source>> {
+        match self.tokenizer.peek2() {
+            (Some(Token::Word(w)), _) if w.starts_with(['"', '`']) => {
+                return self.struct_fields(backend, Token::RParen);
+            }
+            (Some(Token::Word(first)), Some(Token::Word(second)))
+                if !continues_type_name(first, second) =>
+            {
+                return self.struct_fields(backend, Token::RParen);
+            }
+            _ => {}
+        }
+        let mut fields = Vec::new();
+        if self.match_(Token::RParen) {
+            return Ok(fields);
+        }
+        loop {
+            let (sql_type, nullable) = self.parse_constrained_type(backend)?;
+            let position = fields.len() + 1;
+            fields.push(StructField::positional(
+                position,
+                sql_type,
+                nullable.unwrap_or(true),
+            ));
+            if self.match_(Token::RParen) {
+                return Ok(fields);
+            }
+            self.expect(Token::Comma)?;
+        }
+    }
+
+    /// Parse the quoted time zone argument of a ClickHouse `DateTime`.
+    fn clickhouse_time_zone(&mut self) -> Result<String, ParseError<'source>> {
+        let tok = self.string_literal()?;
+        tok.to_string()
+            .strip_prefix('\'')
+            .and_then(|s| s.strip_suffix('\''))
+            .map(str::to_string)
+            .ok_or(ParseError::Unexpected(tok))
+    }
+
+    /// Parse the ClickHouse type names that mean something else (or nothing) in
+    /// other dialects. Returns `None` if `w` is not one of them.
+    fn clickhouse_type(&mut self, w: &'source str) -> Result<Option<SqlType>, ParseError<'source>> {
+        let backend = Backend::ClickHouse;
+        let sql_type = if eqi(w, "Int8") {
+            SqlType::TinyInt
+        } else if eqi(w, "Int16") {
+            SqlType::SmallInt
+        } else if eqi(w, "Int32") {
+            SqlType::Integer
+        } else if eqi(w, "Int64") {
+            SqlType::BigInt
+        } else if eqi(w, "UInt8") {
+            SqlType::UnsignedInteger(8)
+        } else if eqi(w, "UInt16") {
+            SqlType::UnsignedInteger(16)
+        } else if eqi(w, "UInt32") {
+            SqlType::UnsignedInteger(32)
+        } else if eqi(w, "UInt64") {
+            SqlType::UnsignedInteger(64)
+        } else if eqi(w, "Float32") {
+            SqlType::Real
+        } else if eqi(w, "Float64") {
+            SqlType::Double
+        } else if eqi(w, "FixedString") {
+            self.expect(Token::LParen)?;
+            let len = self.next_int()?;
+            self.expect(Token::RParen)?;
+            SqlType::Char(Some(len), Default::default())
+        } else if eqi(w, "Time64") {
+            let precision = self.precision()?;
+            SqlType::Time {
+                precision,
+                time_zone_spec: TimeZoneSpec::Without,
+            }
+        } else if eqi(w, "DateTime64") {
+            // DateTime64(precision [, 'time zone'])
+            let mut precision = None;
+            let mut time_zone_spec = TimeZoneSpec::Local;
+            if self.match_(Token::LParen) {
+                precision = Some(self.next_int()?);
+                if self.match_(Token::Comma) {
+                    time_zone_spec = TimeZoneSpec::Named(self.clickhouse_time_zone()?);
+                }
+                self.expect(Token::RParen)?;
+            }
+            SqlType::Timestamp {
+                precision,
+                time_zone_spec,
+            }
+        } else if eqi(w, "DateTime") && self.match_(Token::LParen) {
+            // DateTime('time zone'), without it DateTime is parsed like elsewhere
+            let time_zone = self.clickhouse_time_zone()?;
+            self.expect(Token::RParen)?;
+            SqlType::Timestamp {
+                precision: Some(0),
+                time_zone_spec: TimeZoneSpec::Named(time_zone),
+            }
+        } else if eqi(w, "Array") && self.match_(Token::LParen) {
+            let (inner_type, inner_nullable) = self.parse_constrained_type(backend)?;
+            self.expect(Token::RParen)?;
+            if inner_nullable == Some(true) {
+                SqlType::Array(Some(Box::new(inner_type)))
+            } else {
+                SqlType::NonNullArray(Box::new(inner_type))
+            }
+        } else if eqi(w, "Map") && self.match_(Token::LParen) {
+            let (key_type, _) = self.parse_constrained_type(backend)?;
+            self.expect(Token::Comma)?;
+            let (value_type, _) = self.parse_constrained_type(backend)?;
+            self.expect(Token::RParen)?;
+            SqlType::Map(Some((Box::new(key_type), Box::new(value_type))))
+        } else if eqi(w, "Tuple") && self.match_(Token::LParen) {
+            let fields = self.struct_fields(backend, Token::RParen)?;
+            SqlType::Struct(Some(fields))
+        } else if eqi(w, "Nothing") {
+            SqlType::Void
+        } else {
+            return Ok(None);
+        };
+        Ok(Some(sql_type))
+    }
+
     // External API
 
     /// Parse the SQL type and return it along with a boolean indicating if its nullable.
     fn parse(&mut self, backend: Backend) -> Result<(SqlType, bool), ParseError<'source>> {
-        let (ty, nullable) = self.parse_constrained_type(backend)?;
-        Ok((ty, nullable.unwrap_or(true)))
+        let (ty, nullable, _default) = self.parse_column_type(backend)?;
+        Ok((ty, nullable))
+    }
+
+    /// Parse the type of a column definition, which, unlike the type of a struct
+    /// field, can have a `DEFAULT <expr>` clause before or after the nullability
+    /// constraint.
+    fn parse_column_type(
+        &mut self,
+        backend: Backend,
+    ) -> Result<(SqlType, bool, Option<&'source str>), ParseError<'source>> {
+        let (ty, mut nullable) = self.parse_constrained_type(backend)?;
+        let default = self.default_expr()?;
+        if default.is_some() && nullable.is_none() {
+            nullable = self.nullable()?;
+        }
+        Ok((ty, nullable.unwrap_or(true), default))
     }
 
     fn parse_unconstrained_type(
@@ -1815,7 +3308,16 @@ impl<'source> Parser<'source> {
         backend: Backend,
     ) -> Result<SqlType, ParseError<'source>> {
         use Backend::*;
-        let mut sql_type = self.parse_inner(backend)?;
+        // every nested type recurses through here, so bound the stack depth
+        if self.depth >= self.nesting_limit {
+            return Err(ParseError::NestingTooDeep {
+                limit: self.nesting_limit,
+            });
+        }
+        self.depth += 1;
+        let sql_type = self.parse_inner(backend);
+        self.depth -= 1;
+        let mut sql_type = sql_type?;
         // postfix-[] syntax for arrays in Postgres and Generic SQL
         if matches!(backend, Postgres | Redshift | RedshiftODBC | Generic { .. }) {
             while self.match_(Token::LBracket) {
@@ -1869,28 +3371,35 @@ impl<'source> Parser<'source> {
                 return Err(ParseError::Unexpected(tok));
             }
             Token::Word(w) => {
-                if eqi(w, "BOOLEAN") || eqi(w, "BOOL") {
+                if backend == ClickHouse
+                    && let Some(sql_type) = self.clickhouse_type(w)?
+                {
+                    sql_type
+                } else if eqi(w, "BOOLEAN") || eqi(w, "BOOL") {
+                    SqlType::Boolean
+                } else if eqi(w, "BIT") && backend == MSSQL {
+                    // BIT is a bit string type in PostgreSQL, but a boolean in SQL Server
                     SqlType::Boolean
                 } else if eqi(w, "TINYINT") || eqi(w, "BYTEINT") {
-                    SqlType::TinyInt
+                    self.maybe_unsigned(SqlType::TinyInt)
                 } else if eqi(w, "SMALLINT")
                     || (eqi(w, "INT2") || eqi(w, "SMALLSERIAL") || eqi(w, "SERIAL2"))
                 {
-                    SqlType::SmallInt
+                    self.maybe_unsigned(SqlType::SmallInt)
                 } else if eqi(w, "INTEGER")
                     || eqi(w, "INT")
                     || eqi(w, "INT4")
                     || eqi(w, "SERIAL")
                     || eqi(w, "SERIAL4")
                 {
-                    SqlType::Integer
+                    self.maybe_unsigned(SqlType::Integer)
                 } else if eqi(w, "BIGINT")
                     || eqi(w, "INT64")
                     || eqi(w, "INT8")
                     || eqi(w, "BIGSERIAL")
                     || eqi(w, "SERIAL8")
                 {
-                    SqlType::BigInt
+                    self.maybe_unsigned(SqlType::BigInt)
                 } else if eqi(w, "REAL") {
                     SqlType::Real
                 } else if eqi(w, "FLOAT") {
@@ -1928,32 +3437,35 @@ impl<'source> Parser<'source> {
                     let precision_and_scale = self.precision_and_scale()?;
                     SqlType::BigNumeric(precision_and_scale)
                 } else if eqi(w, "CHAR") || eqi(w, "CHARACTER") || eqi(w, "NCHAR") {
-                    if self.match_word("LARGE") {
-                        self.expect(Token::Word("OBJECT"))?;
+                    if self.match_words("LARGE", "OBJECT") {
                         SqlType::Clob // CHARACTER LARGE OBJECT
                     } else {
+                        let national = eqi(w, "NCHAR");
                         let varying = self.match_word("VARYING");
                         let len = self.precision()?;
+                        let mut attrs = self.string_attrs(backend)?;
+                        attrs.national = national;
                         if varying {
-                            let attrs = self.string_attrs(backend)?;
                             SqlType::Varchar(len, attrs)
                         } else {
-                            SqlType::Char(len)
+                            SqlType::Char(len, attrs)
                         }
                     }
                 } else if eqi(w, "VARCHAR") || eqi(w, "NVARCHAR") {
-                    let len = self.precision()?;
-                    let attrs = self.string_attrs(backend)?;
+                    let len = self.max_length()?;
+                    let mut attrs = self.string_attrs(backend)?;
+                    attrs.national = eqi(w, "NVARCHAR");
                     SqlType::Varchar(len, attrs)
                 } else if eqi(w, "NATIONAL") {
                     self.expect(Token::Word("CHAR"))?;
                     let varying = self.match_word("VARYING");
                     let len = self.precision()?;
+                    let mut attrs = self.string_attrs(backend)?;
+                    attrs.national = true;
                     if varying {
-                        let attrs = self.string_attrs(backend)?;
                         SqlType::Varchar(len, attrs)
                     } else {
-                        SqlType::Char(len)
+                        SqlType::Char(len, attrs)
                     }
                 } else if eqi(w, "STRING") {
                     // BigQuery uses STRING as an alias for VARCHAR
@@ -1966,8 +3478,7 @@ impl<'source> Parser<'source> {
                 } else if eqi(w, "BLOB") {
                     SqlType::Blob
                 } else if eqi(w, "BINARY") {
-                    if self.match_word("LARGE") {
-                        self.expect(Token::Word("OBJECT"))?;
+                    if self.match_words("LARGE", "OBJECT") {
                         SqlType::Blob // BINARY LARGE OBJECT
                     } else if self.match_word("VARYING") {
                         // BINARY VARYING (Redshift)
@@ -1985,7 +3496,7 @@ impl<'source> Parser<'source> {
                     // Redshift also uses VARBYTE and VARBINARY
                     || eqi(w, "VARBYTE")
                 {
-                    let len = self.precision()?;
+                    let len = self.max_length()?;
                     SqlType::Binary(len)
                 } else if eqi(w, "DATE") {
                     SqlType::Date
@@ -2044,7 +3555,21 @@ impl<'source> Parser<'source> {
                             time_zone_spec: TimeZoneSpec::Without,
                         }
                     } else {
-                        SqlType::DateTime
+                        SqlType::DateTime { precision }
+                    }
+                } else if eqi(w, "DATETIME2") {
+                    // SQL Server
+                    let precision = self.precision()?;
+                    SqlType::Timestamp {
+                        precision,
+                        time_zone_spec: TimeZoneSpec::Without,
+                    }
+                } else if eqi(w, "DATETIMEOFFSET") {
+                    // SQL Server
+                    let precision = self.precision()?;
+                    SqlType::Timestamp {
+                        precision,
+                        time_zone_spec: TimeZoneSpec::With,
                     }
                 } else if eqi(w, "TIMESTAMP_TZ") {
                     let precision = self.precision()?;
@@ -2066,7 +3591,13 @@ impl<'source> Parser<'source> {
                     // declaration as decorative metadata, not an actual constraint. But this
                     // parser will preserve that metadata so that it can be used when rendering
                     // the type as a string.
-                    let qualifier = match self.interval_qualifier()? {
+                    let (qualifier, leading_precision) = match self.interval_qualifier()? {
+                        Some((start, leading_precision, end)) => {
+                            (Some((start, end)), leading_precision)
+                        }
+                        None => (None, None),
+                    };
+                    let qualifier = match qualifier {
                         Some((start, None)) => {
                             if matches!(start, DateTimeField::Second) {
                                 self.precision()?
@@ -2092,24 +3623,36 @@ impl<'source> Parser<'source> {
                             .map(DateTimeField::from_precision)
                             .map(|unit| (unit, None)),
                     };
-                    SqlType::Interval(qualifier)
+                    SqlType::Interval(qualifier, leading_precision)
                 } else if eqi(w, "JSON") {
                     SqlType::Json
                 } else if eqi(w, "JSONB") {
                     SqlType::Jsonb
                 } else if eqi(w, "GEOMETRY") {
-                    SqlType::Geometry
+                    SqlType::Geometry(None)
                 } else if eqi(w, "GEOGRAPHY") {
-                    SqlType::Geography
+                    SqlType::Geography(None)
                 } else if eqi(w, "ARRAY") {
                     let (left, right) = match backend {
                         Snowflake => (Token::LParen, Token::RParen),
                         _ => (Token::LAngle, Token::RAngle),
                     };
                     if self.match_(left) {
+                        if self.peek_is(right) || self.peek_is(Token::Comma) {
+                            return Err(ParseError::MalformedArrayType);
+                        }
                         let inner_type = self.parse_unconstrained_type(backend)?;
+                        let elements_nullable = !(matches!(backend, Databricks | DatabricksODBC)
+                            && self.match_words("NOT", "NULL"));
+                        if self.peek_is(Token::Comma) {
+                            return Err(ParseError::MalformedArrayType);
+                        }
                         self.expect(right)?;
-                        SqlType::Array(Some(Box::new(inner_type)))
+                        if elements_nullable {
+                            SqlType::Array(Some(Box::new(inner_type)))
+                        } else {
+                            SqlType::NonNullArray(Box::new(inner_type))
+                        }
                     } else {
                         SqlType::Array(None)
                     }
@@ -2117,6 +3660,11 @@ impl<'source> Parser<'source> {
                     // In some scenarios, we get "RECORD" as a type from BigQuery.
                     // That just means a generic struct.
                     SqlType::Struct(None)
+                } else if eqi(w, "ROW")
+                    && matches!(backend, Generic { .. })
+                    && self.match_(Token::LParen)
+                {
+                    SqlType::Row(self.row_fields(backend)?)
                 } else if eqi(w, "OBJECT") || eqi(w, "STRUCT") {
                     let (left, right) = match backend {
                         Snowflake => (Token::LParen, Token::RParen),
@@ -2125,14 +3673,25 @@ impl<'source> Parser<'source> {
                     let inner_fields = if self.match_(left) {
                         let fields = self.struct_fields(backend, right)?;
                         Some(fields)
+                    } else if backend == BigQuery && self.match_(Token::LParen) {
+                        // STRUCT(a INT64) is the constructor-style form accepted by
+                        // BigQuery. The leading STRUCT keyword sets it apart from
+                        // Postgres composite types.
+                        let fields = self.struct_fields(backend, Token::RParen)?;
+                        Some(fields)
                     } else {
                         None
                     };
                     SqlType::Struct(inner_fields)
                 } else if eqi(w, "MAP") {
                     let kv = if self.match_(Token::LAngle) {
+                        if self.peek_is(Token::RAngle) || self.peek_is(Token::Comma) {
+                            return Err(ParseError::EmptyMapType);
+                        }
                         let key_type = self.parse_unconstrained_type(backend)?;
-                        self.expect(Token::Comma)?;
+                        if !self.match_(Token::Comma) || self.peek_is(Token::RAngle) {
+                            return Err(ParseError::EmptyMapType);
+                        }
                         let value_type = self.parse_unconstrained_type(backend)?;
                         self.expect(Token::RAngle)?;
                         Some((Box::new(key_type), Box::new(value_type)))
@@ -2140,26 +3699,18 @@ impl<'source> Parser<'source> {
                         None
                     };
                     SqlType::Map(kv)
+                } else if eqi(w, "UUID") || eqi(w, "UNIQUEIDENTIFIER") {
+                    SqlType::Uuid
+                } else if eqi(w, "INET") {
+                    SqlType::Inet
+                } else if eqi(w, "CIDR") {
+                    SqlType::Cidr
                 } else if eqi(w, "VARIANT") {
                     SqlType::Variant
                 } else if eqi(w, "VOID") {
                     SqlType::Void
                 } else {
-                    // gather all tokens before "[NOT] NULL" and return Other(..)
-                    let mut other = w.to_string();
-                    while let Some(tok) = {
-                        self.tokenizer.peek_and_then(|t| {
-                            if t == Token::Word("NOT") || t == Token::Word("NULL") {
-                                None
-                            } else {
-                                Some(t)
-                            }
-                        })
-                    } {
-                        use fmt::Write as _;
-                        write!(&mut other, " {tok}").unwrap();
-                    }
-                    SqlType::Other(other)
+                    SqlType::Other(self.other_type(w))
                 }
             }
         };
@@ -1,5 +1,10 @@
+use std::sync::Arc;
+
+use arrow_schema::{DataType, Field, Fields, Schema};
+
 use crate::Backend;
 use crate::sql::ident::*;
+use crate::sql::tokenizer::QuotingStyle;
 use crate::sql::types::*;
 
 use Backend::*;
@@ -18,6 +23,13 @@ fn assert_parses_to(line: u32, input: &str, expected: &SqlType, backend: Backend
     );
 }
 
+fn national() -> StringAttrs {
+    StringAttrs {
+        national: true,
+        ..Default::default()
+    }
+}
+
 /// Test that parsing leads to the expected SqlType on every backend.
 ///
 /// Uses [assert_parses_to] for every pair.
@@ -59,10 +71,10 @@ fn test_parser() {
             (line!(), "bigNUMeric      ", BigNumeric(None)),
             (line!(), "bignumeric(20)  ", BigNumeric(Some((20, None)))),
             (line!(), "bignuMERic(60,2)", BigNumeric(Some((60, Some(2))))),
-            (line!(), "CHar         ", Char(None)),
-            (line!(), "CHar(20)     ", Char(Some(20))),
-            (line!(), "chARACter    ", Char(None)),
-            (line!(), "chARACter(20)", Char(Some(20))),
+            (line!(), "CHar         ", Char(None, Default::default())),
+            (line!(), "CHar(20)     ", Char(Some(20), Default::default())),
+            (line!(), "chARACter    ", Char(None, Default::default())),
+            (line!(), "chARACter(20)", Char(Some(20), Default::default())),
             (
                 line!(),
                 "charaCTER VARying      ",
@@ -73,11 +85,11 @@ fn test_parser() {
                 "charaCTER VARying (20 )",
                 Varchar(Some(20), Default::default()),
             ),
-            (line!(), "natioNAL CHar          ", Char(None)),
+            (line!(), "natioNAL CHar          ", Char(None, national())),
             (
                 line!(),
                 "natioNAL CHar vaRying  ",
-                Varchar(None, Default::default()),
+                Varchar(None, national()),
             ),
             (
                 line!(),
@@ -86,6 +98,7 @@ fn test_parser() {
                     None,
                     StringAttrs {
                         collate_spec: Some("'utf8'".to_string()),
+                        ..Default::default()
                     },
                 ),
             ),
@@ -96,6 +109,7 @@ fn test_parser() {
                     None,
                     StringAttrs {
                         collate_spec: Some("UNICODE_CI".to_string()),
+                        ..Default::default()
                     },
                 ),
             ),
@@ -173,33 +187,41 @@ fn test_parser() {
                     time_zone_spec: TimeZoneSpec::With,
                 },
             ),
-            (line!(), "INTERVal", Interval(None)),
-            (line!(), "interval (0 )", Interval(Some((Second, None)))),
+            (line!(), "INTERVal", Interval(None, None)),
+            (
+                line!(),
+                "interval (0 )",
+                Interval(Some((Second, None)), None),
+            ),
             (
                 line!(),
                 "interval ( 3)",
-                Interval(Some((Millisecond, None))),
+                Interval(Some((Millisecond, None)), None),
             ),
             (
                 line!(),
                 "interval second(3)",
-                Interval(Some((Millisecond, None))),
+                Interval(Some((Millisecond, None)), None),
             ),
             (
                 line!(),
                 "interval year to second(6)",
-                Interval(Some((Year, Some(Microsecond)))),
+                Interval(Some((Year, Some(Microsecond))), None),
             ),
             (
                 line!(),
                 "interval year to microsecond",
-                Interval(Some((Year, Some(Microsecond)))),
+                Interval(Some((Year, Some(Microsecond))), None),
+            ),
+            (
+                line!(),
+                "interval minute",
+                Interval(Some((Minute, None)), None),
             ),
-            (line!(), "interval minute", Interval(Some((Minute, None)))),
             (line!(), "jSON", Json),
             (line!(), "jSONb", Jsonb),
-            (line!(), "geoMETRY", Geometry),
-            (line!(), "geoGRAPHy", Geography),
+            (line!(), "geoMETRY", Geometry(None)),
+            (line!(), "geoGRAPHy", Geography(None)),
             (line!(), "arrAY", Array(None)),
             (
                 line!(),
@@ -269,6 +291,7 @@ fn test_parser() {
                             None,
                             StringAttrs {
                                 collate_spec: Some("UNICODE_CI".to_string()),
+                                ..Default::default()
                             },
                         ),
                         false,
@@ -315,7 +338,9 @@ fn test_parser() {
             ),
         ]
     };
-    for backend in backends() {
+    // ClickHouse type names clash with the ones above (e.g. Int8 is a TINYINT), so
+    // it is covered by test_clickhouse_types instead
+    for backend in backends().into_iter().filter(|b| *b != ClickHouse) {
         let data = data_for_backend(backend);
         for (line, input, expected) in data.iter() {
             assert_parses_to(*line, input, expected, backend);
@@ -331,7 +356,8 @@ fn test_bigquery_types() {
         (line!(), "BYTES", Binary(None)),
         (line!(), "INT64", BigInt),
         (line!(), "FLOAT64", Double),
-        (line!(), "DATETIME", DateTime),
+        (line!(), "DATETIME", DateTime { precision: None }),
+        (line!(), "DATETIME(6)", DateTime { precision: Some(6) }),
         (line!(), "ARRAY<INT64>", Array(Some(Box::new(BigInt)))),
         (
             line!(),
@@ -349,6 +375,310 @@ fn test_bigquery_types() {
     }
 }
 
+#[test]
+fn test_generic_rows() {
+    let generic = Generic {
+        library_name: "generic",
+        entrypoint: None,
+    };
+    let table = [
+        (line!(), "ROW(INT, VARCHAR)"),
+        (line!(), "ROW(a INT, b VARCHAR)"),
+        (line!(), "ARRAY<ROW(DATE, NUMERIC(10, 2))>"),
+        (line!(), "ROW(p ROW(BIGINT, BIGINT))"),
+        (
+            line!(),
+            "ROW(TIMESTAMP(3) WITH TIME ZONE, DOUBLE PRECISION)",
+        ),
+        (line!(), r#"ROW("a b" INT, c VARCHAR)"#),
+    ];
+    for (line, input) in table {
+        let (ty, _) = SqlType::parse(generic, input).unwrap();
+        assert_roundtrip(line, &ty, input, generic);
+    }
+
+    // positional fields are named after their 1-based position
+    let (ty, _) = SqlType::parse(generic, "row(integer, varchar)").unwrap();
+    let Row(fields) = &ty else {
+        panic!("{ty:?}");
+    };
+    let names = fields
+        .iter()
+        .map(|field| field.name.as_ref())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["1", "2"]);
+    assert_eq!(ty.to_string(generic), "ROW(INT, VARCHAR)");
+    assert!(matches!(ty.field_type(&["2"]), Some(Varchar(None, _))));
+
+    let (ty, _) = SqlType::parse(generic, r#"ROW("a b" INTEGER, c VARCHAR)"#).unwrap();
+    let names = ty
+        .fields()
+        .unwrap()
+        .iter()
+        .map(|field| field.name.as_ref())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["a b", "c"]);
+
+    // other backends render rows as structs
+    let (ty, _) = SqlType::parse(generic, "ROW(a INT, b VARCHAR)").unwrap();
+    assert_eq!(ty.to_string(Databricks), "STRUCT<a: INT, b: STRING>");
+    // and don't parse ROW as a type
+    let parsed = SqlType::parse(Databricks, "ROW(a INT)");
+    assert!(!matches!(parsed, Ok((Row(_), _))), "{parsed:?}");
+}
+
+#[test]
+fn test_datetime_precision() {
+    let ty = DateTime { precision: Some(6) };
+    assert_roundtrip(line!(), &ty, "DATETIME(6)", BigQuery);
+    assert_roundtrip(line!(), &ty, "TIMESTAMP_NTZ(6)", Snowflake);
+    let ty = DateTime { precision: None };
+    assert_roundtrip(line!(), &ty, "DATETIME", BigQuery);
+    assert_roundtrip(line!(), &ty, "TIMESTAMP_NTZ", Snowflake);
+
+    // Snowflake's DATETIME is an alias of TIMESTAMP_NTZ
+    let (ty, _) = SqlType::parse(Snowflake, "DATETIME(6)").unwrap();
+    assert_eq!(ty.to_string(Snowflake), "TIMESTAMP_NTZ(6)");
+    let (ty, _) = SqlType::parse(BigQuery, "datetime(3)").unwrap();
+    assert!(matches!(ty, DateTime { precision: Some(3) }), "{ty:?}");
+}
+
+#[test]
+fn test_bigquery_parenthesized_struct() {
+    let expected = Struct(Some(vec![
+        StructField::new(Ident::plain("a"), BigInt, true),
+        StructField::new(Ident::plain("b"), SqlType::varchar(None), true),
+    ]));
+    assert_parses_to(line!(), "STRUCT(a INT64, b STRING)", &expected, BigQuery);
+    assert_parses_to(line!(), "struct(a INT64, b STRING)", &expected, BigQuery);
+
+    // rendered back in the canonical angle-bracket form
+    let (ty, _) = SqlType::parse(BigQuery, "STRUCT(a INT64, b STRING)").unwrap();
+    assert_eq!(ty.to_string(BigQuery), "STRUCT<a INT64, b STRING>");
+
+    let (ty, _) = SqlType::parse(BigQuery, "ARRAY<STRUCT(a INT64, b STRUCT(c STRING))>").unwrap();
+    assert_eq!(
+        ty.to_string(BigQuery),
+        "ARRAY<STRUCT<a INT64, b STRUCT<c STRING>>>"
+    );
+}
+
+/// Test parsing of strings that might only be recognized by SQL Server.
+#[test]
+fn test_mssql_types() {
+    let table = vec![
+        (line!(), "BIT", Boolean),
+        (line!(), "NVARCHAR(MAX)", Varchar(None, national())),
+        (line!(), "nvarchar(4000)", Varchar(Some(4000), national())),
+        (line!(), "VARCHAR(MAX)", Varchar(None, Default::default())),
+        (line!(), "NCHAR(10)", Char(Some(10), national())),
+        (line!(), "VARBINARY(MAX)", Binary(None)),
+        (
+            line!(),
+            "DATETIME2(7)",
+            Timestamp {
+                precision: Some(7),
+                time_zone_spec: TimeZoneSpec::Without,
+            },
+        ),
+        (
+            line!(),
+            "DATETIMEOFFSET",
+            Timestamp {
+                precision: None,
+                time_zone_spec: TimeZoneSpec::With,
+            },
+        ),
+        (line!(), "UNIQUEIDENTIFIER", Uuid),
+    ];
+    for (line, input, expected) in table {
+        assert_parses_to(line, input, &expected, MSSQL);
+    }
+}
+
+#[test]
+fn test_uuid_arrow_type() {
+    // only SQL Server returns UUIDs as binaries, other drivers return strings
+    assert_eq!(
+        Uuid.pick_best_arrow_type(MSSQL),
+        DataType::FixedSizeBinary(16)
+    );
+    for backend in [Postgres, Snowflake, Databricks, BigQuery, ClickHouse] {
+        assert_eq!(
+            Uuid.pick_best_arrow_type(backend),
+            DataType::Utf8,
+            "{backend}"
+        );
+    }
+}
+
+/// Test parsing of strings that might only be recognized by ClickHouse.
+#[test]
+fn test_clickhouse_types() {
+    let table = vec![
+        (line!(), "Int8", TinyInt),
+        (line!(), "UInt8", UnsignedInteger(8)),
+        (line!(), "UInt64", UnsignedInteger(64)),
+        (line!(), "Float32", Real),
+        (
+            line!(),
+            "FixedString(16)",
+            Char(Some(16), Default::default()),
+        ),
+        (
+            line!(),
+            "DateTime64(6, 'Asia/Tokyo')",
+            Timestamp {
+                precision: Some(6),
+                time_zone_spec: TimeZoneSpec::Named("Asia/Tokyo".to_string()),
+            },
+        ),
+        (
+            line!(),
+            "Map(String, Nullable(Int32))",
+            Map(Some((Box::new(SqlType::varchar(None)), Box::new(Integer)))),
+        ),
+        (
+            line!(),
+            "Tuple(a Int32, b Nullable(String))",
+            Struct(Some(vec![
+                StructField::new(Ident::plain("a"), Integer, false),
+                StructField::new(Ident::plain("b"), SqlType::varchar(None), true),
+            ])),
+        ),
+        (line!(), "Nothing", Void),
+    ];
+    for (line, input, expected) in table {
+        assert_parses_to(line, input, &expected, ClickHouse);
+    }
+
+    // nullability is part of the type
+    let (ty, nullable) = SqlType::parse(ClickHouse, "Nullable(Int32)").unwrap();
+    assert!(matches!(ty, Integer), "{ty:?}");
+    assert!(nullable);
+    let (ty, nullable) = SqlType::parse(ClickHouse, "Int32").unwrap();
+    assert!(matches!(ty, Integer), "{ty:?}");
+    assert!(!nullable);
+    let (ty, nullable) = SqlType::parse(ClickHouse, "Array(Nullable(String))").unwrap();
+    assert!(matches!(&ty, Array(Some(inner)) if matches!(**inner, Varchar(None, _))));
+    assert!(!nullable);
+    assert_roundtrip(line!(), &ty, "Array(Nullable(String))", ClickHouse);
+    let (ty, _) = SqlType::parse(ClickHouse, "Array(String)").unwrap();
+    assert!(matches!(&ty, NonNullArray(inner) if matches!(**inner, Varchar(None, _))));
+    assert_roundtrip(line!(), &ty, "Array(String)", ClickHouse);
+    let (ty, _) = SqlType::parse(ClickHouse, "Map(String, Nullable(Int32))").unwrap();
+    assert_roundtrip(line!(), &ty, "Map(String, Nullable(Int32))", ClickHouse);
+    let (ty, _) = SqlType::parse(ClickHouse, "DateTime('Asia/Tokyo')").unwrap();
+    assert!(
+        matches!(
+            &ty,
+            Timestamp {
+                precision: Some(0),
+                time_zone_spec: TimeZoneSpec::Named(time_zone),
+            } if time_zone == "Asia/Tokyo"
+        ),
+        "{ty:?}"
+    );
+    assert_roundtrip(line!(), &ty, "DateTime('Asia/Tokyo')", ClickHouse);
+
+    let mut out = String::new();
+    Integer
+        .write_with_nullability(ClickHouse, true, &mut out)
+        .unwrap();
+    assert_eq!(out, "Nullable(Int32)");
+    let mut out = String::new();
+    Array(Some(Box::new(Integer)))
+        .write_with_nullability(ClickHouse, true, &mut out)
+        .unwrap();
+    assert_eq!(out, "Array(Nullable(Int32))");
+
+    assert_eq!(
+        SqlType::normalize(ClickHouse, "Nullable(Int32)").unwrap(),
+        "Nullable(Int32)"
+    );
+    assert_eq!(SqlType::normalize(ClickHouse, "Int32").unwrap(), "Int32");
+}
+
+#[test]
+fn test_clickhouse_unsigned_and_time_zone_roundtrip() {
+    let table = vec![
+        (
+            line!(),
+            UnsignedInteger(8),
+            "UInt8",
+            DataType::UInt8,
+            "SMALLINT",
+        ),
+        (
+            line!(),
+            UnsignedInteger(16),
+            "UInt16",
+            DataType::UInt16,
+            "INT",
+        ),
+        (
+            line!(),
+            UnsignedInteger(32),
+            "UInt32",
+            DataType::UInt32,
+            "BIGINT",
+        ),
+        (
+            line!(),
+            UnsignedInteger(64),
+            "UInt64",
+            DataType::UInt64,
+            "NUMERIC(20, 0)",
+        ),
+    ];
+    for (line, ty, s, data_type, widened) in table {
+        assert_roundtrip(line, &ty, s, ClickHouse);
+        assert_eq!(
+            ty.pick_best_arrow_type(ClickHouse),
+            data_type,
+            "from line {line}"
+        );
+        let from_arrow = SqlType::_from_arrow_type(ClickHouse, &data_type);
+        assert_eq!(from_arrow.to_string(ClickHouse), s, "from line {line}");
+        // backends without unsigned integers use a signed type that holds the whole range
+        assert_eq!(ty.to_string(Postgres), widened, "from line {line}");
+    }
+
+    let ty = Timestamp {
+        precision: Some(3),
+        time_zone_spec: TimeZoneSpec::Named("Asia/Tokyo".to_string()),
+    };
+    assert_roundtrip(line!(), &ty, "DateTime64(3, 'Asia/Tokyo')", ClickHouse);
+    assert_eq!(
+        ty.pick_best_arrow_type(ClickHouse),
+        DataType::Timestamp(
+            arrow_schema::TimeUnit::Millisecond,
+            Some("Asia/Tokyo".into())
+        )
+    );
+    // other backends only know that there is a time zone
+    assert_eq!(ty.to_string(Postgres), "TIMESTAMP(3) WITH TIME ZONE");
+    assert!(SqlType::parse(ClickHouse, "DateTime64(3, Asia)").is_err());
+}
+
+#[test]
+fn test_databricks_integer_and_boolean_types() {
+    let table = vec![
+        (line!(), Boolean, "BOOLEAN"),
+        (line!(), TinyInt, "TINYINT"),
+        (line!(), SmallInt, "SMALLINT"),
+        (line!(), Integer, "INT"),
+        (line!(), BigInt, "BIGINT"),
+    ];
+    for backend in [Databricks, DatabricksODBC] {
+        for (line, ty, s) in table.iter() {
+            assert_roundtrip(*line, ty, s, backend);
+        }
+    }
+    assert_parses_to(line!(), "TINYINT", &TinyInt, Databricks);
+    assert_parses_to(line!(), "boolean", &Boolean, Databricks);
+}
+
 fn backends() -> Vec<Backend> {
     vec![
         Postgres,
@@ -357,6 +687,8 @@ fn backends() -> Vec<Backend> {
         Databricks,
         DatabricksODBC,
         RedshiftODBC,
+        MSSQL,
+        ClickHouse,
         Generic {
             library_name: "generic",
             entrypoint: None,
@@ -383,7 +715,7 @@ fn assert_roundtrip(line: u32, ty: &SqlType, s: &str, backend: Backend) {
 
 /// Returns a vector of triplets with a line number, SQL type, and its rendering for a given backend.
 fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static str)> {
-    // | # | SQLType | - | BigQuery | Snowflake | Postgres | Databricks | generic |
+    // | # | SQLType | - | BigQuery | Snowflake | Postgres | Databricks | generic | MSSQL | ClickHouse |
     let sqltype_bg_generic_snow_table = vec![
         (
             line!(),
@@ -393,6 +725,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "BOOLEAN",
             "BOOLEAN",
             "BOOLEAN",
+            "BIT",
+            "Bool",
         ),
         (
             line!(),
@@ -402,6 +736,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "SMALLINT",
             "TINYINT",
             "TINYINT",
+            "TINYINT",
+            "Int8",
         ),
         (
             line!(),
@@ -411,8 +747,20 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "SMALLINT",
             "SMALLINT",
             "SMALLINT",
+            "SMALLINT",
+            "Int16",
+        ),
+        (
+            line!(),
+            Integer,
+            "INT64",
+            "INT",
+            "INT",
+            "INT",
+            "INT",
+            "INT",
+            "Int32",
         ),
-        (line!(), Integer, "INT64", "INT", "INT", "INT", "INT"),
         (
             line!(),
             BigInt,
@@ -421,8 +769,20 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "BIGINT",
             "BIGINT",
             "BIGINT",
+            "BIGINT",
+            "Int64",
+        ),
+        (
+            line!(),
+            Real,
+            "FLOAT64",
+            "REAL",
+            "REAL",
+            "FLOAT",
+            "REAL",
+            "REAL",
+            "Float32",
         ),
-        (line!(), Real, "FLOAT64", "REAL", "REAL", "FLOAT", "REAL"),
         (
             line!(),
             Float(None),
@@ -431,6 +791,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "REAL",
             "FLOAT",
             "FLOAT",
+            "FLOAT",
+            "Float64",
         ),
         (
             line!(),
@@ -440,6 +802,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "REAL",
             "FLOAT",
             "FLOAT(3)",
+            "FLOAT(3)",
+            "Float32",
         ),
         (
             line!(),
@@ -449,6 +813,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "DOUBLE PRECISION",
             "DOUBLE",
             "DOUBLE PRECISION",
+            "DOUBLE PRECISION",
+            "Float64",
         ),
         (
             line!(),
@@ -458,6 +824,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "NUMERIC",
             "DECIMAL",
             "NUMERIC",
+            "NUMERIC",
+            "Decimal",
         ),
         (
             line!(),
@@ -467,6 +835,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "NUMERIC(20)",
             "DECIMAL(20)",
             "NUMERIC(20)",
+            "NUMERIC(20)",
+            "Decimal(20)",
         ),
         (
             line!(),
@@ -476,6 +846,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "NUMERIC(60, 2)",
             "DECIMAL(60, 2)",
             "NUMERIC(60, 2)",
+            "NUMERIC(60, 2)",
+            "Decimal(60, 2)",
         ),
         (
             line!(),
@@ -485,6 +857,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "VARCHAR",
             "STRING",
             "VARCHAR",
+            "NVARCHAR(MAX)",
+            "String",
         ),
         (
             line!(),
@@ -494,10 +868,42 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "VARCHAR(255)",
             "STRING",
             "VARCHAR(255)",
+            "NVARCHAR(255)",
+            "String",
+        ),
+        (
+            line!(),
+            Text,
+            "STRING",
+            "TEXT",
+            "TEXT",
+            "STRING",
+            "TEXT",
+            "NVARCHAR(MAX)",
+            "String",
+        ),
+        (
+            line!(),
+            Clob,
+            "STRING",
+            "TEXT",
+            "TEXT",
+            "STRING",
+            "CLOB",
+            "NVARCHAR(MAX)",
+            "String",
+        ),
+        (
+            line!(),
+            Blob,
+            "BYTES",
+            "BINARY",
+            "BYTEA",
+            "BINARY",
+            "BLOB",
+            "VARBINARY(MAX)",
+            "String",
         ),
-        (line!(), Text, "STRING", "TEXT", "TEXT", "STRING", "TEXT"),
-        (line!(), Clob, "STRING", "TEXT", "TEXT", "STRING", "CLOB"),
-        (line!(), Blob, "BYTES", "BINARY", "BYTEA", "BINARY", "BLOB"),
         (
             line!(),
             Binary(None),
@@ -506,6 +912,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "BYTEA",
             "BINARY",
             "BINARY",
+            "VARBINARY(MAX)",
+            "String",
         ),
         (
             line!(),
@@ -515,6 +923,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "BYTEA",
             "BINARY",
             "BINARY(16)",
+            "VARBINARY(16)",
+            "FixedString(16)",
         ),
         (
             line!(),
@@ -524,8 +934,20 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "BYTEA",
             "BINARY",
             "BINARY(255)",
+            "VARBINARY(255)",
+            "FixedString(255)",
+        ),
+        (
+            line!(),
+            Date,
+            "DATE",
+            "DATE",
+            "DATE",
+            "DATE",
+            "DATE",
+            "DATE",
+            "Date",
         ),
-        (line!(), Date, "DATE", "DATE", "DATE", "DATE", "DATE"),
         (
             line!(),
             Time {
@@ -537,6 +959,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "TIME",
             "TIME WITHOUT TIME ZONE", // Databricks doesn't actually have a TIME type
             "TIME WITHOUT TIME ZONE",
+            "TIME",
+            "Time",
         ),
         (
             line!(),
@@ -549,6 +973,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "TIME(0)",
             "TIME(0) WITHOUT TIME ZONE", // Databricks doesn't actually have a TIME type
             "TIME(0) WITHOUT TIME ZONE",
+            "TIME(0)",
+            "Time64(0)",
         ),
         (
             line!(),
@@ -561,6 +987,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "TIME(5)",
             "TIME(5) WITHOUT TIME ZONE", // Databricks doesn't actually have a TIME type
             "TIME(5) WITHOUT TIME ZONE",
+            "TIME(5)",
+            "Time64(5)",
         ),
         (
             line!(),
@@ -573,6 +1001,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "TIME(9)",
             "TIME(9) WITHOUT TIME ZONE", // Databricks doesn't actually have a TIME type
             "TIME(9) WITHOUT TIME ZONE",
+            "TIME(9)",
+            "Time64(9)",
         ),
         (
             line!(),
@@ -585,15 +1015,19 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "TIME(9) WITH TIME ZONE",
             "TIME(9) WITH TIME ZONE", // Databricks doesn't actually have a TIME type
             "TIME(9) WITH TIME ZONE",
+            "TIME(9)",
+            "Time64(9)",
         ),
         (
             line!(),
-            DateTime,
+            DateTime { precision: None },
             "DATETIME",
             "TIMESTAMP_NTZ",
             "TIMESTAMP",
             "TIMESTAMP_NTZ",
             "DATETIME",
+            "DATETIME",
+            "DateTime",
         ),
         (
             line!(),
@@ -606,6 +1040,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "TIMESTAMP",
             "TIMESTAMP_NTZ",
             "TIMESTAMP WITHOUT TIME ZONE",
+            "DATETIME2",
+            "DateTime64(3)",
         ),
         (
             line!(),
@@ -618,6 +1054,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "TIMESTAMPTZ",
             "TIMESTAMP",
             "TIMESTAMP WITH TIME ZONE",
+            "DATETIMEOFFSET",
+            "DateTime64(3, 'UTC')",
         ),
         (
             line!(),
@@ -630,6 +1068,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "TIMESTAMP(3)",
             "TIMESTAMP_NTZ",
             "TIMESTAMP(3) WITHOUT TIME ZONE",
+            "DATETIME2(3)",
+            "DateTime64(3)",
         ),
         (
             line!(),
@@ -642,10 +1082,14 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "TIMESTAMP(3) WITH TIME ZONE",
             "TIMESTAMP",
             "TIMESTAMP(3) WITH TIME ZONE",
+            "DATETIMEOFFSET(3)",
+            "DateTime64(3, 'UTC')",
         ),
         (
             line!(),
-            Interval(None),
+            Interval(None, None),
+            "INTERVAL",
+            "INTERVAL",
             "INTERVAL",
             "INTERVAL",
             "INTERVAL",
@@ -654,7 +1098,9 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
         ),
         (
             line!(),
-            Interval(Some((Second, None))),
+            Interval(Some((Second, None)), None),
+            "INTERVAL SECOND",
+            "INTERVAL SECOND",
             "INTERVAL SECOND",
             "INTERVAL SECOND",
             "INTERVAL SECOND",
@@ -663,25 +1109,31 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
         ),
         (
             line!(),
-            Interval(Some((Millisecond, None))),
+            Interval(Some((Millisecond, None)), None),
             "INTERVAL MILLISECOND",
             "INTERVAL MILLISECOND",
             "INTERVAL SECOND(3)",
             "INTERVAL MILLISECOND",
             "INTERVAL MILLISECOND",
+            "INTERVAL MILLISECOND",
+            "INTERVAL MILLISECOND",
         ),
         (
             line!(),
-            Interval(Some((Day, Some(Microsecond)))),
+            Interval(Some((Day, Some(Microsecond))), None),
             "INTERVAL DAY TO MICROSECOND",
             "INTERVAL DAY TO MICROSECOND",
             "INTERVAL DAY TO SECOND(6)",
             "INTERVAL DAY TO MICROSECOND",
             "INTERVAL DAY TO MICROSECOND",
+            "INTERVAL DAY TO MICROSECOND",
+            "INTERVAL DAY TO MICROSECOND",
         ),
         (
             line!(),
-            Interval(Some((Year, None))),
+            Interval(Some((Year, None)), None),
+            "INTERVAL YEAR",
+            "INTERVAL YEAR",
             "INTERVAL YEAR",
             "INTERVAL YEAR",
             "INTERVAL YEAR",
@@ -690,7 +1142,9 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
         ),
         (
             line!(),
-            Interval(Some((Day, Some(Hour)))),
+            Interval(Some((Day, Some(Hour))), None),
+            "INTERVAL DAY TO HOUR",
+            "INTERVAL DAY TO HOUR",
             "INTERVAL DAY TO HOUR",
             "INTERVAL DAY TO HOUR",
             "INTERVAL DAY TO HOUR",
@@ -699,7 +1153,9 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
         ),
         (
             line!(),
-            Interval(Some((Day, Some(Minute)))),
+            Interval(Some((Day, Some(Minute))), None),
+            "INTERVAL DAY TO MINUTE",
+            "INTERVAL DAY TO MINUTE",
             "INTERVAL DAY TO MINUTE",
             "INTERVAL DAY TO MINUTE",
             "INTERVAL DAY TO MINUTE",
@@ -708,7 +1164,9 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
         ),
         (
             line!(),
-            Interval(Some((Day, Some(Second)))),
+            Interval(Some((Day, Some(Second))), None),
+            "INTERVAL DAY TO SECOND",
+            "INTERVAL DAY TO SECOND",
             "INTERVAL DAY TO SECOND",
             "INTERVAL DAY TO SECOND",
             "INTERVAL DAY TO SECOND",
@@ -717,7 +1175,9 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
         ),
         (
             line!(),
-            Interval(Some((Hour, Some(Minute)))),
+            Interval(Some((Hour, Some(Minute))), None),
+            "INTERVAL HOUR TO MINUTE",
+            "INTERVAL HOUR TO MINUTE",
             "INTERVAL HOUR TO MINUTE",
             "INTERVAL HOUR TO MINUTE",
             "INTERVAL HOUR TO MINUTE",
@@ -726,7 +1186,9 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
         ),
         (
             line!(),
-            Interval(Some((Hour, Some(Second)))),
+            Interval(Some((Hour, Some(Second))), None),
+            "INTERVAL HOUR TO SECOND",
+            "INTERVAL HOUR TO SECOND",
             "INTERVAL HOUR TO SECOND",
             "INTERVAL HOUR TO SECOND",
             "INTERVAL HOUR TO SECOND",
@@ -735,7 +1197,9 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
         ),
         (
             line!(),
-            Interval(Some((Minute, Some(Second)))),
+            Interval(Some((Minute, Some(Second))), None),
+            "INTERVAL MINUTE TO SECOND",
+            "INTERVAL MINUTE TO SECOND",
             "INTERVAL MINUTE TO SECOND",
             "INTERVAL MINUTE TO SECOND",
             "INTERVAL MINUTE TO SECOND",
@@ -744,7 +1208,9 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
         ),
         (
             line!(),
-            Interval(Some((Month, Some(Day)))),
+            Interval(Some((Month, Some(Day))), None),
+            "INTERVAL MONTH TO DAY",
+            "INTERVAL MONTH TO DAY",
             "INTERVAL MONTH TO DAY",
             "INTERVAL MONTH TO DAY",
             "INTERVAL MONTH TO DAY",
@@ -753,7 +1219,9 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
         ),
         (
             line!(),
-            Interval(Some((Month, Some(Hour)))),
+            Interval(Some((Month, Some(Hour))), None),
+            "INTERVAL MONTH TO HOUR",
+            "INTERVAL MONTH TO HOUR",
             "INTERVAL MONTH TO HOUR",
             "INTERVAL MONTH TO HOUR",
             "INTERVAL MONTH TO HOUR",
@@ -762,7 +1230,9 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
         ),
         (
             line!(),
-            Interval(Some((Month, Some(Minute)))),
+            Interval(Some((Month, Some(Minute))), None),
+            "INTERVAL MONTH TO MINUTE",
+            "INTERVAL MONTH TO MINUTE",
             "INTERVAL MONTH TO MINUTE",
             "INTERVAL MONTH TO MINUTE",
             "INTERVAL MONTH TO MINUTE",
@@ -771,7 +1241,9 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
         ),
         (
             line!(),
-            Interval(Some((Month, Some(Second)))),
+            Interval(Some((Month, Some(Second))), None),
+            "INTERVAL MONTH TO SECOND",
+            "INTERVAL MONTH TO SECOND",
             "INTERVAL MONTH TO SECOND",
             "INTERVAL MONTH TO SECOND",
             "INTERVAL MONTH TO SECOND",
@@ -780,7 +1252,9 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
         ),
         (
             line!(),
-            Interval(Some((Year, Some(Day)))),
+            Interval(Some((Year, Some(Day))), None),
+            "INTERVAL YEAR TO DAY",
+            "INTERVAL YEAR TO DAY",
             "INTERVAL YEAR TO DAY",
             "INTERVAL YEAR TO DAY",
             "INTERVAL YEAR TO DAY",
@@ -789,7 +1263,9 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
         ),
         (
             line!(),
-            Interval(Some((Year, Some(Hour)))),
+            Interval(Some((Year, Some(Hour))), None),
+            "INTERVAL YEAR TO HOUR",
+            "INTERVAL YEAR TO HOUR",
             "INTERVAL YEAR TO HOUR",
             "INTERVAL YEAR TO HOUR",
             "INTERVAL YEAR TO HOUR",
@@ -798,7 +1274,9 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
         ),
         (
             line!(),
-            Interval(Some((Year, Some(Minute)))),
+            Interval(Some((Year, Some(Minute))), None),
+            "INTERVAL YEAR TO MINUTE",
+            "INTERVAL YEAR TO MINUTE",
             "INTERVAL YEAR TO MINUTE",
             "INTERVAL YEAR TO MINUTE",
             "INTERVAL YEAR TO MINUTE",
@@ -807,7 +1285,9 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
         ),
         (
             line!(),
-            Interval(Some((Year, Some(Month)))),
+            Interval(Some((Year, Some(Month))), None),
+            "INTERVAL YEAR TO MONTH",
+            "INTERVAL YEAR TO MONTH",
             "INTERVAL YEAR TO MONTH",
             "INTERVAL YEAR TO MONTH",
             "INTERVAL YEAR TO MONTH",
@@ -816,7 +1296,9 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
         ),
         (
             line!(),
-            Interval(Some((Year, Some(Second)))),
+            Interval(Some((Year, Some(Second))), None),
+            "INTERVAL YEAR TO SECOND",
+            "INTERVAL YEAR TO SECOND",
             "INTERVAL YEAR TO SECOND",
             "INTERVAL YEAR TO SECOND",
             "INTERVAL YEAR TO SECOND",
@@ -831,6 +1313,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "JSON[]",
             "ARRAY<JSON>",
             "ARRAY<JSON>",
+            "ARRAY<JSON>",
+            "Array(JSON)",
         ),
         (
             line!(),
@@ -844,6 +1328,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "(a REAL)",
             "STRUCT<a: FLOAT>",
             "STRUCT<a FLOAT>",
+            "STRUCT<a FLOAT>",
+            "Tuple(a Nullable(Float64))",
         ),
         (
             line!(),
@@ -856,6 +1342,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "(name VARCHAR, age INT NOT NULL)",
             "STRUCT<name: STRING, age: INT NOT NULL>",
             "STRUCT<name VARCHAR, age INT NOT NULL>",
+            "STRUCT<name NVARCHAR(MAX), age INT NOT NULL>",
+            "Tuple(name Nullable(String), age Int32)",
         ),
         (
             line!(),
@@ -891,6 +1379,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "(last_completion_time TIMESTAMP, error_time TIMESTAMP, error (reason VARCHAR, location VARCHAR, message VARCHAR))",
             "STRUCT<last_completion_time: TIMESTAMP_NTZ, error_time: TIMESTAMP_NTZ, error: STRUCT<reason: STRING, location: STRING, message: STRING>>",
             "STRUCT<last_completion_time TIMESTAMP WITHOUT TIME ZONE, error_time TIMESTAMP WITHOUT TIME ZONE, error STRUCT<reason VARCHAR, location VARCHAR, message VARCHAR>>",
+            "STRUCT<last_completion_time DATETIME2, error_time DATETIME2, error STRUCT<reason NVARCHAR(MAX), location NVARCHAR(MAX), message NVARCHAR(MAX)>>",
+            "Tuple(last_completion_time Nullable(DateTime64(3)), error_time Nullable(DateTime64(3)), error Tuple(reason Nullable(String), location Nullable(String), message Nullable(String)))",
         ),
         (
             line!(),
@@ -903,6 +1393,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "(date DATE, value VARCHAR)[]",
             "ARRAY<STRUCT<date: DATE, value: STRING>>",
             "ARRAY<STRUCT<date DATE, value VARCHAR>>",
+            "ARRAY<STRUCT<date DATE, value NVARCHAR(MAX)>>",
+            "Array(Tuple(date Nullable(Date), value Nullable(String)))",
         ),
         (
             line!(),
@@ -919,6 +1411,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "(elements (date DATE, value VARCHAR)[])",
             "STRUCT<elements: ARRAY<STRUCT<date: DATE, value: STRING>>>",
             "STRUCT<elements ARRAY<STRUCT<date DATE, value VARCHAR>>>",
+            "STRUCT<elements ARRAY<STRUCT<date DATE, value NVARCHAR(MAX)>>>",
+            "Tuple(elements Array(Tuple(date Nullable(Date), value Nullable(String))))",
         ),
         (
             line!(),
@@ -928,6 +1422,8 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "MAP<VARCHAR, INT>",
             "MAP<STRING, INT>",
             "MAP<VARCHAR, INT>",
+            "MAP<NVARCHAR(MAX), INT>",
+            "Map(String, Nullable(Int32))",
         ),
         (
             line!(),
@@ -937,8 +1433,53 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "VARIANT",
             "VARIANT",
             "VARIANT",
+            "VARIANT",
+            "VARIANT",
+        ),
+        (
+            line!(),
+            Uuid,
+            "STRING",
+            "VARCHAR",
+            "UUID",
+            "STRING",
+            "UUID",
+            "UNIQUEIDENTIFIER",
+            "UUID",
+        ),
+        (
+            line!(),
+            Inet,
+            "STRING",
+            "VARCHAR",
+            "INET",
+            "STRING",
+            "INET",
+            "INET",
+            "String",
+        ),
+        (
+            line!(),
+            Cidr,
+            "STRING",
+            "VARCHAR",
+            "CIDR",
+            "STRING",
+            "CIDR",
+            "CIDR",
+            "String",
+        ),
+        (
+            line!(),
+            Void,
+            "VOID",
+            "VOID",
+            "VOID",
+            "VOID",
+            "VOID",
+            "VOID",
+            "Nothing",
         ),
-        (line!(), Void, "VOID", "VOID", "VOID", "VOID", "VOID"),
         (
             line!(),
             Other("ANY OTHER TYPE".to_string()),
@@ -947,17 +1488,21 @@ fn expected_type_rendering_for(backend: Backend) -> Vec<(u32, SqlType, &'static
             "ANY OTHER TYPE",
             "ANY OTHER TYPE",
             "ANY OTHER TYPE",
+            "ANY OTHER TYPE",
+            "ANY OTHER TYPE",
         ),
     ];
     let zipped = sqltype_bg_generic_snow_table
         .into_iter()
-        .map(|(line, t, bq, snow, pq, dbx, generic)| {
+        .map(|(line, t, bq, snow, pq, dbx, generic, mssql, clickhouse)| {
             let s = match backend {
                 BigQuery => bq,
                 Snowflake => snow,
                 Postgres | Redshift | RedshiftODBC | Salesforce => pq,
                 Databricks | DatabricksODBC => dbx,
                 Generic { .. } => generic,
+                MSSQL => mssql,
+                ClickHouse => clickhouse,
             };
             (line, t, s)
         })
@@ -974,6 +1519,37 @@ fn test_string_roundtrip_for_all_types_on_all_backends() {
     }
 }
 
+#[test]
+fn test_nullability_roundtrip_for_all_types_on_all_backends() {
+    for backend in backends() {
+        for (line, t, s) in expected_type_rendering_for(backend) {
+            for nullable in [true, false] {
+                let rendered = t.to_string_with_nullability(backend, nullable, false);
+                // ClickHouse can't express nullable composite types (or JSON)
+                let expected_nullable = nullable
+                    && !(backend == ClickHouse
+                        && matches!(
+                            t,
+                            Array(_) | NonNullArray(_) | Struct(_) | Row(_) | Map(_) | Json
+                        ));
+                let (parsed, parsed_nullable) = SqlType::parse(backend, &rendered).unwrap();
+                assert_eq!(
+                    parsed.to_string(backend),
+                    s,
+                    "parsing: {rendered} ({backend}) from {}:{line}",
+                    file!()
+                );
+                assert_eq!(
+                    parsed_nullable,
+                    expected_nullable,
+                    "parsing: {rendered} ({backend}) from {}:{line}",
+                    file!()
+                );
+            }
+        }
+    }
+}
+
 #[test]
 fn test_roundtrip_struct_with_quoted_field() {
     // the quote style carried on the SqlType depends on the backend
@@ -999,10 +1575,35 @@ fn test_roundtrip_struct_with_quoted_field() {
     }
 }
 
-/// This test makes it easier to attach a debugger and step through
-/// a specific function call compared to `test_string_roundtrip_for_all_types_on_all_backends`.
 #[test]
-fn test_timestamp_on_databricks() {
+fn test_postgres_struct_with_not_null_array_field() {
+    let t = Struct(Some(vec![
+        StructField::new(Ident::plain("a"), Array(Some(Box::new(Integer))), false),
+        StructField::new(
+            Ident::plain("b"),
+            Array(Some(Box::new(Array(Some(Box::new(Integer)))))),
+            true,
+        ),
+    ]));
+    assert_roundtrip(line!(), &t, "(a INT[] NOT NULL, b INT[][])", Postgres);
+
+    // the `[]` suffix binds to the type, before the constraint is read
+    let (ty, nullable) = SqlType::parse(Postgres, "(a INTEGER[] NOT NULL)").unwrap();
+    assert!(nullable);
+    let Struct(Some(fields)) = ty else {
+        panic!("{ty:?}");
+    };
+    assert_eq!(fields.len(), 1);
+    assert!(!fields[0].nullable);
+    assert!(matches!(&fields[0].sql_type, Array(Some(inner)) if matches!(**inner, Integer)));
+
+    assert!(SqlType::parse(Postgres, "(a INTEGER NOT NULL[])").is_err());
+}
+
+/// This test makes it easier to attach a debugger and step through
+/// a specific function call compared to `test_string_roundtrip_for_all_types_on_all_backends`.
+#[test]
+fn test_timestamp_on_databricks() {
     let s = "TIMESTAMP";
     let t = Timestamp {
         precision: None,
@@ -1058,3 +1659,1376 @@ fn test_struct_on_databricks() {
         assert_eq!(fields[2].name(), "active");
     }
 }
+
+#[test]
+fn test_field_type() {
+    let table = vec![
+        (
+            line!(),
+            BigQuery,
+            "STRUCT<last_completion_time TIMESTAMP, error_time TIMESTAMP, error STRUCT<reason STRING, location STRING, message STRING>>",
+        ),
+        (
+            line!(),
+            Snowflake,
+            "OBJECT(last_completion_time TIMESTAMP_NTZ, error_time TIMESTAMP_NTZ, error OBJECT(reason VARCHAR, location VARCHAR, message VARCHAR))",
+        ),
+        (
+            line!(),
+            Postgres,
+            "(last_completion_time TIMESTAMP, error_time TIMESTAMP, error (reason VARCHAR, location VARCHAR, message VARCHAR))",
+        ),
+        (
+            line!(),
+            Databricks,
+            "STRUCT<last_completion_time: TIMESTAMP_NTZ, error_time: TIMESTAMP_NTZ, error: STRUCT<reason: STRING, location: STRING, message: STRING>>",
+        ),
+    ];
+    for (line, backend, input) in table {
+        let (ty, _) = SqlType::parse(backend, input).unwrap();
+        let reason = ty.field_type(&["error", "reason"]);
+        assert!(
+            matches!(reason, Some(Varchar(None, _))),
+            "{reason:?} ({backend}) from {}:{line}",
+            file!()
+        );
+        // plain identifiers are matched case-insensitively
+        assert!(ty.field_type(&["ERROR", "Reason"]).is_some());
+        assert!(matches!(ty.field_type(&[]), Some(Struct(_))));
+        assert!(matches!(ty.field_type(&["error"]), Some(Struct(_))));
+        assert!(ty.field_type(&["error", "missing"]).is_none());
+        assert!(ty.field_type(&["error", "reason", "deeper"]).is_none());
+    }
+
+    let quoted = Struct(Some(vec![StructField::new(
+        Ident::unquoted(canonical_quote(Snowflake), "Age"),
+        Integer,
+        true,
+    )]));
+    assert!(matches!(quoted.field_type(&["Age"]), Some(Integer)));
+    assert!(quoted.field_type(&["AGE"]).is_none());
+}
+
+#[test]
+fn test_complex_type_accessors() {
+    let (array, _) = SqlType::parse(Databricks, "ARRAY<BIGINT>").unwrap();
+    assert!(matches!(array.element_type(), Some(BigInt)));
+    assert!(array.key_type().is_none());
+    assert!(array.fields().is_none());
+
+    let (map, _) = SqlType::parse(Databricks, "MAP<STRING, DOUBLE>").unwrap();
+    assert!(matches!(map.key_type(), Some(Varchar(None, _))));
+    assert!(matches!(map.element_type(), Some(Double)));
+    assert!(map.fields().is_none());
+
+    let (st, _) = SqlType::parse(Databricks, "STRUCT<a: INT, b: ARRAY<DATE>>").unwrap();
+    let fields = st.fields().unwrap();
+    assert_eq!(fields.len(), 2);
+    assert_eq!(fields[0].name.to_string_lossy(), "a");
+    assert!(matches!(fields[0].sql_type, Integer));
+    assert!(matches!(fields[1].sql_type.element_type(), Some(Date)));
+    assert!(st.element_type().is_none());
+    assert!(st.key_type().is_none());
+
+    // untyped and scalar types have nothing to descend into
+    for ty in [Array(None), Map(None), Struct(None), Integer, Json] {
+        assert!(ty.element_type().is_none(), "{ty:?}");
+        assert!(ty.key_type().is_none(), "{ty:?}");
+        assert!(ty.fields().is_none(), "{ty:?}");
+    }
+}
+
+#[test]
+fn test_bigquery_rejects_nested_arrays() {
+    let (ty, _) = SqlType::parse(BigQuery, "ARRAY<ARRAY<INT64>>").unwrap();
+    let err = ty.validate(BigQuery).unwrap_err();
+    assert!(err.contains("ARRAY<ARRAY<INT64>>"), "{err}");
+    assert!(err.contains("ARRAY<STRUCT<x ARRAY<INT64>>>"), "{err}");
+    // also when the nested array is inside a struct
+    let (ty, _) = SqlType::parse(BigQuery, "STRUCT<a ARRAY<ARRAY<STRING>>>").unwrap();
+    assert!(ty.validate(BigQuery).is_err());
+
+    let (ty, _) = SqlType::parse(BigQuery, "ARRAY<STRUCT<x ARRAY<INT64>>>").unwrap();
+    assert_eq!(ty.validate(BigQuery), Ok(()));
+
+    // other backends are permissive
+    let ty = Array(Some(Box::new(Array(Some(Box::new(Integer))))));
+    for backend in backends().into_iter().filter(|b| *b != BigQuery) {
+        assert_eq!(ty.validate(backend), Ok(()), "{backend}");
+    }
+}
+
+#[test]
+fn test_validate_time_with_time_zone() {
+    // parsing is lenient...
+    let (ty, _) = SqlType::parse(Snowflake, "TIME WITH TIME ZONE").unwrap();
+    assert_eq!(ty.to_string(Snowflake), "TIME WITH TIME ZONE");
+    // ...but validation is not
+    let err = ty.validate(Snowflake).unwrap_err();
+    assert!(err.contains("TIME WITH TIME ZONE"), "{err}");
+    let (ty, _) = SqlType::parse(Snowflake, "TIME(3) WITH LOCAL TIME ZONE").unwrap();
+    assert!(ty.validate(Snowflake).is_err());
+    let (ty, _) = SqlType::parse(Snowflake, "ARRAY(TIME WITH TIME ZONE)").unwrap();
+    assert!(ty.validate(Snowflake).is_err());
+
+    for input in [
+        "TIME",
+        "TIME(9)",
+        "TIMESTAMP_TZ",
+        "TIMESTAMP WITH TIME ZONE",
+    ] {
+        let (ty, _) = SqlType::parse(Snowflake, input).unwrap();
+        assert_eq!(ty.validate(Snowflake), Ok(()), "{input}");
+    }
+    // Postgres has TIMETZ
+    let (ty, _) = SqlType::parse(Postgres, "TIME WITH TIME ZONE").unwrap();
+    assert_eq!(ty.validate(Postgres), Ok(()));
+}
+
+#[test]
+fn test_negative_numeric_scale() {
+    let generic = Generic {
+        library_name: "generic",
+        entrypoint: None,
+    };
+    let ty = Numeric(Some((10, Some(-2))));
+    for backend in [Postgres, generic] {
+        assert_roundtrip(line!(), &ty, "NUMERIC(10, -2)", backend);
+        assert_eq!(ty.validate(backend), Ok(()), "{backend}");
+        // Arrow decimals have a signed scale too
+        let data_type = ty.pick_best_arrow_type(backend);
+        assert_eq!(data_type, DataType::Decimal128(10, -2));
+        assert!(matches!(
+            SqlType::_from_arrow_type(backend, &data_type),
+            Numeric(Some((10, Some(-2))))
+        ));
+    }
+
+    // Snowflake parses it, but has no negative scales
+    let (ty, _) = SqlType::parse(Snowflake, "NUMBER(10, -2)").unwrap();
+    assert_eq!(ty.to_string(Snowflake), "NUMBER(10, -2)");
+    let err = ty.validate(Snowflake).unwrap_err();
+    assert!(err.contains("negative scale"), "{err}");
+    for backend in [BigQuery, Databricks, MSSQL] {
+        assert!(ty.validate(backend).is_err(), "{backend}");
+    }
+    let (ty, _) = SqlType::parse(Snowflake, "NUMBER(10, 2)").unwrap();
+    assert_eq!(ty.validate(Snowflake), Ok(()));
+}
+
+#[test]
+fn test_interval_leading_precision() {
+    // Oracle-like backends keep the leading field precision
+    let generic = Generic {
+        library_name: "generic",
+        entrypoint: None,
+    };
+    let ty = Interval(Some((Day, Some(Microsecond))), Some(3));
+    assert_roundtrip(line!(), &ty, "INTERVAL DAY(3) TO SECOND(6)", generic);
+    for (input, expected) in [
+        ("interval year(4) to month", "INTERVAL YEAR(4) TO MONTH"),
+        ("INTERVAL DAY(2)", "INTERVAL DAY(2)"),
+        ("INTERVAL DAY(9) TO SECOND", "INTERVAL DAY(9) TO SECOND"),
+    ] {
+        let (parsed, _) = SqlType::parse(generic, input).unwrap();
+        assert_eq!(parsed.to_string(generic), expected);
+    }
+
+    // other backends drop it
+    let (parsed, _) = SqlType::parse(Postgres, "INTERVAL DAY(3) TO SECOND(6)").unwrap();
+    assert!(matches!(
+        parsed,
+        Interval(Some((Day, Some(Microsecond))), Some(3))
+    ));
+    assert_eq!(parsed.to_string(Postgres), "INTERVAL DAY TO SECOND(6)");
+    assert_eq!(
+        parsed.pick_best_arrow_type(Postgres),
+        Interval(Some((Day, Some(Microsecond))), None).pick_best_arrow_type(Postgres)
+    );
+
+    // a precision after SECOND is still the fractional seconds precision
+    let (parsed, _) = SqlType::parse(generic, "INTERVAL SECOND(3)").unwrap();
+    assert!(matches!(parsed, Interval(Some((Millisecond, None)), None)));
+}
+
+#[test]
+fn test_timestamp_utc_offset() {
+    let generic = Generic {
+        library_name: "generic",
+        entrypoint: None,
+    };
+    for (input, minutes, arrow_tz) in [
+        ("TIMESTAMP(3) +05:00", 300, "+05:00"),
+        ("TIMESTAMP(3) -03:30", -210, "-03:30"),
+    ] {
+        let (ty, _) = SqlType::parse(generic, input).unwrap();
+        assert!(
+            matches!(
+                ty,
+                Timestamp {
+                    precision: Some(3),
+                    time_zone_spec: TimeZoneSpec::Offset(m),
+                } if m == minutes
+            ),
+            "{ty:?}"
+        );
+        assert_roundtrip(line!(), &ty, input, generic);
+        let Timestamp { time_zone_spec, .. } = &ty else {
+            unreachable!()
+        };
+        for backend in backends() {
+            assert!(
+                time_zone_spec.is_with_time_zone(backend),
+                "{input} should be with time zone on {backend}"
+            );
+        }
+        assert_eq!(
+            ty.pick_best_arrow_type(generic),
+            DataType::Timestamp(arrow_schema::TimeUnit::Millisecond, Some(arrow_tz.into()))
+        );
+
+        // backends without offsets in types fall back to WITH TIME ZONE
+        assert_eq!(ty.to_string(Postgres), "TIMESTAMP(3) WITH TIME ZONE");
+        assert_eq!(ty.to_string(Snowflake), "TIMESTAMP_TZ(3)");
+        assert_eq!(ty.to_string(MSSQL), "DATETIMEOFFSET(3)");
+    }
+
+    let (ty, _) = SqlType::parse(generic, "TIME +01:00").unwrap();
+    assert_eq!(ty.to_string(generic), "TIME +01:00");
+    assert!(SqlType::parse(generic, "TIMESTAMP +05").is_err());
+
+    for input in [
+        "TIMESTAMP +15:00",
+        "TIMESTAMP -14:30",
+        "TIMESTAMP +600:00",
+        "TIMESTAMP +9999:00",
+        "TIMESTAMP -05:60",
+        "TIMESTAMP +05:99",
+        "TIMESTAMP +05:-30",
+    ] {
+        assert!(SqlType::parse(generic, input).is_err(), "{input}");
+    }
+    // the largest offsets in use
+    let (ty, _) = SqlType::parse(generic, "TIMESTAMP +14:00").unwrap();
+    assert_roundtrip(line!(), &ty, "TIMESTAMP +14:00", generic);
+    let (ty, _) = SqlType::parse(generic, "TIMESTAMP -12:00").unwrap();
+    assert_roundtrip(line!(), &ty, "TIMESTAMP -12:00", generic);
+}
+
+#[test]
+fn test_serde_roundtrip() {
+    let ty = Struct(Some(vec![
+        StructField::new(
+            Ident::plain("tags"),
+            Array(Some(Box::new(Varchar(
+                Some(20),
+                StringAttrs {
+                    collate_spec: Some("UNICODE_CI".to_string()),
+                    ..Default::default()
+                },
+            )))),
+            true,
+        ),
+        StructField::new(
+            Ident::unquoted(QuotingStyle::Double, "Created At"),
+            Timestamp {
+                precision: Some(3),
+                time_zone_spec: TimeZoneSpec::With,
+            },
+            false,
+        )
+        .with_comment("'when'".to_string()),
+        StructField::new(
+            Ident::plain("ttl"),
+            Interval(Some((Day, Some(Second))), None),
+            true,
+        ),
+    ]));
+
+    let json = serde_json::to_value(&ty).unwrap();
+    assert_eq!(json["kind"], "Struct");
+    assert_eq!(json["value"][0]["sql_type"]["kind"], "Array");
+    assert_eq!(json["value"][1]["name"]["kind"], "Unquoted");
+
+    let restored: SqlType = serde_json::from_value(json).unwrap();
+    for backend in backends() {
+        assert_eq!(
+            restored.to_string(backend),
+            ty.to_string(backend),
+            "{backend}"
+        );
+    }
+    let Struct(Some(fields)) = &restored else {
+        panic!("expected a struct, got {restored:?}");
+    };
+    assert!(
+        matches!(&fields[1].name, Ident::Unquoted(QuotingStyle::Double, s) if s == "Created At")
+    );
+    assert!(!fields[1].nullable);
+}
+
+#[test]
+fn test_render_all_backends() {
+    let ty = Timestamp {
+        precision: Some(3),
+        time_zone_spec: TimeZoneSpec::With,
+    };
+    let rendered = ty.render_all_backends();
+    for backend in backends() {
+        let entries: Vec<_> = rendered.iter().filter(|(b, _)| *b == backend).collect();
+        assert_eq!(entries.len(), 1, "{backend}: {rendered:?}");
+        assert_eq!(entries[0].1, ty.to_string(backend));
+    }
+    let lookup = |backend| &rendered.iter().find(|(b, _)| *b == backend).unwrap().1;
+    assert_eq!(lookup(Snowflake), "TIMESTAMP_TZ(3)");
+    assert_eq!(lookup(Postgres), "TIMESTAMP(3) WITH TIME ZONE");
+    assert_eq!(lookup(Redshift), "TIMESTAMP(3) WITH TIME ZONE");
+    assert!(rendered.iter().any(|(b, _)| *b == Salesforce));
+}
+
+#[test]
+fn test_empty_type_specifications() {
+    for (backend, input, message) in [
+        (Databricks, "MAP<>", "MAP needs a key and a value type"),
+        (
+            Databricks,
+            "MAP<STRING>",
+            "MAP needs a key and a value type",
+        ),
+        (
+            Databricks,
+            "MAP<STRING, >",
+            "MAP needs a key and a value type",
+        ),
+        (BigQuery, "ARRAY<>", "ARRAY needs exactly one element type"),
+        (BigQuery, "ARRAY<,>", "ARRAY needs exactly one element type"),
+        (
+            BigQuery,
+            "ARRAY<INT64, STRING>",
+            "ARRAY needs exactly one element type",
+        ),
+        (Snowflake, "ARRAY()", "ARRAY needs exactly one element type"),
+    ] {
+        let err = SqlType::parse(backend, input).unwrap_err();
+        assert!(err.contains(message), "{input} ({backend}): {err}");
+    }
+
+    // an empty struct is valid
+    for (backend, input) in [(Databricks, "STRUCT<>"), (Snowflake, "OBJECT()")] {
+        let (ty, _) = SqlType::parse(backend, input).unwrap();
+        assert!(
+            matches!(&ty, Struct(Some(fields)) if fields.is_empty()),
+            "{input} ({backend}): {ty:?}"
+        );
+    }
+}
+
+#[test]
+fn test_databricks_array_element_nullability() {
+    for backend in [Databricks, DatabricksODBC] {
+        assert_roundtrip(
+            line!(),
+            &Array(Some(Box::new(Integer))),
+            "ARRAY<INT>",
+            backend,
+        );
+        assert_roundtrip(
+            line!(),
+            &NonNullArray(Box::new(Integer)),
+            "ARRAY<INT NOT NULL>",
+            backend,
+        );
+        assert_roundtrip(
+            line!(),
+            &NonNullArray(Box::new(Array(Some(Box::new(Integer))))),
+            "ARRAY<ARRAY<INT> NOT NULL>",
+            backend,
+        );
+    }
+
+    let (ty, nullable) = SqlType::parse(Databricks, "array<int not null> not null").unwrap();
+    assert!(matches!(&ty, NonNullArray(inner) if matches!(**inner, Integer)));
+    assert!(!nullable);
+
+    // the element nullability is carried by the Arrow list item
+    let DataType::List(item) = ty.pick_best_arrow_type(Databricks) else {
+        panic!("expected a list");
+    };
+    assert!(!item.is_nullable());
+    let DataType::List(item) = Array(Some(Box::new(Integer))).pick_best_arrow_type(Databricks)
+    else {
+        panic!("expected a list");
+    };
+    assert!(item.is_nullable());
+
+    // other backends can't declare non-nullable elements
+    assert_eq!(ty.to_string(BigQuery), "ARRAY<INT64>");
+    assert_eq!(ty.to_string(Postgres), "INT[]");
+    assert!(SqlType::parse(BigQuery, "ARRAY<INT64 NOT NULL>").is_err());
+}
+
+#[test]
+fn test_reparse_other_types() {
+    let ty = Other("UUID".to_string()).reparse(Postgres);
+    assert!(matches!(ty, Uuid), "{ty:?}");
+    let ty = Other("varchar(10)".to_string()).reparse(Postgres);
+    assert_eq!(ty.to_string(Postgres), "VARCHAR(10)");
+
+    // still unknown
+    let ty = Other("TSVECTOR".to_string()).reparse(Postgres);
+    assert!(matches!(&ty, Other(s) if s == "TSVECTOR"), "{ty:?}");
+    // not a type at all
+    let ty = Other("(".to_string()).reparse(Postgres);
+    assert!(matches!(&ty, Other(s) if s == "("), "{ty:?}");
+
+    let ty = Struct(Some(vec![
+        StructField::new(
+            Ident::plain("ids"),
+            Array(Some(Box::new(Other("UUID".to_string())))),
+            true,
+        ),
+        StructField::new(Ident::plain("n"), Integer, false),
+    ]));
+    assert_eq!(
+        ty.reparse(Databricks).to_string(Databricks),
+        "STRUCT<ids: ARRAY<STRING>, n: INT NOT NULL>"
+    );
+    let ty = ty.reparse(Databricks);
+    let Struct(Some(fields)) = &ty else {
+        panic!("{ty:?}");
+    };
+    assert!(matches!(&fields[0].sql_type, Array(Some(inner)) if matches!(**inner, Uuid)));
+}
+
+#[test]
+fn test_boolean_as_smallint_compat() {
+    let compat = CompatOptions {
+        boolean_as_smallint: true,
+    };
+    assert_eq!(Boolean.to_string(Redshift), "BOOLEAN");
+    assert_eq!(
+        Boolean.to_string_compat(Redshift, CompatOptions::default()),
+        "BOOLEAN"
+    );
+    assert_eq!(Boolean.to_string_compat(Redshift, compat), "SMALLINT");
+    assert_eq!(Integer.to_string_compat(Redshift, compat), "INT");
+
+    let ty = Array(Some(Box::new(Boolean)));
+    assert_eq!(ty.to_string_compat(Postgres, compat), "SMALLINT[]");
+    assert_eq!(ty.to_string(Postgres), "BOOLEAN[]");
+    let ty = Struct(Some(vec![StructField::new(
+        Ident::plain("flag"),
+        Boolean,
+        false,
+    )]));
+    assert_eq!(
+        ty.to_string_compat(Databricks, compat),
+        "STRUCT<flag: SMALLINT NOT NULL>"
+    );
+}
+
+#[test]
+fn test_other_type_is_captured_verbatim() {
+    for backend in backends() {
+        let (ty, nullable) =
+            SqlType::parse(backend, "RASTER(Polygon, (4326)) extra[1] NOT NULL").unwrap();
+        assert!(
+            matches!(&ty, Other(s) if s == "RASTER(Polygon, (4326)) extra[1]"),
+            "{ty:?} ({backend})"
+        );
+        assert!(!nullable, "{backend}");
+    }
+
+    // the capture ends with the field in a struct
+    let (ty, _) = SqlType::parse(Snowflake, "OBJECT(a RASTER(1, 2), b INT)").unwrap();
+    let fields = ty.fields().unwrap();
+    assert!(matches!(&fields[0].sql_type, Other(s) if s == "RASTER(1, 2)"));
+    assert!(matches!(fields[1].sql_type, Integer));
+
+    let (ty, nullable, default) =
+        SqlType::parse_with_default(Postgres, "mood DEFAULT 'ok' NOT NULL").unwrap();
+    assert!(matches!(&ty, Other(s) if s == "mood"));
+    assert!(!nullable);
+    assert_eq!(default.as_deref(), Some("'ok'"));
+}
+
+#[test]
+fn test_nesting_limit() {
+    let nested = |levels: usize| {
+        format!(
+            "{}INT{}",
+            "ARRAY<".repeat(levels - 1),
+            ">".repeat(levels - 1)
+        )
+    };
+
+    let (ty, _) = SqlType::parse(BigQuery, &nested(MAX_NESTING_DEPTH)).unwrap();
+    assert_eq!(ty.depth(), MAX_NESTING_DEPTH);
+    let err = SqlType::parse(BigQuery, &nested(MAX_NESTING_DEPTH + 1)).unwrap_err();
+    assert!(err.contains("nested more than 128 levels deep"), "{err}");
+
+    // far deeper than the stack could handle without the limit
+    let err = SqlType::parse(BigQuery, &nested(100_000)).unwrap_err();
+    assert!(err.contains("nested more than"), "{err}");
+
+    let err = SqlType::parse_with_nesting_limit(Snowflake, "OBJECT(a ARRAY(INT))", 2).unwrap_err();
+    assert!(err.contains("nested more than 2 levels deep"), "{err}");
+    let (ty, _) = SqlType::parse_with_nesting_limit(Snowflake, "OBJECT(a ARRAY(INT))", 3).unwrap();
+    assert_eq!(ty.depth(), 3);
+
+    assert_eq!(Integer.depth(), 1);
+    assert_eq!(Array(None).depth(), 1);
+    assert_eq!(Struct(Some(vec![])).depth(), 1);
+    let map = Map(Some((
+        Box::new(Integer),
+        Box::new(Array(Some(Box::new(Integer)))),
+    )));
+    assert_eq!(map.depth(), 3);
+}
+
+#[test]
+fn test_snowflake_semi_structured_types() {
+    let table = vec![
+        (line!(), Array(Some(Box::new(Integer))), "ARRAY(INT)"),
+        (
+            line!(),
+            Struct(Some(vec![StructField::new(
+                Ident::plain("a"),
+                SqlType::varchar(None),
+                true,
+            )])),
+            "OBJECT(a VARCHAR)",
+        ),
+        (line!(), Array(None), "ARRAY"),
+        (line!(), Struct(None), "OBJECT"),
+    ];
+    for (line, ty, s) in table {
+        assert_roundtrip(line, &ty, s, Snowflake);
+    }
+    // INTEGER is an alias that renders as INT
+    assert_parses_to(
+        line!(),
+        "ARRAY(INTEGER)",
+        &Array(Some(Box::new(Integer))),
+        Snowflake,
+    );
+}
+
+#[test]
+fn test_verbose_timestamp_on_snowflake() {
+    // the SQL-standard spelling parses to the same type as Snowflake's
+    // TIMESTAMP_*TZ aliases and renders back in the canonical form
+    let table = vec![
+        (line!(), "TIMESTAMP(6) WITH TIME ZONE", "TIMESTAMP_TZ(6)"),
+        (line!(), "timestamp(6) with time zone", "TIMESTAMP_TZ(6)"),
+        (line!(), "TIMESTAMP WITH TIME ZONE", "TIMESTAMP_TZ"),
+        (
+            line!(),
+            "TIMESTAMP(6) WITH LOCAL TIME ZONE",
+            "TIMESTAMP_LTZ(6)",
+        ),
+        (
+            line!(),
+            "TIMESTAMP(6) WITHOUT TIME ZONE",
+            "TIMESTAMP_NTZ(6)",
+        ),
+    ];
+    for (line, input, expected) in table {
+        let (parsed, _nullable) = SqlType::parse(Snowflake, input).unwrap();
+        assert_eq!(
+            parsed.to_string(Snowflake),
+            expected,
+            "input: {input} from {}:{line}",
+            file!()
+        );
+        assert_parses_to(line, input, &parsed, Snowflake);
+        assert_parses_to(line, expected, &parsed, Snowflake);
+    }
+}
+
+#[test]
+fn test_resolve_timezone_on_snowflake() {
+    let unspecified = Timestamp {
+        precision: Some(3),
+        time_zone_spec: TimeZoneSpec::Unspecified,
+    };
+    let table = vec![
+        (line!(), TimestampPolicy::DefaultToNtz, "TIMESTAMP_NTZ(3)"),
+        (line!(), TimestampPolicy::DefaultToTz, "TIMESTAMP_TZ(3)"),
+    ];
+    for (line, policy, expected) in table {
+        let resolved = unspecified.resolve_timezone(Snowflake, policy).unwrap();
+        assert_eq!(
+            resolved.to_string(Snowflake),
+            expected,
+            "{policy:?} from {}:{line}",
+            file!()
+        );
+    }
+
+    let err = unspecified
+        .resolve_timezone(Snowflake, TimestampPolicy::Error)
+        .unwrap_err();
+    assert!(err.contains("ambiguous"), "{err}");
+
+    // nested timestamps are resolved too
+    let nested = Array(Some(Box::new(Struct(Some(vec![StructField::new(
+        Ident::plain("ts"),
+        unspecified.clone(),
+        true,
+    )])))));
+    let resolved = nested
+        .resolve_timezone(Snowflake, TimestampPolicy::DefaultToNtz)
+        .unwrap();
+    assert_eq!(
+        resolved.to_string(Snowflake),
+        "ARRAY(OBJECT(ts TIMESTAMP_NTZ(3)))"
+    );
+    assert!(
+        nested
+            .resolve_timezone(Snowflake, TimestampPolicy::Error)
+            .is_err()
+    );
+
+    // explicit time zone specs are left alone
+    let with = Timestamp {
+        precision: None,
+        time_zone_spec: TimeZoneSpec::Local,
+    };
+    let resolved = with
+        .resolve_timezone(Snowflake, TimestampPolicy::Error)
+        .unwrap();
+    assert_eq!(resolved.to_string(Snowflake), "TIMESTAMP_LTZ");
+}
+
+#[test]
+fn test_unicode_escaped_idents_on_postgres() {
+    let table = vec![
+        (line!(), r#"(U&"d\0061t\+000061" INTEGER)"#, "data"),
+        (line!(), r#"(u&"\0441\043B\043E\043D" INTEGER)"#, "слон"),
+        (
+            line!(),
+            r#"(U&"d!0061t!+000061" UESCAPE '!' INTEGER)"#,
+            "data",
+        ),
+        (line!(), r#"(U&"a\\b" INTEGER)"#, r"a\b"),
+        (line!(), r#"(U&"a!!b\" UESCAPE '!' INTEGER)"#, r"a!b\"),
+        (line!(), r#"(U&"a""b" INTEGER)"#, r#"a"b"#),
+        (line!(), r#"(U&"\D83D\DE00" INTEGER)"#, "😀"),
+    ];
+    for backend in [Postgres, Redshift, RedshiftODBC] {
+        for (line, input, expected_name) in &table {
+            let (ty, _) = SqlType::parse(backend, input).unwrap();
+            let Struct(Some(fields)) = &ty else {
+                panic!("expected a struct: {ty:?} from {}:{line}", file!());
+            };
+            assert_eq!(
+                fields[0].name.as_ref(),
+                *expected_name,
+                "input: {input} ({backend}) from {}:{line}",
+                file!()
+            );
+            assert!(matches!(fields[0].sql_type, Integer));
+        }
+    }
+
+    let (ty, _) = SqlType::parse(Postgres, r#"(U&"d\0061t\+000061" INTEGER)"#).unwrap();
+    assert_eq!(ty.to_string(Postgres), r#"("data" INT)"#);
+
+    let invalid = [
+        r#"(U&"\006" INTEGER)"#,
+        r#"(U&"\+00006" INTEGER)"#,
+        r#"(U&"\D83D" INTEGER)"#,
+        r#"(U&"a" UESCAPE 'a' INTEGER)"#,
+        r#"(U&"a" UESCAPE '!!' INTEGER)"#,
+        r#"(U&"a INTEGER)"#,
+    ];
+    for input in invalid {
+        assert!(SqlType::parse(Postgres, input).is_err(), "input: {input}");
+    }
+}
+
+#[test]
+fn test_escaped_quotes_in_idents() {
+    let table = vec![
+        (line!(), BigQuery, "STRUCT<`a``b` INT64>", "a`b"),
+        (line!(), Databricks, "STRUCT<`a``b`: INT>", "a`b"),
+        (line!(), MSSQL, "STRUCT<[a]]b] INT>", "a]b"),
+        (line!(), MSSQL, "STRUCT<[my col] INT, [x] BIT>", "my col"),
+    ];
+    for (line, backend, input, expected_name) in table {
+        let (ty, _) = SqlType::parse(backend, input).unwrap();
+        let Struct(Some(fields)) = &ty else {
+            panic!("expected a struct: {ty:?} from {}:{line}", file!());
+        };
+        assert_eq!(
+            fields[0].name.as_ref(),
+            expected_name,
+            "input: {input} ({backend}) from {}:{line}",
+            file!()
+        );
+        // escaping is restored when rendering
+        assert_eq!(ty.to_string(backend), input, "from {}:{line}", file!());
+    }
+
+    // brackets are not quotes on other backends
+    assert!(SqlType::parse(Postgres, "([a] INT)").is_err());
+}
+
+#[test]
+fn test_map_from_arrow_type() {
+    let entries = Field::new(
+        "entries",
+        DataType::Struct(Fields::from(vec![
+            Field::new("key", DataType::Utf8, false),
+            Field::new("value", DataType::Int32, true),
+        ])),
+        false,
+    );
+    let expected = Map(Some((Box::new(SqlType::varchar(None)), Box::new(Integer))));
+    for sorted in [false, true] {
+        let data_type = DataType::Map(Arc::new(entries.clone()), sorted);
+        for backend in backends() {
+            let ty = SqlType::_from_arrow_type(backend, &data_type);
+            assert_eq!(
+                ty.to_string(backend),
+                expected.to_string(backend),
+                "sorted: {sorted} ({backend})"
+            );
+            assert!(
+                matches!(&ty, Map(Some((k, v))) if matches!(**k, Varchar(None, _)) && matches!(**v, Integer)),
+                "{ty:?}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_from_arrow_type_with_encoding() {
+    let data_type = DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8));
+    for backend in backends() {
+        let (ty, dictionary_encoded) = SqlType::from_arrow_type_with_encoding(backend, &data_type);
+        assert!(matches!(ty, Varchar(None, _)), "{ty:?} ({backend})");
+        assert!(dictionary_encoded);
+        // the default mapping is the same
+        assert_eq!(
+            SqlType::_from_arrow_type(backend, &data_type).to_string(backend),
+            ty.to_string(backend)
+        );
+
+        let (ty, dictionary_encoded) =
+            SqlType::from_arrow_type_with_encoding(backend, &DataType::Utf8);
+        assert!(matches!(ty, Varchar(None, _)), "{ty:?} ({backend})");
+        assert!(!dictionary_encoded);
+    }
+
+    let data_type = DataType::Dictionary(
+        Box::new(DataType::UInt8),
+        Box::new(DataType::Decimal128(10, 2)),
+    );
+    let (ty, dictionary_encoded) = SqlType::from_arrow_type_with_encoding(Postgres, &data_type);
+    assert!(matches!(ty, Numeric(Some((10, Some(2))))), "{ty:?}");
+    assert!(dictionary_encoded);
+}
+
+#[test]
+fn test_fixed_size_list_from_arrow_type() {
+    let item = Field::new("item", DataType::Int32, true);
+    let data_type = DataType::FixedSizeList(Arc::new(item), 4);
+    for backend in backends() {
+        let ty = SqlType::_from_arrow_type(backend, &data_type);
+        assert!(
+            matches!(&ty, Array(Some(inner)) if matches!(**inner, Integer)),
+            "{ty:?} ({backend})"
+        );
+    }
+}
+
+#[test]
+fn test_struct_from_arrow_type_quotes_field_names() {
+    let data_type = DataType::Struct(Fields::from(vec![
+        Field::new("weird name", DataType::Int32, true),
+        Field::new("id", DataType::Int32, true),
+    ]));
+    for backend in backends() {
+        let ty = SqlType::_from_arrow_type(backend, &data_type);
+        let Struct(Some(fields)) = &ty else {
+            panic!("expected a struct, got {ty:?} ({backend})");
+        };
+        assert!(matches!(&fields[0].name, Ident::Unquoted(..)), "{ty:?}");
+        assert!(matches!(&fields[1].name, Ident::Plain(_)), "{ty:?}");
+
+        let q = quote_char(backend);
+        let rendered = ty.to_string(backend);
+        assert!(
+            rendered.contains(&format!("{q}weird name{q}")),
+            "{rendered} ({backend})"
+        );
+    }
+
+    for backend in [Postgres, Snowflake, BigQuery, Databricks] {
+        let rendered = SqlType::_from_arrow_type(backend, &data_type).to_string(backend);
+        let (parsed, _) = SqlType::parse(backend, &rendered).unwrap();
+        let Struct(Some(fields)) = &parsed else {
+            panic!("expected a struct, got {parsed:?} from {rendered} ({backend})");
+        };
+        assert_eq!(
+            fields[0].name.as_ref(),
+            "weird name",
+            "{rendered} ({backend})"
+        );
+        assert_eq!(fields[1].name.as_ref(), "id", "{rendered} ({backend})");
+    }
+}
+
+#[test]
+fn test_unsigned_integers() {
+    let generic = Generic {
+        library_name: "generic",
+        entrypoint: None,
+    };
+    let table = vec![
+        (line!(), "TINYINT UNSIGNED", 8, "SMALLINT"),
+        (line!(), "SMALLINT UNSIGNED", 16, "INT"),
+        (line!(), "INT UNSIGNED", 32, "BIGINT"),
+        (line!(), "BIGINT UNSIGNED", 64, "NUMERIC(20, 0)"),
+    ];
+    for (line, input, bits, widened) in table {
+        let (ty, _) = SqlType::parse(generic, input).unwrap();
+        assert!(
+            matches!(ty, UnsignedInteger(b) if b == bits),
+            "{ty:?} from {}:{line}",
+            file!()
+        );
+        assert_roundtrip(line, &ty, input, generic);
+        let (ty, _) = SqlType::parse(generic, &input.to_lowercase()).unwrap();
+        assert!(
+            matches!(ty, UnsignedInteger(b) if b == bits),
+            "{ty:?} from {}:{line}",
+            file!()
+        );
+        // backends without unsigned integers use a signed type that holds the whole range
+        assert_eq!(ty.to_string(Postgres), widened, "from {}:{line}", file!());
+    }
+    // signed by default
+    assert_roundtrip(line!(), &Integer, "INT", generic);
+    assert_roundtrip(line!(), &BigInt, "BIGINT", generic);
+    let (_, nullable) = SqlType::parse(Postgres, "INT UNSIGNED NOT NULL").unwrap();
+    assert!(!nullable);
+
+    // UInt64 values above i64::MAX don't fit in a BIGINT
+    let field = Field::new("n", DataType::UInt64, true);
+    for backend in backends() {
+        let (ty, _) = SqlType::from_field(backend, &field).unwrap();
+        assert!(matches!(ty, UnsignedInteger(64)), "{ty:?} ({backend})");
+    }
+    assert_eq!(UnsignedInteger(64).to_string(Postgres), "NUMERIC(20, 0)");
+    let field = Field::new("n", DataType::UInt32, true);
+    let (ty, _) = SqlType::from_field(Postgres, &field).unwrap();
+    assert!(matches!(ty, UnsignedInteger(32)), "{ty:?}");
+    assert_eq!(ty.pick_best_arrow_type(generic), DataType::UInt32);
+    assert_eq!(ty.pick_best_arrow_type(Postgres), DataType::Int64);
+}
+
+#[test]
+fn test_from_arrow_schema() {
+    let backend = Snowflake;
+    let schema = Schema::new(vec![
+        Integer.to_field(backend, "id".to_string(), false),
+        SqlType::varchar(Some(16)).to_field(backend, "name".to_string(), true),
+        // no SQL type in the metadata
+        Field::new("score", DataType::Float64, true),
+    ]);
+    let columns = SqlType::from_arrow_schema(backend, &schema).unwrap();
+    let columns = columns
+        .iter()
+        .map(|(name, ty, nullable)| (name.as_str(), ty.to_string(backend), *nullable))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        columns,
+        vec![
+            ("id", "INT".to_string(), false),
+            ("name", "VARCHAR(16)".to_string(), true),
+            ("score", "DOUBLE PRECISION".to_string(), true),
+        ]
+    );
+
+    let field = Field::new("bad", DataType::Utf8, true).with_metadata(
+        [(
+            metadata_sql_type_key(backend).to_string(),
+            "ARRAY(".to_string(),
+        )]
+        .into(),
+    );
+    let err = SqlType::from_arrow_schema(backend, &Schema::new(vec![field])).unwrap_err();
+    assert!(err.contains("'bad'"), "{err}");
+}
+
+#[test]
+fn test_network_address_field_roundtrip() {
+    let backend = Postgres;
+    let field = Inet.to_field(backend, "addr".to_string(), true);
+    assert_eq!(field.data_type(), &DataType::Utf8);
+    let (ty, nullable) = SqlType::from_field(backend, &field).unwrap();
+    assert!(matches!(ty, Inet), "{ty:?}");
+    assert!(nullable);
+
+    // the extension metadata is enough to recover the type when the
+    // SQL type string is missing from the field metadata
+    for (line, sql_type) in [(line!(), Inet), (line!(), Cidr)] {
+        let field = sql_type.to_field(backend, "addr".to_string(), true);
+        let mut metadata = field.metadata().clone();
+        metadata.remove(metadata_sql_type_key(backend));
+        let field = field.with_metadata(metadata);
+        let (ty, _) = SqlType::from_field(backend, &field).unwrap();
+        assert_eq!(
+            ty.to_string(backend),
+            sql_type.to_string(backend),
+            "from {}:{line}",
+            file!()
+        );
+    }
+
+    // plain strings are still strings
+    let field = Field::new("addr", DataType::Utf8, true);
+    let (ty, _) = SqlType::from_field(backend, &field).unwrap();
+    assert!(matches!(ty, Varchar(None, _)), "{ty:?}");
+}
+
+#[test]
+fn test_normalize() {
+    let table = vec![
+        (line!(), Snowflake, "number(10,0)", "NUMBER(10, 0)"),
+        (line!(), Snowflake, "  int  ", "INT"),
+        (line!(), Snowflake, "timestamp_ntz", "TIMESTAMP_NTZ"),
+        (line!(), BigQuery, "bool", "BOOL"),
+        (line!(), BigQuery, "float64", "FLOAT64"),
+        (line!(), BigQuery, "boolean", "BOOL"),
+        (line!(), Postgres, "int8", "BIGINT"),
+        (line!(), Postgres, "character varying(20)", "VARCHAR(20)"),
+        (line!(), Postgres, "integer not null", "INT NOT NULL"),
+        (line!(), Databricks, "string", "STRING"),
+        (line!(), Databricks, "decimal(10,2)", "DECIMAL(10, 2)"),
+    ];
+    for (line, backend, input, expected) in table {
+        let normalized = SqlType::normalize(backend, input).unwrap();
+        assert_eq!(
+            normalized,
+            expected,
+            "input: {input} ({backend}) from {}:{line}",
+            file!()
+        );
+    }
+}
+
+#[test]
+fn test_national_char_roundtrip() {
+    let table = vec![
+        (line!(), MSSQL, "NVARCHAR(10)", "NVARCHAR(10)"),
+        // SQL Server variable-length strings are always NVARCHAR
+        (line!(), MSSQL, "VARCHAR(10)", "NVARCHAR(10)"),
+        (line!(), MSSQL, "NVARCHAR(MAX)", "NVARCHAR(MAX)"),
+        (line!(), MSSQL, "NCHAR(5)", "NCHAR(5)"),
+        (line!(), MSSQL, "CHAR(5)", "CHAR(5)"),
+        (line!(), MSSQL, "NATIONAL CHAR VARYING(10)", "NVARCHAR(10)"),
+        (line!(), Snowflake, "NVARCHAR(10)", "NVARCHAR(10)"),
+        (line!(), Snowflake, "NCHAR", "NCHAR"),
+        // backends without national character types drop the prefix
+        (line!(), Postgres, "NVARCHAR(10)", "VARCHAR(10)"),
+        (line!(), BigQuery, "NVARCHAR(10)", "STRING"),
+    ];
+    for (line, backend, input, expected) in table {
+        let (ty, _) = SqlType::parse(backend, input).unwrap();
+        assert_eq!(
+            ty.to_string(backend),
+            expected,
+            "input: {input} ({backend}) from {}:{line}",
+            file!()
+        );
+    }
+}
+
+#[test]
+fn test_numeric_precision_only_arrow_type() {
+    for backend in backends() {
+        let ty = Numeric(Some((10, None)));
+        let data_type = ty.pick_best_arrow_type(backend);
+        assert_eq!(data_type, DataType::Decimal128(10, 0), "{backend}");
+
+        let back = SqlType::_from_arrow_type(backend, &data_type);
+        assert!(
+            matches!(back, Numeric(Some((10, Some(0))))),
+            "{back:?} ({backend})"
+        );
+        assert_eq!(back.pick_best_arrow_type(backend), data_type, "{backend}");
+
+        // an explicit scale is preserved
+        let back = SqlType::_from_arrow_type(backend, &DataType::Decimal128(10, 2));
+        assert!(
+            matches!(back, Numeric(Some((10, Some(2))))),
+            "{back:?} ({backend})"
+        );
+    }
+}
+
+#[test]
+fn test_structurally_equal_unordered() {
+    let a = Struct(Some(vec![
+        StructField::new(Ident::plain("id"), BigInt, false),
+        StructField::new(Ident::plain("name"), SqlType::varchar(None), true),
+    ]));
+    let b = Struct(Some(vec![
+        StructField::new(Ident::plain("name"), SqlType::varchar(None), true),
+        StructField::new(Ident::plain("id"), BigInt, false),
+    ]));
+    let c = Struct(Some(vec![
+        StructField::new(Ident::plain("name"), SqlType::varchar(None), true),
+        StructField::new(Ident::plain("id"), Integer, false),
+    ]));
+    for backend in backends() {
+        // positional comparison tells them apart...
+        assert_ne!(a.to_string(backend), b.to_string(backend), "{backend}");
+        // ...but they have the same fields
+        assert!(a.structurally_equal_unordered(&b, backend), "{backend}");
+        assert!(b.structurally_equal_unordered(&a, backend), "{backend}");
+        assert!(!a.structurally_equal_unordered(&c, backend), "{backend}");
+
+        let array_a = Array(Some(Box::new(a.clone())));
+        let array_b = Array(Some(Box::new(b.clone())));
+        assert!(
+            array_a.structurally_equal_unordered(&array_b, backend),
+            "{backend}"
+        );
+    }
+}
+
+#[test]
+fn test_geospatial_srid_field_roundtrip() {
+    let backend = Snowflake;
+    let table = vec![
+        // Snowflake geographies always use WGS 84
+        (line!(), Geography(None), Some(4326), "GEOGRAPHY"),
+        (line!(), Geography(Some(4326)), Some(4326), "GEOGRAPHY"),
+        (line!(), Geometry(Some(3857)), Some(3857), "GEOMETRY"),
+        (line!(), Geometry(None), None, "GEOMETRY"),
+    ];
+    for (line, sql_type, expected_srid, expected_str) in table {
+        let field = sql_type.to_field(backend, "geo".to_string(), true);
+        assert_eq!(field.data_type(), &DataType::Utf8);
+        assert_eq!(
+            field.metadata().get("ARROW:extension:name").unwrap(),
+            "geoarrow.wkt"
+        );
+
+        let (ty, _) = SqlType::from_field(backend, &field).unwrap();
+        assert_eq!(ty.to_string(backend), expected_str, "from line {line}");
+        let srid = match ty {
+            Geometry(srid) | Geography(srid) => srid,
+            _ => panic!("{ty:?} from line {line}"),
+        };
+        assert_eq!(srid, expected_srid, "from line {line}");
+
+        // without the SQL type string, the extension metadata is used instead
+        let mut metadata = field.metadata().clone();
+        metadata.remove(metadata_sql_type_key(backend));
+        let field = field.with_metadata(metadata);
+        let (ty, _) = SqlType::from_field(backend, &field).unwrap();
+        assert_eq!(ty.to_string(backend), expected_str, "from line {line}");
+        assert!(
+            matches!(ty, Geometry(srid) | Geography(srid) if srid == expected_srid),
+            "{ty:?} from line {line}"
+        );
+    }
+}
+
+#[test]
+fn test_geospatial_per_backend() {
+    // BigQuery has no GEOMETRY type, so geometries are rendered as geographies
+    assert_eq!(Geometry(None).to_string(BigQuery), "GEOGRAPHY");
+    assert_roundtrip(line!(), &Geography(None), "GEOGRAPHY", BigQuery);
+    for backend in [Snowflake, Postgres] {
+        assert_roundtrip(line!(), &Geometry(None), "GEOMETRY", backend);
+        assert_roundtrip(line!(), &Geography(None), "GEOGRAPHY", backend);
+    }
+
+    // both types are stored as WKT strings on every backend
+    for backend in [Snowflake, BigQuery, Postgres] {
+        for sql_type in [Geometry(None), Geography(None)] {
+            let field = sql_type.to_field(backend, "geo".to_string(), true);
+            assert_eq!(field.data_type(), &DataType::Utf8, "{backend}");
+            assert_eq!(
+                field.metadata().get("ARROW:extension:name").unwrap(),
+                "geoarrow.wkt",
+                "{backend}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_parse_with_default() {
+    let table = vec![
+        (line!(), "INTEGER DEFAULT 0", "INT", true, Some("0")),
+        (
+            line!(),
+            "TIMESTAMP DEFAULT CURRENT_TIMESTAMP NOT NULL",
+            "TIMESTAMP",
+            false,
+            Some("CURRENT_TIMESTAMP"),
+        ),
+        (line!(), "INT NOT NULL DEFAULT -1", "INT", false, Some("-1")),
+        (
+            line!(),
+            "VARCHAR(10) DEFAULT 'a b' NULL",
+            "VARCHAR(10)",
+            true,
+            Some("'a b'"),
+        ),
+        (
+            line!(),
+            "TIMESTAMP DEFAULT date_trunc('day', now()) not null",
+            "TIMESTAMP",
+            false,
+            Some("date_trunc('day', now())"),
+        ),
+        (line!(), "TEXT DEFAULT NULL", "TEXT", true, Some("NULL")),
+        (line!(), "INTEGER NOT NULL", "INT", false, None),
+    ];
+    for (line, input, expected_type, expected_nullable, expected_default) in table {
+        let (ty, nullable, default) = SqlType::parse_with_default(Postgres, input).unwrap();
+        let msg = format!("input: {input} from {}:{line}", file!());
+        assert_eq!(ty.to_string(Postgres), expected_type, "{msg}");
+        assert_eq!(nullable, expected_nullable, "{msg}");
+        assert_eq!(default.as_deref(), expected_default, "{msg}");
+
+        // the DEFAULT clause doesn't get in the way of the plain parser
+        let (ty, nullable) = SqlType::parse(Postgres, input).unwrap();
+        assert_eq!(ty.to_string(Postgres), expected_type, "{msg}");
+        assert_eq!(nullable, expected_nullable, "{msg}");
+    }
+
+    assert!(SqlType::parse_with_default(Postgres, "INTEGER DEFAULT").is_err());
+}
+
+#[test]
+fn test_to_string_with_nullability() {
+    let table = vec![
+        // (line, backend, nullable, explicit_null, expected)
+        (line!(), Databricks, true, false, "INT"),
+        (line!(), Databricks, false, false, "INT NOT NULL"),
+        (line!(), Databricks, true, true, "INT NULL"),
+        (line!(), Databricks, false, true, "INT NOT NULL"),
+        (line!(), Snowflake, true, true, "INT NULL"),
+        (line!(), ClickHouse, true, false, "Nullable(Int32)"),
+        (line!(), ClickHouse, true, true, "Nullable(Int32)"),
+        (line!(), ClickHouse, false, true, "Int32"),
+    ];
+    for (line, backend, nullable, explicit_null, expected) in table {
+        let s = Integer.to_string_with_nullability(backend, nullable, explicit_null);
+        assert_eq!(s, expected, "from {}:{line}", file!());
+
+        let (ty, parsed_nullable) = SqlType::parse(backend, &s).unwrap();
+        assert!(matches!(ty, Integer), "{ty:?} from {}:{line}", file!());
+        assert_eq!(parsed_nullable, nullable, "from {}:{line}", file!());
+    }
+}
+
+#[test]
+fn test_metadata_keys_for_every_backend() {
+    let generic = Generic {
+        library_name: "generic",
+        entrypoint: None,
+    };
+    let mut own_keys = HashMap::new();
+    for &backend in Backend::all().iter().chain([&generic]) {
+        let keys = metadata_type_candidate_keys(backend);
+        assert!(!keys.is_empty(), "{backend:?}");
+        // the key we write is specific to the backend...
+        assert_eq!(metadata_sql_type_key(backend), keys[0]);
+        assert!(keys[0].ends_with(":type"), "{backend:?}: {keys:?}");
+        // ...and the generic key is understood by all of them
+        assert!(keys.contains(&"type_text"), "{backend:?}: {keys:?}");
+
+        // only the ADBC and ODBC drivers of a backend share a key
+        let family = format!("{backend}");
+        let previous = own_keys.insert(keys[0], family.clone());
+        assert!(
+            previous.is_none_or(|previous| previous == family),
+            "{backend:?} reuses {}",
+            keys[0]
+        );
+    }
+    assert!(!Backend::all().contains(&generic));
+}
+
+#[test]
+fn test_salesforce_metadata_keys() {
+    assert_eq!(metadata_sql_type_key(Salesforce), "SALESFORCE:type");
+    assert_ne!(
+        metadata_sql_type_key(Salesforce),
+        metadata_sql_type_key(Postgres)
+    );
+
+    // schemas written before Salesforce had its own key are still understood
+    let field = Field::new("amount", DataType::Decimal128(18, 2), true)
+        .with_metadata([("POSTGRES:type".to_string(), "NUMERIC(18, 2)".to_string())].into());
+    assert_eq!(
+        original_type_string(Salesforce, &field).unwrap(),
+        "NUMERIC(18, 2)"
+    );
+
+    // a Salesforce-specific key wins over the legacy one
+    let field = field.with_metadata(
+        [
+            ("POSTGRES:type".to_string(), "NUMERIC(18, 2)".to_string()),
+            ("SALESFORCE:type".to_string(), "DECIMAL(18, 2)".to_string()),
+        ]
+        .into(),
+    );
+    assert_eq!(
+        original_type_string(Salesforce, &field).unwrap(),
+        "DECIMAL(18, 2)"
+    );
+    // but PostgreSQL doesn't pick up Salesforce types
+    assert_eq!(
+        original_type_string(Postgres, &field).unwrap(),
+        "NUMERIC(18, 2)"
+    );
+}
+
+#[test]
+fn test_to_arrow_field_with_metadata_override() {
+    let ty = Numeric(Some((18, Some(2))));
+    for (backend, key) in [
+        (Postgres, "type_text"),
+        (Snowflake, "type_text"),
+        (BigQuery, "type"),
+    ] {
+        let field =
+            ty.to_arrow_field_with_metadata_override(backend, "amount".to_string(), false, key);
+        assert_eq!(field.metadata().len(), 1, "{backend}");
+        assert_eq!(field.metadata()[key], ty.to_string(backend), "{backend}");
+        assert_eq!(
+            field.data_type(),
+            ty.to_field(backend, "amount".to_string(), false)
+                .data_type()
+        );
+
+        let (parsed, _nullable) = SqlType::from_field(backend, &field).unwrap();
+        assert_eq!(
+            parsed.to_string(backend),
+            ty.to_string(backend),
+            "{backend}"
+        );
+    }
+
+    // to_field keeps writing the backend's own key
+    let field = ty.to_field(Postgres, "amount".to_string(), true);
+    assert!(
+        field
+            .metadata()
+            .contains_key(metadata_sql_type_key(Postgres))
+    );
+    assert!(!field.metadata().contains_key("type_text"));
+}
+
+#[test]
+fn test_multi_word_types() {
+    let table = vec![
+        (line!(), Postgres, "DOUBLE PRECISION", "DOUBLE PRECISION"),
+        (
+            line!(),
+            Postgres,
+            "double precision not null",
+            "DOUBLE PRECISION",
+        ),
+        (line!(), Snowflake, "CHARACTER LARGE OBJECT", "TEXT"),
+        (
+            line!(),
+            Postgres,
+            "(double DOUBLE PRECISION, precision DOUBLE PRECISION)",
+            "(double DOUBLE PRECISION, precision DOUBLE PRECISION)",
+        ),
+        (
+            line!(),
+            Databricks,
+            "STRUCT<double: DOUBLE, large: BINARY>",
+            "STRUCT<double: DOUBLE, large: BINARY>",
+        ),
+    ];
+    for (line, backend, input, expected) in table {
+        let (parsed, _nullable) = SqlType::parse(backend, input).unwrap();
+        assert_eq!(
+            parsed.to_string(backend),
+            expected,
+            "input: {input} ({backend}) from {}:{line}",
+            file!()
+        );
+    }
+
+    // LARGE is only consumed when followed by OBJECT
+    assert!(SqlType::parse(Postgres, "CHARACTER LARGE").is_ok());
+    let (parsed, _) = SqlType::parse(Postgres, "BINARY LARGE OBJECT").unwrap();
+    assert!(matches!(parsed, Blob));
+}
+
+#[test]
+fn test_character_set_and_collation() {
+    let generic = Generic {
+        library_name: "generic",
+        entrypoint: None,
+    };
+    let full = "VARCHAR(10) CHARACTER SET utf8mb4 COLLATE utf8mb4_bin";
+    let ty = Varchar(
+        Some(10),
+        StringAttrs {
+            charset: Some("utf8mb4".to_string()),
+            collate_spec: Some("utf8mb4_bin".to_string()),
+            ..Default::default()
+        },
+    );
+    assert_roundtrip(line!(), &ty, full, generic);
+    assert_roundtrip(
+        line!(),
+        &Char(
+            Some(3),
+            StringAttrs {
+                charset: Some("latin1".to_string()),
+                ..Default::default()
+            },
+        ),
+        "CHAR(3) CHARACTER SET latin1",
+        generic,
+    );
+
+    let table = vec![
+        // the clauses can come in either order, and CHARSET is a synonym
+        (line!(), generic, full, full),
+        (
+            line!(),
+            generic,
+            "varchar(10) collate utf8mb4_bin charset utf8mb4",
+            full,
+        ),
+        (
+            line!(),
+            generic,
+            "CHAR(3) CHARACTER SET binary",
+            "CHAR(3) CHARACTER SET binary",
+        ),
+        // the character set is dropped on backends that don't support it
+        (line!(), Postgres, full, "VARCHAR(10) COLLATE utf8mb4_bin"),
+        (line!(), Snowflake, full, "VARCHAR(10) COLLATE utf8mb4_bin"),
+        (line!(), Databricks, full, "STRING COLLATE utf8mb4_bin"),
+        (line!(), MSSQL, full, "NVARCHAR(10) COLLATE utf8mb4_bin"),
+    ];
+    for (line, backend, input, expected) in table {
+        let (parsed, _nullable) = SqlType::parse(backend, input).unwrap();
+        assert_eq!(
+            parsed.to_string(backend),
+            expected,
+            "input: {input} ({backend}) from {}:{line}",
+            file!()
+        );
+    }
+}
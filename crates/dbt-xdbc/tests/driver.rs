@@ -160,6 +160,8 @@ mod tests {
 
                 Ok(builder)
             }
+            Backend::MSSQL => unimplemented!("SQL Server database builder in tests"),
+            Backend::ClickHouse => unimplemented!("ClickHouse database builder in tests"),
             Backend::Generic { .. } => unimplemented!("generic backend database builder in tests"),
         }?;
         if backend == Backend::Snowflake {